@@ -0,0 +1,15 @@
+pub use infra::{
+    bandwidth_limit::{BandwidthLimit, RateLimiter},
+    buffer_size::BufferSize,
+    case_sensitivity::CaseSensitivity,
+    copy_options::CopyOptions,
+    deadline::Deadline,
+    directory_difference::DirectoryDifference,
+    file_digest::FileDigest,
+    glob_patterns::GlobPatterns,
+    hash_algorithm::HashAlgorithm,
+    retry_policy::RetryPolicy,
+    symlink_policy::SymlinkPolicy,
+    transfer_summary::TransferSummary,
+    verification_mode::VerificationMode,
+};
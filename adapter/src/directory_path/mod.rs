@@ -1,3 +1,5 @@
-pub mod readonly_directory_path;
+pub mod readable_directory_path;
 pub mod virtual_directory_path;
 pub mod writable_directory_path;
+
+pub use infra::file_system::CHECKSUM_FILE_NAME;
@@ -0,0 +1,318 @@
+use infra::{
+    copy_options::CopyOptions, directory_difference::DirectoryDifference, file_digest::FileDigest,
+    file_system::FileSystem,
+};
+use shared::error::{AppError, AppResult};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReadableDirectoryPath(PathBuf);
+
+impl ReadableDirectoryPath {
+    pub fn new(path: impl Into<PathBuf>) -> AppResult<Self> {
+        let path = path.into();
+
+        if !path.is_dir() {
+            return Err(AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("ディレクトリ '{}' は存在しません", path.display()),
+            )));
+        }
+
+        if !FileSystem::is_directory_readable(&path) {
+            return Err(AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::PermissionDenied,
+                format!(
+                    "ディレクトリ '{}' に読み取り権限がありません",
+                    path.display()
+                ),
+            )));
+        }
+
+        Ok(Self(path))
+    }
+
+    pub fn remove_all(&self) -> AppResult<()> {
+        FileSystem::clear_directory_contents(&self.0)
+    }
+
+    pub fn remove_all_with_options(&self, options: &CopyOptions) -> AppResult<()> {
+        FileSystem::clear_directory_contents_with_options(&self.0, options)
+    }
+
+    /// コピーと同じ規則で辿ったファイルの数
+    pub fn file_count(&self) -> AppResult<u64> {
+        Ok(FileSystem::count_entries(&self.0, &CopyOptions::default())?.0)
+    }
+
+    pub fn file_count_with_options(&self, options: &CopyOptions) -> AppResult<u64> {
+        Ok(FileSystem::count_entries(&self.0, options)?.0)
+    }
+
+    /// 配下のディレクトリの数（自身は数えない）
+    pub fn dir_count(&self) -> AppResult<u64> {
+        Ok(FileSystem::count_entries(&self.0, &CopyOptions::default())?.1)
+    }
+
+    /// コピーと同じ規則で辿ったファイルサイズの合計（ディレクトリ自体は数えない）
+    pub fn total_size(&self) -> AppResult<u64> {
+        self.total_size_with_options(&CopyOptions::default())
+    }
+
+    pub fn total_size_with_options(&self, options: &CopyOptions) -> AppResult<u64> {
+        FileSystem::calculate_directory_size(&self.0, options)
+    }
+
+    pub fn digest_files(&self, options: &CopyOptions) -> AppResult<Vec<FileDigest>> {
+        FileSystem::digest_files(&self.0, options)
+    }
+
+    pub fn tree_hash(&self, options: &CopyOptions) -> AppResult<String> {
+        FileSystem::tree_hash_with_options(&self.0, options)
+    }
+
+    pub fn list_entries(&self, options: &CopyOptions) -> AppResult<Vec<PathBuf>> {
+        FileSystem::list_entries(&self.0, options)
+    }
+
+    pub fn verify_checksum_file(&self) -> AppResult<Vec<DirectoryDifference>> {
+        FileSystem::verify_checksum_file(&self.0)
+    }
+
+    pub fn is_empty(&self) -> AppResult<bool> {
+        FileSystem::is_directory_empty(&self.0)
+    }
+
+    pub fn differences_from(
+        &self,
+        source: &ReadableDirectoryPath,
+        options: &CopyOptions,
+    ) -> AppResult<Vec<DirectoryDifference>> {
+        FileSystem::find_directory_differences(&source.0, &self.0, options)
+    }
+}
+
+impl TryFrom<String> for ReadableDirectoryPath {
+    type Error = AppError;
+
+    fn try_from(path: String) -> Result<Self, Self::Error> {
+        Self::new(path)
+    }
+}
+
+impl AsRef<Path> for ReadableDirectoryPath {
+    fn as_ref(&self) -> &Path {
+        self.0.as_path()
+    }
+}
+
+impl AsRef<PathBuf> for ReadableDirectoryPath {
+    fn as_ref(&self) -> &PathBuf {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for ReadableDirectoryPath {
+    type Target = PathBuf;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for ReadableDirectoryPath {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn creates_readable_dir_from_readonly_directory() {
+        // ===== Arrange =====
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().to_path_buf();
+
+        // Set read-only permission for the directory
+        let mut perms = std::fs::metadata(&path).unwrap().permissions();
+        perms.set_readonly(true);
+        std::fs::set_permissions(&path, perms).unwrap();
+
+        // ===== Act =====
+        let source_dir = ReadableDirectoryPath::new(path.clone()).unwrap();
+
+        // ===== Assert =====
+        assert_eq!(source_dir.to_str().unwrap(), path.to_str().unwrap());
+    }
+
+    #[test]
+    fn fails_creating_readable_dir_from_nonexistent_path() {
+        // ===== Arrange =====
+        let invalid_path = PathBuf::from("/path/does/not/exist");
+
+        // ===== Act =====
+        let result = ReadableDirectoryPath::new(invalid_path);
+
+        // ===== Assert =====
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn creates_readable_dir_from_writable_directory() {
+        // ===== Arrange =====
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().to_path_buf();
+
+        // ===== Act =====
+        let result = ReadableDirectoryPath::new(path.clone());
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn fails_creating_readable_dir_from_file() {
+        // ===== Arrange =====
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("file.txt");
+        std::fs::write(&path, "content").unwrap();
+
+        // ===== Act =====
+        let result = ReadableDirectoryPath::new(path);
+
+        // ===== Assert =====
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn readable_directory_path_counts_files_and_directories() {
+        // ===== Arrange =====
+        let temp_dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("a").join("b")).unwrap();
+        std::fs::create_dir(temp_dir.path().join("c")).unwrap();
+        std::fs::write(temp_dir.path().join("root.txt"), "root").unwrap();
+        std::fs::write(temp_dir.path().join("a").join("one.txt"), "one").unwrap();
+        std::fs::write(temp_dir.path().join("a").join("b").join("two.txt"), "two").unwrap();
+        let dir = ReadableDirectoryPath::new(temp_dir.path().to_path_buf()).unwrap();
+
+        // ===== Act =====
+        let file_count = dir.file_count();
+        let dir_count = dir.dir_count();
+
+        // ===== Assert =====
+        assert_eq!(file_count.unwrap(), 3);
+        assert_eq!(dir_count.unwrap(), 3);
+    }
+
+    #[test]
+    fn readable_directory_path_total_size_sums_nested_file_sizes() {
+        // ===== Arrange =====
+        let temp_dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("a").join("empty")).unwrap();
+        std::fs::write(temp_dir.path().join("root.txt"), "12345").unwrap();
+        std::fs::write(temp_dir.path().join("a").join("nested.txt"), "1234567").unwrap();
+        let readable_dir = ReadableDirectoryPath::new(temp_dir.path().to_path_buf()).unwrap();
+
+        // ===== Act =====
+        let result = readable_dir.total_size();
+
+        // ===== Assert =====
+        assert_eq!(result.unwrap(), 12);
+    }
+
+    #[test]
+    fn readable_directory_path_remove_all_successfully_removes_directory() {
+        // ===== Arrange =====
+        let temp_dir = tempfile::tempdir().unwrap();
+        let test_dir = temp_dir.path().join("test_dir");
+        std::fs::create_dir(&test_dir).unwrap();
+
+        // テストファイルを作成
+        let test_file = test_dir.join("test.txt");
+        std::fs::write(&test_file, "test content").unwrap();
+
+        // ディレクトリを読み取り専用に設定
+        let mut perms = std::fs::metadata(&test_dir).unwrap().permissions();
+        perms.set_readonly(true);
+        std::fs::set_permissions(&test_dir, perms).unwrap();
+
+        let readable_dir = ReadableDirectoryPath::new(test_dir.to_path_buf()).unwrap();
+
+        // ===== Act =====
+        let result = readable_dir.remove_all();
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert!(FileSystem::is_directory_empty(&test_dir).unwrap());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn readable_directory_path_remove_all_removes_readonly_entries_and_keeps_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // ===== Arrange =====
+        let temp_dir = tempfile::tempdir().unwrap();
+        let test_dir = temp_dir.path().join("test_dir");
+        let sub_dir = test_dir.join("sub");
+        std::fs::create_dir_all(&sub_dir).unwrap();
+        std::fs::write(test_dir.join("test.txt"), "test content").unwrap();
+        std::fs::write(sub_dir.join("nested.txt"), "nested").unwrap();
+        for path in [
+            test_dir.join("test.txt"),
+            sub_dir.join("nested.txt"),
+            sub_dir.clone(),
+            test_dir.clone(),
+        ] {
+            let mode = if path.is_dir() { 0o555 } else { 0o444 };
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(mode)).unwrap();
+        }
+        let readable_dir = ReadableDirectoryPath::new(test_dir.to_path_buf()).unwrap();
+
+        // ===== Act =====
+        let result = readable_dir.remove_all();
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert!(FileSystem::is_directory_empty(&test_dir).unwrap());
+        let mode = std::fs::metadata(&test_dir).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o555);
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn readable_directory_path_remove_all_removes_readonly_entries_and_keeps_permissions() {
+        // ===== Arrange =====
+        let temp_dir = tempfile::tempdir().unwrap();
+        let test_dir = temp_dir.path().join("test_dir");
+        let sub_dir = test_dir.join("sub");
+        std::fs::create_dir_all(&sub_dir).unwrap();
+        std::fs::write(test_dir.join("test.txt"), "test content").unwrap();
+        std::fs::write(sub_dir.join("nested.txt"), "nested").unwrap();
+        for path in [
+            test_dir.join("test.txt"),
+            sub_dir.join("nested.txt"),
+            sub_dir.clone(),
+            test_dir.clone(),
+        ] {
+            let mut perms = std::fs::metadata(&path).unwrap().permissions();
+            perms.set_readonly(true);
+            std::fs::set_permissions(&path, perms).unwrap();
+        }
+        let readable_dir = ReadableDirectoryPath::new(test_dir.to_path_buf()).unwrap();
+
+        // ===== Act =====
+        let result = readable_dir.remove_all();
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert!(FileSystem::is_directory_empty(&test_dir).unwrap());
+        assert!(std::fs::metadata(&test_dir)
+            .unwrap()
+            .permissions()
+            .readonly());
+    }
+}
@@ -1,5 +1,6 @@
+use infra::file_system::FileSystem;
 use shared::error::{AppError, AppResult};
-use std::{fs, path::PathBuf};
+use std::path::{Path, PathBuf};
 
 use super::writable_directory_path::WritableDirectoryPath;
 
@@ -21,10 +22,54 @@ impl VirtualDirectoryPath {
     }
 
     pub fn create_writable_directory_path(self) -> AppResult<WritableDirectoryPath> {
-        fs::create_dir_all(self.0.clone())?;
+        self.create_writable_directory_path_with_mode(None)
+    }
+
+    /// `mode` を指定すると、新たに作成したディレクトリすべてにそのパーミッションを設定する（Unixのみ）
+    pub fn create_writable_directory_path_with_mode(
+        self,
+        mode: Option<u32>,
+    ) -> AppResult<WritableDirectoryPath> {
+        FileSystem::create_directory_with_mode(&self.0, mode)?;
         WritableDirectoryPath::new(self.0)
     }
 
+    /// 作成の途中で失敗しないよう、存在する最も近い親ディレクトリに書き込めるかを事前に確認する
+    pub fn ensure_creatable(&self) -> AppResult<()> {
+        let ancestor = FileSystem::nearest_existing_ancestor(&self.0);
+        if !ancestor.is_dir() {
+            return Err(AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::NotADirectory,
+                format!(
+                    "'{}' を作成できません: '{}' はディレクトリではありません",
+                    self.0.display(),
+                    ancestor.display()
+                ),
+            )));
+        }
+        // 権限ビットだけでは所有者の違いや root の権限を判定できないため、実際に書き込めるかを確かめる
+        if !FileSystem::is_directory_writable(ancestor) {
+            return Err(AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::PermissionDenied,
+                format!(
+                    "'{}' を作成できません: 親ディレクトリ '{}' に書き込み権限がありません",
+                    self.0.display(),
+                    ancestor.display()
+                ),
+            )));
+        }
+        Ok(())
+    }
+
+    /// 作成予定のディレクトリを含むボリュームの空き容量を返す
+    pub fn available_space(&self) -> AppResult<u64> {
+        FileSystem::available_space(&self.0)
+    }
+
+    pub fn is_nested_with(&self, other: &Path) -> AppResult<bool> {
+        FileSystem::are_paths_nested(&self.0, other)
+    }
+
     pub fn to_str(&self) -> AppResult<&str> {
         self.0.to_str().ok_or_else(|| {
             AppError::Io(std::io::Error::new(
@@ -38,6 +83,7 @@ impl VirtualDirectoryPath {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs;
     use tempfile::TempDir;
 
     #[test]
@@ -96,4 +142,55 @@ mod tests {
             virtual_path.to_str().unwrap()
         );
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn virtual_directory_path_ensure_creatable_follows_actual_write_access() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let parent_path = temp_dir.path().join("locked");
+        fs::create_dir(&parent_path).unwrap();
+        fs::set_permissions(&parent_path, fs::Permissions::from_mode(0o555)).unwrap();
+        let virtual_dir = VirtualDirectoryPath::new(parent_path.join("2025")).unwrap();
+        // 権限ビットが書き込み不可でも、root などは実際に書き込める
+        let writable = fs::write(parent_path.join("probe"), "").is_ok();
+        let _ = fs::remove_file(parent_path.join("probe"));
+
+        // ===== Act =====
+        let result = virtual_dir.ensure_creatable();
+
+        // ===== Assert =====
+        assert_eq!(result.is_ok(), writable);
+        if writable {
+            assert!(virtual_dir.create_writable_directory_path().is_ok());
+        }
+        fs::set_permissions(&parent_path, fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn virtual_directory_path_creates_directories_with_specified_mode() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let parent_path = temp_dir.path().join("2025");
+        let virtual_path = parent_path.join("06");
+        let virtual_dir = VirtualDirectoryPath::new(virtual_path.clone()).unwrap();
+
+        // ===== Act =====
+        let result = virtual_dir.create_writable_directory_path_with_mode(Some(0o750));
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        for path in [&parent_path, &virtual_path] {
+            let mode = fs::metadata(path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o7777, 0o750);
+        }
+        // 既存の親ディレクトリは変更しない
+        let temp_mode = fs::metadata(temp_dir.path()).unwrap().permissions().mode();
+        assert_ne!(temp_mode & 0o7777, 0o750);
+    }
 }
@@ -1,254 +1,723 @@
-use infra::file_system::FileSystem;
-use shared::error::{AppError, AppResult};
-use std::path::{Path, PathBuf};
-
-use super::readonly_directory_path::ReadonlyDirectoryPath;
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct WritableDirectoryPath(PathBuf);
-
-impl WritableDirectoryPath {
-    pub fn new(path: impl Into<PathBuf>) -> AppResult<Self> {
-        let path = path.into();
-
-        if !path.is_dir() {
-            return Err(AppError::Io(std::io::Error::new(
-                std::io::ErrorKind::NotFound,
-                format!("ディレクトリ '{}' は存在しません", path.display()),
-            )));
-        }
-
-        if FileSystem::is_path_readonly(&path)? {
-            return Err(AppError::Io(std::io::Error::new(
-                std::io::ErrorKind::PermissionDenied,
-                format!(
-                    "ディレクトリ '{}' に書き込み権限がありません",
-                    path.display()
-                ),
-            )));
-        }
-
-        Ok(Self(path))
-    }
-
-    pub fn join(&self, path: impl Into<PathBuf>) -> Self {
-        let path = self.0.join(path.into());
-        Self(path)
-    }
-
-    pub fn is_empty(&self) -> AppResult<bool> {
-        FileSystem::is_directory_empty(&self.0)
-    }
-
-    pub fn copy_all_data_from(&self, source: &ReadonlyDirectoryPath) -> AppResult<()> {
-        FileSystem::copy_all_data_under_the_directory_with_hash_verification(
-            source.as_path(),
-            &self.0,
-        )
-    }
-
-    pub fn verify_directory_contents_match(&self, other: &Path) -> AppResult<bool> {
-        FileSystem::verify_directory_contents_match(&self.0, other)
-    }
-
-    pub fn remove_all(&self) -> AppResult<()> {
-        FileSystem::clear_directory_contents(&self.0)
-    }
-}
-
-impl TryFrom<String> for WritableDirectoryPath {
-    type Error = AppError;
-
-    fn try_from(path: String) -> Result<Self, Self::Error> {
-        Self::new(path)
-    }
-}
-
-impl TryFrom<PathBuf> for WritableDirectoryPath {
-    type Error = AppError;
-
-    fn try_from(path: PathBuf) -> Result<Self, Self::Error> {
-        Self::new(path)
-    }
-}
-
-impl AsRef<Path> for WritableDirectoryPath {
-    fn as_ref(&self) -> &Path {
-        self.as_path()
-    }
-}
-
-impl AsRef<PathBuf> for WritableDirectoryPath {
-    fn as_ref(&self) -> &PathBuf {
-        &self.0
-    }
-}
-
-impl std::ops::Deref for WritableDirectoryPath {
-    type Target = PathBuf;
-
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-
-impl std::ops::DerefMut for WritableDirectoryPath {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn creates_writable_dir_from_writable_directory() {
-        // ===== Arrange =====
-        let temp_dir = tempfile::tempdir().unwrap();
-        let path = temp_dir.path().to_path_buf();
-
-        // ===== Act =====
-        let writable_dir = WritableDirectoryPath::new(path.clone()).unwrap();
-
-        // ===== Assert =====
-        assert_eq!(writable_dir.to_str().unwrap(), path.to_str().unwrap());
-    }
-
-    #[test]
-    fn fails_creating_writable_dir_from_nonexistent_path() {
-        // ===== Arrange =====
-        let path = Path::new("nonexistent");
-
-        // ===== Act =====
-        let result = WritableDirectoryPath::new(path);
-
-        // ===== Assert =====
-        assert!(result.is_err());
-    }
-
-    #[test]
-    fn creates_writable_dir_from_readonly_directory() {
-        // ===== Arrange =====
-        let temp_dir = tempfile::tempdir().unwrap();
-        let path = temp_dir.path().to_path_buf();
-        let metadata = std::fs::metadata(&path).unwrap();
-        let mut perms = metadata.permissions();
-        perms.set_readonly(true);
-        std::fs::set_permissions(&path, perms).unwrap();
-
-        // ===== Act =====
-        let result = WritableDirectoryPath::new(path.clone());
-
-        // ===== Assert =====
-        assert!(result.is_err());
-    }
-
-    #[test]
-    fn writable_directory_path_copy_all_data_from_successfully_copies_files() {
-        // ===== Arrange =====
-        let temp_dir = tempfile::tempdir().unwrap();
-        let source_dir = temp_dir.path().join("source");
-        let dest_dir = temp_dir.path().join("dest");
-
-        std::fs::create_dir(&source_dir).unwrap();
-        std::fs::create_dir(&dest_dir).unwrap();
-
-        // ソースディレクトリにファイルを作成
-        let test_file = source_dir.join("test.txt");
-        std::fs::write(&test_file, "test content").unwrap();
-
-        // ソースディレクトリを読み取り専用に設定
-        let mut perms = std::fs::metadata(&source_dir).unwrap().permissions();
-        perms.set_readonly(true);
-        std::fs::set_permissions(&source_dir, perms).unwrap();
-
-        let readonly_source =
-            ReadonlyDirectoryPath::new(source_dir.to_string_lossy().to_string()).unwrap();
-        let writable_dest = WritableDirectoryPath::new(dest_dir.to_path_buf()).unwrap();
-
-        // ===== Act =====
-        let result = writable_dest.copy_all_data_from(&readonly_source);
-
-        // ===== Assert =====
-        assert!(result.is_ok());
-        assert!(dest_dir.join("test.txt").exists());
-        let copied_content = std::fs::read_to_string(dest_dir.join("test.txt")).unwrap();
-        assert_eq!(copied_content, "test content");
-    }
-
-    #[test]
-    fn writable_directory_path_verify_directory_contents_match_returns_true_for_identical_directories(
-    ) {
-        // ===== Arrange =====
-        let temp_dir = tempfile::tempdir().unwrap();
-        let dir1 = temp_dir.path().join("dir1");
-        let dir2 = temp_dir.path().join("dir2");
-
-        std::fs::create_dir(&dir1).unwrap();
-        std::fs::create_dir(&dir2).unwrap();
-
-        // 両方のディレクトリに同じファイルを作成
-        let test_file1 = dir1.join("test.txt");
-        let test_file2 = dir2.join("test.txt");
-        std::fs::write(&test_file1, "test content").unwrap();
-        std::fs::write(&test_file2, "test content").unwrap();
-
-        let writable_dir = WritableDirectoryPath::new(dir1.to_path_buf()).unwrap();
-
-        // ===== Act =====
-        let result = writable_dir.verify_directory_contents_match(&dir2);
-
-        // ===== Assert =====
-        assert!(result.is_ok());
-        assert!(result.unwrap());
-    }
-
-    #[test]
-    fn writable_directory_path_verify_directory_contents_match_returns_false_for_different_directories(
-    ) {
-        // ===== Arrange =====
-        let temp_dir = tempfile::tempdir().unwrap();
-        let dir1 = temp_dir.path().join("dir1");
-        let dir2 = temp_dir.path().join("dir2");
-
-        std::fs::create_dir(&dir1).unwrap();
-        std::fs::create_dir(&dir2).unwrap();
-
-        // 異なるファイルを作成
-        let test_file1 = dir1.join("file1.txt");
-        let test_file2 = dir2.join("file2.txt");
-        std::fs::write(&test_file1, "content1").unwrap();
-        std::fs::write(&test_file2, "content2").unwrap();
-
-        let writable_dir = WritableDirectoryPath::new(dir1.to_path_buf()).unwrap();
-
-        // ===== Act =====
-        let result = writable_dir.verify_directory_contents_match(&dir2);
-
-        // ===== Assert =====
-        assert!(result.is_ok());
-        assert!(!result.unwrap());
-    }
-
-    #[test]
-    fn writable_directory_path_remove_all_successfully_removes_directory() {
-        // ===== Arrange =====
-        let temp_dir = tempfile::tempdir().unwrap();
-        let test_dir = temp_dir.path().join("test_dir");
-        std::fs::create_dir(&test_dir).unwrap();
-
-        // テストファイルを作成
-        let test_file = test_dir.join("test.txt");
-        std::fs::write(&test_file, "test content").unwrap();
-
-        let writable_dir = WritableDirectoryPath::new(test_dir.to_path_buf()).unwrap();
-
-        // ===== Act =====
-        let result = writable_dir.remove_all();
-
-        // ===== Assert =====
-        assert!(result.is_ok());
-        assert!(FileSystem::is_directory_empty(&test_dir).unwrap());
-    }
-}
+use infra::{
+    copy_options::CopyOptions, directory_difference::DirectoryDifference, file_digest::FileDigest,
+    file_system::FileSystem, transfer_summary::TransferSummary,
+    verification_mode::VerificationMode,
+};
+use log::{debug, info, warn};
+use shared::{
+    error::{AppError, AppResult},
+    message::Message,
+};
+use std::path::{Path, PathBuf};
+
+use super::readable_directory_path::ReadableDirectoryPath;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WritableDirectoryPath(PathBuf);
+
+impl WritableDirectoryPath {
+    pub fn new(path: impl Into<PathBuf>) -> AppResult<Self> {
+        let path = path.into();
+
+        if !path.is_dir() {
+            return Err(AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("ディレクトリ '{}' は存在しません", path.display()),
+            )));
+        }
+
+        if FileSystem::is_path_readonly(&path)? {
+            return Err(AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::PermissionDenied,
+                format!(
+                    "ディレクトリ '{}' に書き込み権限がありません",
+                    path.display()
+                ),
+            )));
+        }
+
+        Ok(Self(path))
+    }
+
+    pub fn join(&self, path: impl Into<PathBuf>) -> Self {
+        let path = self.0.join(path.into());
+        Self(path)
+    }
+
+    /// コピーと同じ規則で辿ったファイルの数
+    pub fn file_count(&self) -> AppResult<u64> {
+        Ok(FileSystem::count_entries(&self.0, &CopyOptions::default())?.0)
+    }
+
+    /// 配下のディレクトリの数（自身は数えない）
+    pub fn dir_count(&self) -> AppResult<u64> {
+        Ok(FileSystem::count_entries(&self.0, &CopyOptions::default())?.1)
+    }
+
+    /// コピーと同じ規則で辿ったファイルサイズの合計（ディレクトリ自体は数えない）
+    pub fn total_size(&self) -> AppResult<u64> {
+        FileSystem::calculate_directory_size(&self.0, &CopyOptions::default())
+    }
+
+    pub fn available_space(&self) -> AppResult<u64> {
+        FileSystem::available_space(&self.0)
+    }
+
+    pub fn digest_files(&self, options: &CopyOptions) -> AppResult<Vec<FileDigest>> {
+        FileSystem::digest_files(&self.0, options)
+    }
+
+    pub fn tree_hash(&self, options: &CopyOptions) -> AppResult<String> {
+        FileSystem::tree_hash_with_options(&self.0, options)
+    }
+
+    pub fn list_entries(&self, options: &CopyOptions) -> AppResult<Vec<PathBuf>> {
+        FileSystem::list_entries(&self.0, options)
+    }
+
+    pub fn write_checksum_file(&self, options: &CopyOptions) -> AppResult<PathBuf> {
+        FileSystem::write_checksum_file(&self.0, options)
+    }
+
+    pub fn is_empty(&self) -> AppResult<bool> {
+        FileSystem::is_directory_empty(&self.0)
+    }
+
+    pub fn is_nested_with(&self, other: &Path) -> AppResult<bool> {
+        FileSystem::are_paths_nested(&self.0, other)
+    }
+
+    pub fn copy_all_data_from(&self, source: &ReadableDirectoryPath) -> AppResult<()> {
+        self.copy_all_data_from_with_report(source).map(|_| ())
+    }
+
+    /// コピーしたファイルの移動元からの相対パスを、名前順に並べて返す（監査用）
+    pub fn copy_all_data_from_with_report(
+        &self,
+        source: &ReadableDirectoryPath,
+    ) -> AppResult<Vec<String>> {
+        let mut copied = Vec::new();
+        self.copy_all_data_from_with_progress(source, &CopyOptions::default(), |path, _| {
+            copied.push(path.to_string_lossy().into_owned())
+        })?;
+        copied.sort();
+        Ok(copied)
+    }
+
+    pub fn copy_all_data_from_with_progress(
+        &self,
+        source: &ReadableDirectoryPath,
+        options: &CopyOptions,
+        progress: impl FnMut(&Path, u64),
+    ) -> AppResult<TransferSummary> {
+        FileSystem::copy_all_data_under_the_directory_with_hash_verification_and_progress(
+            source.as_path(),
+            &self.0,
+            options,
+            progress,
+        )
+    }
+
+    pub fn try_atomic_move_from(
+        &self,
+        source: &ReadableDirectoryPath,
+        options: &CopyOptions,
+    ) -> AppResult<Option<TransferSummary>> {
+        self.try_atomic_move_from_with_progress(source, options, |_, _| {})
+    }
+
+    /// リネームで移動できた場合は、移動したファイルごとに `progress` を呼び出す
+    pub fn try_atomic_move_from_with_progress(
+        &self,
+        source: &ReadableDirectoryPath,
+        options: &CopyOptions,
+        progress: impl FnMut(&Path, u64),
+    ) -> AppResult<Option<TransferSummary>> {
+        FileSystem::try_atomic_move_with_progress(source.as_path(), &self.0, options, progress)
+    }
+
+    pub fn verify_directory_contents_match(&self, other: &Path) -> AppResult<bool> {
+        FileSystem::verify_directory_contents_match(&self.0, other)
+    }
+
+    pub fn verify_directory_contents_match_with_options(
+        &self,
+        other: &Path,
+        options: &CopyOptions,
+    ) -> AppResult<bool> {
+        FileSystem::verify_directory_contents_match_with_options(&self.0, other, options)
+    }
+
+    pub fn remove_all(&self) -> AppResult<()> {
+        FileSystem::clear_directory_contents(&self.0)
+    }
+
+    /// ディレクトリ自体も含めて削除する
+    pub fn remove(self) -> AppResult<()> {
+        FileSystem::remove_directory(&self.0)
+    }
+
+    /// `to` にリネームする。`to` に既存のディレクトリがあれば、中身ごと置き換える
+    pub fn rename_replacing(self, to: &Path) -> AppResult<Self> {
+        FileSystem::replace_directory(&self.0, to)?;
+        Ok(Self(to.to_path_buf()))
+    }
+
+    /// 空でなければ中身をすべて削除する
+    pub fn ensure_empty_or_clear(&self) -> AppResult<()> {
+        self.ensure_empty_or_clear_with_options(&CopyOptions::default())
+    }
+
+    /// 確認と削除は `options` のストレージを介して行う
+    pub fn ensure_empty_or_clear_with_options(&self, options: &CopyOptions) -> AppResult<()> {
+        match options.storage_backend.is_empty(&self.0)? {
+            true => Ok(()),
+            false => options.storage_backend.remove_all(&self.0),
+        }
+    }
+
+    /// 空でなければ `AppError::DestinationNotEmpty` を返す
+    pub fn ensure_empty_or_err(&self) -> AppResult<()> {
+        self.ensure_empty_or_err_with_options(&CopyOptions::default())
+    }
+
+    /// 確認は `options` のストレージを介して行う
+    pub fn ensure_empty_or_err_with_options(&self, options: &CopyOptions) -> AppResult<()> {
+        match options.storage_backend.is_empty(&self.0)? {
+            true => Ok(()),
+            false => Err(AppError::DestinationNotEmpty(self.0.clone())),
+        }
+    }
+
+    pub fn move_all_data_from(&self, source: &ReadableDirectoryPath) -> AppResult<TransferSummary> {
+        self.move_all_data_from_with_progress(source, &CopyOptions::default(), |_, _| {})
+    }
+
+    /// コピー・検証・移動元のクリアを行い、コピーか検証に失敗した場合は移動先をロールバックする
+    pub fn move_all_data_from_with_progress(
+        &self,
+        source: &ReadableDirectoryPath,
+        options: &CopyOptions,
+        mut progress: impl FnMut(&Path, u64),
+    ) -> AppResult<TransferSummary> {
+        // リネームではコピーしていないため、ファイルごとのログも移動として出力する
+        let moved = |path: &Path, bytes| {
+            debug!(
+                "{}",
+                Message::FileMoved { path, bytes }.render(options.lang)
+            );
+            progress(path, bytes);
+        };
+        if let Some(summary) = self.try_atomic_move_from_with_progress(source, options, moved)? {
+            info!(
+                "{}",
+                Message::MovedByRename {
+                    source,
+                    destination: &self.0,
+                }
+                .render(options.lang)
+            );
+            return Ok(summary);
+        }
+
+        let summary = self.copy_verified_data_from_with_progress(source, options, progress)?;
+
+        source.remove_all_with_options(options)?;
+        info!("{}", Message::SourceCleared(source).render(options.lang));
+        Ok(summary)
+    }
+
+    /// 移動元を残したままコピーと検証を行い、失敗した場合は移動先をロールバックする
+    pub fn copy_verified_data_from_with_progress(
+        &self,
+        source: &ReadableDirectoryPath,
+        options: &CopyOptions,
+        progress: impl FnMut(&Path, u64),
+    ) -> AppResult<TransferSummary> {
+        self.copy_and_verify(source, options, progress)
+            .or_else(|e| {
+                // 既存のファイルを再利用する場合は、コピー済みのファイルを次回の実行で使えるよう残す
+                if options.skip_existing_matching {
+                    return Err(e);
+                }
+                // コピー中のどの段階で失敗しても、作成したファイルとサブディレクトリをすべて削除する
+                // 移動先ディレクトリ自体は削除せず、空の状態で残す
+                warn!("{}", Message::RollingBack(&e).render(options.lang));
+                options.storage_backend.remove_all(&self.0)?;
+                Err(e)
+            })
+    }
+
+    fn copy_and_verify(
+        &self,
+        source: &ReadableDirectoryPath,
+        options: &CopyOptions,
+        mut progress: impl FnMut(&Path, u64),
+    ) -> AppResult<TransferSummary> {
+        let copied = |path: &Path, bytes| {
+            debug!(
+                "{}",
+                Message::FileCopied { path, bytes }.render(options.lang)
+            );
+            progress(path, bytes);
+        };
+        let summary = self.copy_all_data_from_with_progress(source, options, copied)?;
+
+        // ファイル単位の検証はコピー時に指定の方法で済んでいるため、ここでは構成とサイズを確認する
+        // 既存のファイルを再利用する場合は、前回のマニフェストや利用者のファイルが移動先に残り得るため、
+        // 移動元のエントリがすべて移動先にあり一致することだけを確認する
+        let matched = match options.storage_backend.is_local() {
+            true => {
+                let structure_options = CopyOptions {
+                    verification_mode: VerificationMode::SizeOnly,
+                    ..options.clone()
+                };
+                match options.skip_existing_matching {
+                    true => {
+                        FileSystem::find_directory_differences(source, &self.0, &structure_options)?
+                            .iter()
+                            .all(|difference| {
+                                matches!(difference, DirectoryDifference::MissingInSource(_))
+                            })
+                    }
+                    false => self
+                        .verify_directory_contents_match_with_options(source, &structure_options)?,
+                }
+            }
+            // ローカル以外のストレージは直接読めないため、ストレージから取得したエントリの構成を比べる
+            false => {
+                let source_entries = source.list_entries(options)?;
+                let destination_entries = options.storage_backend.list(&self.0, options)?;
+                match options.skip_existing_matching {
+                    true => source_entries
+                        .iter()
+                        .all(|entry| destination_entries.contains(entry)),
+                    false => source_entries == destination_entries,
+                }
+            }
+        };
+        info!(
+            "{}",
+            Message::VerificationResult(matched).render(options.lang)
+        );
+        match matched {
+            true => Ok(summary),
+            false => Err(AppError::IntegrityMismatch {
+                source_path: source.to_path_buf(),
+                destination_path: self.0.clone(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<String> for WritableDirectoryPath {
+    type Error = AppError;
+
+    fn try_from(path: String) -> Result<Self, Self::Error> {
+        Self::new(path)
+    }
+}
+
+impl TryFrom<PathBuf> for WritableDirectoryPath {
+    type Error = AppError;
+
+    fn try_from(path: PathBuf) -> Result<Self, Self::Error> {
+        Self::new(path)
+    }
+}
+
+impl AsRef<Path> for WritableDirectoryPath {
+    fn as_ref(&self) -> &Path {
+        self.as_path()
+    }
+}
+
+impl AsRef<PathBuf> for WritableDirectoryPath {
+    fn as_ref(&self) -> &PathBuf {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for WritableDirectoryPath {
+    type Target = PathBuf;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for WritableDirectoryPath {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn creates_writable_dir_from_writable_directory() {
+        // ===== Arrange =====
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().to_path_buf();
+
+        // ===== Act =====
+        let writable_dir = WritableDirectoryPath::new(path.clone()).unwrap();
+
+        // ===== Assert =====
+        assert_eq!(writable_dir.to_str().unwrap(), path.to_str().unwrap());
+    }
+
+    #[test]
+    fn fails_creating_writable_dir_from_nonexistent_path() {
+        // ===== Arrange =====
+        let path = Path::new("nonexistent");
+
+        // ===== Act =====
+        let result = WritableDirectoryPath::new(path);
+
+        // ===== Assert =====
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn creates_writable_dir_from_readonly_directory() {
+        // ===== Arrange =====
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().to_path_buf();
+        let metadata = std::fs::metadata(&path).unwrap();
+        let mut perms = metadata.permissions();
+        perms.set_readonly(true);
+        std::fs::set_permissions(&path, perms).unwrap();
+
+        // ===== Act =====
+        let result = WritableDirectoryPath::new(path.clone());
+
+        // ===== Assert =====
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn writable_directory_path_copy_all_data_from_successfully_copies_files() {
+        // ===== Arrange =====
+        let temp_dir = tempfile::tempdir().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+
+        std::fs::create_dir(&source_dir).unwrap();
+        std::fs::create_dir(&dest_dir).unwrap();
+
+        // ソースディレクトリにファイルを作成
+        let test_file = source_dir.join("test.txt");
+        std::fs::write(&test_file, "test content").unwrap();
+
+        // ソースディレクトリを読み取り専用に設定
+        let mut perms = std::fs::metadata(&source_dir).unwrap().permissions();
+        perms.set_readonly(true);
+        std::fs::set_permissions(&source_dir, perms).unwrap();
+
+        let readable_source =
+            ReadableDirectoryPath::new(source_dir.to_string_lossy().to_string()).unwrap();
+        let writable_dest = WritableDirectoryPath::new(dest_dir.to_path_buf()).unwrap();
+
+        // ===== Act =====
+        let result = writable_dest.copy_all_data_from(&readable_source);
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert!(dest_dir.join("test.txt").exists());
+        let copied_content = std::fs::read_to_string(dest_dir.join("test.txt")).unwrap();
+        assert_eq!(copied_content, "test content");
+    }
+
+    #[test]
+    fn writable_directory_path_copy_all_data_from_with_report_lists_copied_files() {
+        // ===== Arrange =====
+        let temp_dir = tempfile::tempdir().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+
+        std::fs::create_dir_all(source_dir.join("sub")).unwrap();
+        std::fs::create_dir(&dest_dir).unwrap();
+        std::fs::write(source_dir.join("b.txt"), "b").unwrap();
+        std::fs::write(source_dir.join("a.txt"), "a").unwrap();
+        std::fs::write(source_dir.join("sub").join("c.txt"), "c").unwrap();
+
+        let readable_source =
+            ReadableDirectoryPath::new(source_dir.to_string_lossy().to_string()).unwrap();
+        let writable_dest = WritableDirectoryPath::new(dest_dir.to_path_buf()).unwrap();
+
+        // ===== Act =====
+        let result = writable_dest.copy_all_data_from_with_report(&readable_source);
+
+        // ===== Assert =====
+        let sub_file = Path::new("sub").join("c.txt");
+        assert_eq!(
+            result.unwrap(),
+            ["a.txt", "b.txt", sub_file.to_str().unwrap()]
+        );
+        assert!(dest_dir.join("sub").join("c.txt").exists());
+    }
+
+    #[test]
+    fn writable_directory_path_copy_all_data_from_with_progress_reports_each_file() {
+        // ===== Arrange =====
+        let temp_dir = tempfile::tempdir().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+
+        std::fs::create_dir(&source_dir).unwrap();
+        std::fs::create_dir(&dest_dir).unwrap();
+
+        std::fs::write(source_dir.join("a.txt"), "a").unwrap();
+        std::fs::write(source_dir.join("b.txt"), "bb").unwrap();
+
+        // ソースディレクトリを読み取り専用に設定
+        let mut perms = std::fs::metadata(&source_dir).unwrap().permissions();
+        perms.set_readonly(true);
+        std::fs::set_permissions(&source_dir, perms).unwrap();
+
+        let readable_source = ReadableDirectoryPath::new(source_dir.clone()).unwrap();
+        let writable_dest = WritableDirectoryPath::new(dest_dir.clone()).unwrap();
+        let mut call_count = 0;
+
+        // ===== Act =====
+        let result = writable_dest.copy_all_data_from_with_progress(
+            &readable_source,
+            &CopyOptions::default(),
+            |_, _| call_count += 1,
+        );
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert_eq!(call_count, 2);
+    }
+
+    #[test]
+    fn writable_directory_path_verify_directory_contents_match_returns_true_for_identical_directories(
+    ) {
+        // ===== Arrange =====
+        let temp_dir = tempfile::tempdir().unwrap();
+        let dir1 = temp_dir.path().join("dir1");
+        let dir2 = temp_dir.path().join("dir2");
+
+        std::fs::create_dir(&dir1).unwrap();
+        std::fs::create_dir(&dir2).unwrap();
+
+        // 両方のディレクトリに同じファイルを作成
+        let test_file1 = dir1.join("test.txt");
+        let test_file2 = dir2.join("test.txt");
+        std::fs::write(&test_file1, "test content").unwrap();
+        std::fs::write(&test_file2, "test content").unwrap();
+
+        let writable_dir = WritableDirectoryPath::new(dir1.to_path_buf()).unwrap();
+
+        // ===== Act =====
+        let result = writable_dir.verify_directory_contents_match(&dir2);
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert!(result.unwrap());
+    }
+
+    #[test]
+    fn writable_directory_path_verify_directory_contents_match_returns_false_for_different_directories(
+    ) {
+        // ===== Arrange =====
+        let temp_dir = tempfile::tempdir().unwrap();
+        let dir1 = temp_dir.path().join("dir1");
+        let dir2 = temp_dir.path().join("dir2");
+
+        std::fs::create_dir(&dir1).unwrap();
+        std::fs::create_dir(&dir2).unwrap();
+
+        // 異なるファイルを作成
+        let test_file1 = dir1.join("file1.txt");
+        let test_file2 = dir2.join("file2.txt");
+        std::fs::write(&test_file1, "content1").unwrap();
+        std::fs::write(&test_file2, "content2").unwrap();
+
+        let writable_dir = WritableDirectoryPath::new(dir1.to_path_buf()).unwrap();
+
+        // ===== Act =====
+        let result = writable_dir.verify_directory_contents_match(&dir2);
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert!(!result.unwrap());
+    }
+
+    #[test]
+    fn writable_directory_path_counts_files_and_directories() {
+        // ===== Arrange =====
+        let temp_dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("a").join("b")).unwrap();
+        std::fs::create_dir(temp_dir.path().join("c")).unwrap();
+        std::fs::write(temp_dir.path().join("root.txt"), "root").unwrap();
+        std::fs::write(temp_dir.path().join("a").join("one.txt"), "one").unwrap();
+        std::fs::write(temp_dir.path().join("a").join("b").join("two.txt"), "two").unwrap();
+        let dir = WritableDirectoryPath::new(temp_dir.path().to_path_buf()).unwrap();
+
+        // ===== Act =====
+        let file_count = dir.file_count();
+        let dir_count = dir.dir_count();
+
+        // ===== Assert =====
+        assert_eq!(file_count.unwrap(), 3);
+        assert_eq!(dir_count.unwrap(), 3);
+    }
+
+    #[test]
+    fn writable_directory_path_total_size_sums_nested_file_sizes() {
+        // ===== Arrange =====
+        let temp_dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("a").join("b")).unwrap();
+        std::fs::write(temp_dir.path().join("root.txt"), "123").unwrap();
+        std::fs::write(
+            temp_dir.path().join("a").join("b").join("deep.bin"),
+            [0u8; 1024],
+        )
+        .unwrap();
+        let writable_dir = WritableDirectoryPath::new(temp_dir.path().to_path_buf()).unwrap();
+
+        // ===== Act =====
+        let result = writable_dir.total_size();
+
+        // ===== Assert =====
+        assert_eq!(result.unwrap(), 1027);
+    }
+
+    #[test]
+    fn writable_directory_path_ensure_empty_succeeds_for_empty_directory() {
+        // ===== Arrange =====
+        let temp_dir = tempfile::tempdir().unwrap();
+        let dir = WritableDirectoryPath::new(temp_dir.path().to_path_buf()).unwrap();
+
+        // ===== Act =====
+        let clear_result = dir.ensure_empty_or_clear();
+        let err_result = dir.ensure_empty_or_err();
+
+        // ===== Assert =====
+        assert!(clear_result.is_ok());
+        assert!(err_result.is_ok());
+    }
+
+    #[test]
+    fn writable_directory_path_ensure_empty_or_clear_clears_non_empty_directory() {
+        // ===== Arrange =====
+        let temp_dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(temp_dir.path().join("sub")).unwrap();
+        std::fs::write(temp_dir.path().join("sub").join("file.txt"), "content").unwrap();
+        let dir = WritableDirectoryPath::new(temp_dir.path().to_path_buf()).unwrap();
+
+        // ===== Act =====
+        let result = dir.ensure_empty_or_clear();
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert!(dir.is_empty().unwrap());
+    }
+
+    #[test]
+    fn writable_directory_path_ensure_empty_or_err_fails_for_non_empty_directory() {
+        // ===== Arrange =====
+        let temp_dir = tempfile::tempdir().unwrap();
+        std::fs::write(temp_dir.path().join("file.txt"), "content").unwrap();
+        let dir = WritableDirectoryPath::new(temp_dir.path().to_path_buf()).unwrap();
+
+        // ===== Act =====
+        let result = dir.ensure_empty_or_err();
+
+        // ===== Assert =====
+        assert!(matches!(result, Err(AppError::DestinationNotEmpty(_))));
+        assert!(temp_dir.path().join("file.txt").exists());
+    }
+
+    #[test]
+    fn writable_directory_path_remove_all_successfully_removes_directory() {
+        // ===== Arrange =====
+        let temp_dir = tempfile::tempdir().unwrap();
+        let test_dir = temp_dir.path().join("test_dir");
+        std::fs::create_dir(&test_dir).unwrap();
+
+        // テストファイルを作成
+        let test_file = test_dir.join("test.txt");
+        std::fs::write(&test_file, "test content").unwrap();
+
+        let writable_dir = WritableDirectoryPath::new(test_dir.to_path_buf()).unwrap();
+
+        // ===== Act =====
+        let result = writable_dir.remove_all();
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert!(FileSystem::is_directory_empty(&test_dir).unwrap());
+    }
+
+    #[test]
+    fn writable_directory_path_move_all_data_from_moves_files_and_clears_source() {
+        // ===== Arrange =====
+        let temp_dir = tempfile::tempdir().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+
+        std::fs::create_dir_all(source_dir.join("subdir")).unwrap();
+        std::fs::create_dir(&dest_dir).unwrap();
+        std::fs::write(source_dir.join("test.txt"), "test content").unwrap();
+        std::fs::write(source_dir.join("subdir").join("nested.txt"), "nested").unwrap();
+
+        // ソースディレクトリを読み取り専用に設定
+        let mut perms = std::fs::metadata(&source_dir).unwrap().permissions();
+        perms.set_readonly(true);
+        std::fs::set_permissions(&source_dir, perms).unwrap();
+
+        let readable_source = ReadableDirectoryPath::new(source_dir.clone()).unwrap();
+        let writable_dest = WritableDirectoryPath::new(dest_dir.clone()).unwrap();
+
+        // ===== Act =====
+        let result = writable_dest.move_all_data_from(&readable_source);
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert!(readable_source.is_empty().unwrap());
+        assert_eq!(
+            std::fs::read_to_string(dest_dir.join("test.txt")).unwrap(),
+            "test content"
+        );
+        assert_eq!(
+            std::fs::read_to_string(dest_dir.join("subdir").join("nested.txt")).unwrap(),
+            "nested"
+        );
+    }
+
+    #[test]
+    fn writable_directory_path_move_all_data_from_rolls_back_on_integrity_error() {
+        // ===== Arrange =====
+        let temp_dir = tempfile::tempdir().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+
+        std::fs::create_dir(&source_dir).unwrap();
+        std::fs::create_dir(&dest_dir).unwrap();
+        std::fs::write(source_dir.join("test.txt"), "test content").unwrap();
+        // 移動先に異なるファイルを作成（整合性エラーを引き起こす）
+        std::fs::write(dest_dir.join("different.txt"), "different content").unwrap();
+
+        // ソースディレクトリを読み取り専用に設定
+        let mut perms = std::fs::metadata(&source_dir).unwrap().permissions();
+        perms.set_readonly(true);
+        std::fs::set_permissions(&source_dir, perms).unwrap();
+
+        let readable_source = ReadableDirectoryPath::new(source_dir.clone()).unwrap();
+        let writable_dest = WritableDirectoryPath::new(dest_dir.clone()).unwrap();
+
+        // ===== Act =====
+        let result = writable_dest.move_all_data_from(&readable_source);
+
+        // ===== Assert =====
+        assert!(matches!(result, Err(AppError::IntegrityMismatch { .. })));
+        assert!(writable_dest.is_empty().unwrap());
+        assert!(source_dir.join("test.txt").exists());
+    }
+}
@@ -0,0 +1 @@
+pub use infra::file_operations::{clear_directory, contents_match, copy_with_verification};
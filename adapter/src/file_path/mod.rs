@@ -1 +1,3 @@
+pub mod output_file_path;
+pub mod readable_file_path;
 pub mod writable_file_path;
@@ -0,0 +1,76 @@
+use shared::error::{AppError, AppResult};
+use std::{fs, path::PathBuf};
+
+#[derive(Debug)]
+pub struct OutputFilePath(PathBuf);
+
+impl OutputFilePath {
+    pub fn new(path: impl Into<PathBuf>, overwrite: bool) -> AppResult<Self> {
+        let path = path.into();
+
+        if path.exists() && !overwrite {
+            return Err(AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::AlreadyExists,
+                format!("ファイル '{}' は既に存在します", path.display()),
+            )));
+        }
+
+        if path.is_dir() {
+            return Err(AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("'{}' はディレクトリです", path.display()),
+            )));
+        }
+
+        Ok(Self(path))
+    }
+
+    pub fn write_content(&self, content: &str) -> AppResult<()> {
+        fs::write(&self.0, content).map_err(AppError::Io)
+    }
+}
+
+impl std::ops::Deref for OutputFilePath {
+    type Target = PathBuf;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn output_file_path_fails_when_file_exists_without_overwrite() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("config.json");
+        fs::write(&path, "{}").unwrap();
+
+        // ===== Act =====
+        let result = OutputFilePath::new(path, false);
+
+        // ===== Assert =====
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn output_file_path_overwrites_existing_file_when_allowed() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("config.json");
+        fs::write(&path, "old").unwrap();
+
+        // ===== Act =====
+        let result = OutputFilePath::new(path.clone(), true)
+            .unwrap()
+            .write_content("new");
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert_eq!(fs::read_to_string(path).unwrap(), "new");
+    }
+}
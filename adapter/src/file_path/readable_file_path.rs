@@ -0,0 +1,123 @@
+use shared::error::{AppError, AppResult};
+use std::{fs, path::PathBuf};
+
+// 設定ファイルとして読み込むには大きすぎるファイルを誤って指定した場合に備えた上限
+const DEFAULT_MAX_CONTENT_SIZE: u64 = 10 * 1024 * 1024;
+
+/// 読み込むだけのファイル。設定ファイルなどは読み取り専用でもよいため、書き込み権限は確認しない
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReadableFilePath {
+    path: PathBuf,
+    max_content_size: u64,
+}
+
+impl ReadableFilePath {
+    pub fn new(path: impl Into<PathBuf>) -> AppResult<Self> {
+        let path = path.into();
+
+        if !path.is_file() {
+            return Err(AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("ファイル '{}' は存在しません", path.display()),
+            )));
+        }
+
+        if let Err(e) = fs::File::open(&path) {
+            return Err(AppError::Io(std::io::Error::new(
+                e.kind(),
+                format!("ファイル '{}' を読み込めません: {}", path.display(), e),
+            )));
+        }
+
+        Ok(Self {
+            path,
+            max_content_size: DEFAULT_MAX_CONTENT_SIZE,
+        })
+    }
+
+    pub fn with_max_content_size(self, max_content_size: u64) -> Self {
+        Self {
+            max_content_size,
+            ..self
+        }
+    }
+
+    pub fn read_content(&self) -> AppResult<String> {
+        let size = fs::metadata(&self.path)?.len();
+        if size > self.max_content_size {
+            return Err(AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "ファイル '{}' のサイズ（{} bytes）が上限（{} bytes）を超えています",
+                    self.path.display(),
+                    size,
+                    self.max_content_size
+                ),
+            )));
+        }
+        String::from_utf8(fs::read(&self.path)?).map_err(|_| {
+            AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!(
+                    "ファイル '{}' は有効な UTF-8 ではありません",
+                    self.path.display()
+                ),
+            ))
+        })
+    }
+}
+
+impl TryFrom<String> for ReadableFilePath {
+    type Error = AppError;
+
+    fn try_from(path: String) -> Result<Self, Self::Error> {
+        Self::new(path)
+    }
+}
+
+impl std::ops::Deref for ReadableFilePath {
+    type Target = PathBuf;
+
+    fn deref(&self) -> &Self::Target {
+        &self.path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn readable_file_path_accepts_readonly_file() {
+        // ===== Arrange =====
+        let temp_file = NamedTempFile::new().unwrap();
+        std::fs::write(&temp_file, "test content").unwrap();
+        let mut perms = std::fs::metadata(temp_file.path()).unwrap().permissions();
+        perms.set_readonly(true);
+        std::fs::set_permissions(temp_file.path(), perms).unwrap();
+
+        // ===== Act =====
+        let result = ReadableFilePath::new(temp_file.path());
+
+        // ===== Assert =====
+        assert_eq!(result.unwrap().read_content().unwrap(), "test content");
+    }
+
+    #[test]
+    fn readable_file_path_fails_with_directory_path() {
+        // ===== Arrange =====
+        let temp_dir = tempfile::tempdir().unwrap();
+
+        // ===== Act =====
+        let result = ReadableFilePath::new(temp_dir.path());
+
+        // ===== Assert =====
+        match result.unwrap_err() {
+            AppError::Io(io_error) => {
+                assert_eq!(io_error.kind(), std::io::ErrorKind::NotFound);
+            }
+            _ => panic!("Expected Io error with NotFound kind"),
+        }
+    }
+}
@@ -1,33 +1,33 @@
 use shared::error::{AppError, AppResult};
 use std::{fs, path::PathBuf};
 
+use super::readable_file_path::ReadableFilePath;
+
+/// 書き込み権限も必要なファイル。読み込むだけであれば [`ReadableFilePath`] を使う
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WritableFilePath(PathBuf);
+pub struct WritableFilePath(ReadableFilePath);
 
 impl WritableFilePath {
     pub fn new(path: impl Into<PathBuf>) -> AppResult<Self> {
-        let path = path.into();
-
-        if !path.is_file() {
-            return Err(AppError::Io(std::io::Error::new(
-                std::io::ErrorKind::NotFound,
-                format!("ファイル '{}' は存在しません", path.display()),
-            )));
-        }
+        let file = ReadableFilePath::new(path)?;
 
-        let metadata = fs::metadata(&path)?;
+        let metadata = fs::metadata(&*file)?;
         if metadata.permissions().readonly() {
             return Err(AppError::Io(std::io::Error::new(
                 std::io::ErrorKind::PermissionDenied,
-                format!("ファイル '{}' に書き込み権限がありません", path.display()),
+                format!("ファイル '{}' に書き込み権限がありません", file.display()),
             )));
         }
 
-        Ok(Self(path))
+        Ok(Self(file))
+    }
+
+    pub fn with_max_content_size(self, max_content_size: u64) -> Self {
+        Self(self.0.with_max_content_size(max_content_size))
     }
 
     pub fn read_content(&self) -> AppResult<String> {
-        std::fs::read_to_string(&self.0).map_err(AppError::Io)
+        self.0.read_content()
     }
 }
 
@@ -164,4 +164,62 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), "");
     }
+
+    #[test]
+    fn writable_file_path_read_content_succeeds_at_max_content_size() {
+        // ===== Arrange =====
+        let temp_file = create_temp_file(&"a".repeat(16));
+        let writable_file = WritableFilePath::new(temp_file.path())
+            .unwrap()
+            .with_max_content_size(16);
+
+        // ===== Act =====
+        let result = writable_file.read_content();
+
+        // ===== Assert =====
+        assert_eq!(result.unwrap().len(), 16);
+    }
+
+    #[test]
+    fn writable_file_path_read_content_fails_over_max_content_size() {
+        // ===== Arrange =====
+        let temp_file = create_temp_file(&"a".repeat(17));
+        let writable_file = WritableFilePath::new(temp_file.path())
+            .unwrap()
+            .with_max_content_size(16);
+
+        // ===== Act =====
+        let result = writable_file.read_content();
+
+        // ===== Assert =====
+        match result.unwrap_err() {
+            AppError::Io(io_error) => {
+                assert_eq!(io_error.kind(), std::io::ErrorKind::InvalidInput);
+            }
+            _ => panic!("Expected Io error with InvalidInput kind"),
+        }
+    }
+
+    #[test]
+    fn writable_file_path_read_content_fails_with_invalid_utf8() {
+        // ===== Arrange =====
+        let temp_file = NamedTempFile::new().unwrap();
+        std::fs::write(&temp_file, [0x7b, 0xff, 0xfe, 0x7d]).unwrap();
+        let writable_file = WritableFilePath::new(temp_file.path()).unwrap();
+
+        // ===== Act =====
+        let result = writable_file.read_content();
+
+        // ===== Assert =====
+        match result.unwrap_err() {
+            AppError::Io(io_error) => {
+                assert_eq!(io_error.kind(), std::io::ErrorKind::InvalidData);
+                assert!(io_error.to_string().contains("UTF-8"));
+                assert!(io_error
+                    .to_string()
+                    .contains(temp_file.path().to_str().unwrap()));
+            }
+            _ => panic!("Expected Io error with InvalidData kind"),
+        }
+    }
 }
@@ -1,2 +1,6 @@
+pub mod copy_options;
 pub mod directory_path;
+pub mod file_operations;
 pub mod file_path;
+pub mod storage_backend;
+pub mod transfer_lock;
@@ -0,0 +1 @@
+pub use infra::storage_backend::{LocalStorageBackend, SharedStorageBackend, StorageBackend};
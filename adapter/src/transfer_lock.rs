@@ -0,0 +1 @@
+pub use infra::transfer_lock::{TransferLock, LOCK_FILE_NAME};
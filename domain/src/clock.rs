@@ -0,0 +1,26 @@
+use chrono::{DateTime, Local};
+
+/// 曜日の判定などに使う現在時刻の取得元。テストや手動実行で時刻を固定できるようにする
+pub trait Clock {
+    fn now(&self) -> DateTime<Local>;
+}
+
+/// システムの現在時刻を返す既定の時計
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Local> {
+        Local::now()
+    }
+}
+
+/// 常に同じ時刻を返す時計
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(pub DateTime<Local>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Local> {
+        self.0
+    }
+}
@@ -1,29 +1,377 @@
-use adapter::directory_path::{
-    virtual_directory_path::VirtualDirectoryPath, writable_directory_path::WritableDirectoryPath,
+use adapter::{
+    copy_options::CopyOptions,
+    directory_path::{
+        virtual_directory_path::VirtualDirectoryPath,
+        writable_directory_path::WritableDirectoryPath,
+    },
 };
-use chrono::Local;
-use shared::error::AppResult;
+use chrono::{DateTime, FixedOffset};
+use shared::error::{AppError, AppResult};
+use std::path::{Path, PathBuf};
 
-use crate::config::destination_directory_path::path_template_renderer::PathTemplateRenderer;
+use crate::config::{
+    destination_directory_path::path_template_renderer::PathTemplateRenderer,
+    directory_mode::DirectoryMode, path_expansion, timezone::ConfigTimeZone,
+    weekday_set::WeekDaySet,
+};
 
 mod path_template_renderer;
 
-pub struct DestinationDirectoryPath(WritableDirectoryPath);
+/// 日付を展開した移動先のパス。設定の構築ではディレクトリを作成せず、移動時に `create` で作成する
+#[derive(Clone)]
+pub struct DestinationDirectoryPath {
+    path: PathBuf,
+    mode: Option<DirectoryMode>,
+    // 別の日時で展開し直せるよう、展開前のテンプレートと展開の設定を保持する
+    template: String,
+    date_offset_days: i64,
+    snap_to_weekdays: Option<WeekDaySet>,
+}
 
 impl DestinationDirectoryPath {
-    pub fn new(path: String) -> AppResult<Self> {
-        let template = VirtualDirectoryPath::new(path)?;
-        let writable_dir = PathTemplateRenderer::new(template)
-            .render(&Local::now())?
-            .create_writable_directory_path()?;
-        Ok(Self(writable_dir))
+    pub fn new(
+        path: String,
+        timezone: &ConfigTimeZone,
+        mode: Option<DirectoryMode>,
+        date_offset_days: i64,
+        snap_to_weekdays: Option<&WeekDaySet>,
+    ) -> AppResult<Self> {
+        Self {
+            path: PathBuf::new(),
+            mode,
+            template: path_expansion::expand(&path)?,
+            date_offset_days,
+            snap_to_weekdays: snap_to_weekdays.cloned(),
+        }
+        .rendered_at(&timezone.now())
+    }
+
+    /// 指定した日時で日付を展開し直す。差し替えた時計の日付で移動先を決めるために使う
+    pub fn rendered_at(&self, now: &DateTime<FixedOffset>) -> AppResult<Self> {
+        let rendered = PathTemplateRenderer::new(self.template.clone())
+            .with_date_offset_days(self.date_offset_days)
+            .with_snap_to_weekdays(self.snap_to_weekdays.clone())
+            .render(now)?;
+        if rendered.exists() && !rendered.is_dir() {
+            return Err(AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::AlreadyExists,
+                format!(
+                    "移動先 '{}' はディレクトリではありません",
+                    rendered.display()
+                ),
+            )));
+        }
+        if !rendered.exists() {
+            // 親ディレクトリが読み取り専用だと移動の開始時の作成で失敗するため、設定の構築の時点で検出する
+            VirtualDirectoryPath::new(&rendered)?.ensure_creatable()?;
+        }
+        Ok(Self {
+            path: rendered,
+            ..self.clone()
+        })
+    }
+
+    /// 日ごとに異なるプレースホルダーを含まない移動先は、以前の実行と重なり空でなくなって失敗するため拒否する
+    pub fn ensure_templated(path: &str) -> AppResult<()> {
+        if PathTemplateRenderer::new(path).has_daily_placeholder() {
+            return Ok(());
+        }
+        Err(AppError::Io(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!(
+                "移動先 '{}' に日付のプレースホルダー（{{dd}} や日を含む {{strftime:...}}）が含まれていないため、2回目以降の実行で移動先が空でなくなります",
+                path
+            ),
+        )))
+    }
+
+    /// 移動先ディレクトリを作成する。既存のディレクトリはそのまま使用し、空であるかは転送前の検証で確認する
+    pub fn create(&self) -> AppResult<WritableDirectoryPath> {
+        match self.existing()? {
+            Some(writable_dir) => Ok(writable_dir),
+            None => VirtualDirectoryPath::new(&self.path)?
+                .create_writable_directory_path_with_mode(self.mode.map(|mode| mode.bits())),
+        }
+    }
+
+    /// 移動先と同じ親ディレクトリに、コピー先とする一時ディレクトリ（`<移動先>.tmp-<pid>`）を作成する
+    pub fn create_staging(&self) -> AppResult<WritableDirectoryPath> {
+        let staging = self.staging_path()?;
+        // 同じプロセス ID で中断した以前の実行の残りは、内容を信用できないため作り直す
+        if staging.is_dir() {
+            WritableDirectoryPath::new(&staging)?.remove()?;
+        }
+        VirtualDirectoryPath::new(staging)?
+            .create_writable_directory_path_with_mode(self.mode.map(|mode| mode.bits()))
+    }
+
+    /// 一時ディレクトリを移動先にリネームし、移動先の内容をまとめて入れ替える
+    pub fn replace_with(&self, staging: WritableDirectoryPath) -> AppResult<WritableDirectoryPath> {
+        staging.rename_replacing(&self.path)
+    }
+
+    fn staging_path(&self) -> AppResult<PathBuf> {
+        let file_name = self.path.file_name().ok_or_else(|| {
+            AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "移動先 '{}' の隣に一時ディレクトリを作成できません",
+                    self.path.display()
+                ),
+            ))
+        })?;
+        let mut staging_name = file_name.to_os_string();
+        staging_name.push(format!(".tmp-{}", std::process::id()));
+        Ok(self.path.with_file_name(staging_name))
+    }
+
+    /// 新たに作成するディレクトリに設定するパーミッション
+    pub fn mode(&self) -> Option<DirectoryMode> {
+        self.mode
+    }
+
+    /// まだ作成されていない移動先は空として扱う
+    pub fn is_empty(&self) -> AppResult<bool> {
+        self.existing()?
+            .map_or(Ok(true), |writable_dir| writable_dir.is_empty())
+    }
+
+    pub fn is_nested_with(&self, other: &Path) -> AppResult<bool> {
+        match self.existing()? {
+            Some(writable_dir) => writable_dir.is_nested_with(other),
+            None => VirtualDirectoryPath::new(&self.path)?.is_nested_with(other),
+        }
+    }
+
+    pub fn available_space(&self) -> AppResult<u64> {
+        match self.existing()? {
+            Some(writable_dir) => writable_dir.available_space(),
+            None => VirtualDirectoryPath::new(&self.path)?.available_space(),
+        }
+    }
+
+    pub fn list_entries(&self, options: &CopyOptions) -> AppResult<Vec<PathBuf>> {
+        self.existing()?.map_or(Ok(Vec::new()), |writable_dir| {
+            writable_dir.list_entries(options)
+        })
+    }
+
+    fn existing(&self) -> AppResult<Option<WritableDirectoryPath>> {
+        if self.path.is_dir() {
+            WritableDirectoryPath::new(&self.path).map(Some)
+        } else {
+            Ok(None)
+        }
     }
 }
 
 impl std::ops::Deref for DestinationDirectoryPath {
-    type Target = WritableDirectoryPath;
+    type Target = PathBuf;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn destination_directory_path_rendered_at_uses_given_date() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let destination = DestinationDirectoryPath::new(
+            format!("{}/{{yyyy}}-{{mm}}-{{dd}}", temp_dir.path().display()),
+            &ConfigTimeZone::default(),
+            None,
+            0,
+            None,
+        )
+        .unwrap();
+        let date = chrono::Local
+            .with_ymd_and_hms(2024, 1, 4, 12, 0, 0)
+            .unwrap()
+            .fixed_offset();
+
+        // ===== Act =====
+        let result = destination.rendered_at(&date).unwrap();
+
+        // ===== Assert =====
+        assert_eq!(*result, temp_dir.path().join("2024-01-04"));
+    }
+
+    #[test]
+    fn destination_directory_path_does_not_create_directory_until_requested() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("new").join("dest");
+
+        // ===== Act =====
+        let destination = DestinationDirectoryPath::new(
+            path.to_str().unwrap().to_string(),
+            &ConfigTimeZone::default(),
+            None,
+            0,
+            None,
+        )
+        .unwrap();
+
+        // ===== Assert =====
+        assert!(!temp_dir.path().join("new").exists());
+        assert!(destination.is_empty().unwrap());
+        assert!(destination.available_space().unwrap() > 0);
+        destination.create().unwrap();
+        assert!(path.is_dir());
+    }
+
+    #[test]
+    fn destination_directory_path_reuses_existing_empty_directory() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("dest");
+        fs::create_dir(&path).unwrap();
+
+        // ===== Act =====
+        let result = DestinationDirectoryPath::new(
+            path.to_str().unwrap().to_string(),
+            &ConfigTimeZone::default(),
+            None,
+            0,
+            None,
+        );
+
+        // ===== Assert =====
+        let destination = result.unwrap();
+        assert_eq!(destination.as_path(), path.as_path());
+        assert!(destination.is_empty().unwrap());
+    }
+
+    #[test]
+    fn destination_directory_path_accepts_existing_non_empty_directory_for_later_validation() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("dest");
+        fs::create_dir(&path).unwrap();
+        fs::write(path.join("existing.txt"), "existing").unwrap();
+
+        // ===== Act =====
+        let result = DestinationDirectoryPath::new(
+            path.to_str().unwrap().to_string(),
+            &ConfigTimeZone::default(),
+            None,
+            0,
+            None,
+        );
+
+        // ===== Assert =====
+        // 空でない場合の中止（または --force による上書き）は転送前の検証で判断する
+        let destination = result.unwrap();
+        assert!(!destination.is_empty().unwrap());
+    }
+
+    #[test]
+    fn destination_directory_path_fails_when_path_is_a_file() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("dest");
+        fs::write(&path, "file").unwrap();
+
+        // ===== Act =====
+        let result = DestinationDirectoryPath::new(
+            path.to_str().unwrap().to_string(),
+            &ConfigTimeZone::default(),
+            None,
+            0,
+            None,
+        );
+
+        // ===== Assert =====
+        assert!(result.is_err());
+    }
+
+    #[test]
+    #[allow(clippy::permissions_set_readonly_false)]
+    fn destination_directory_path_fails_early_under_readonly_parent() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let parent = temp_dir.path().join("readonly");
+        fs::create_dir(&parent).unwrap();
+        let mut perms = fs::metadata(&parent).unwrap().permissions();
+        perms.set_readonly(true);
+        fs::set_permissions(&parent, perms.clone()).unwrap();
+        let path = parent.join("2024").join("01");
+        // root などは権限ビットに関わらず書き込めるため、実際に書き込めない場合のみ失敗を期待する
+        let writable = fs::write(parent.join("probe"), "").is_ok();
+        let _ = fs::remove_file(parent.join("probe"));
+
+        // ===== Act =====
+        let result = DestinationDirectoryPath::new(
+            path.to_str().unwrap().to_string(),
+            &ConfigTimeZone::default(),
+            None,
+            0,
+            None,
+        );
+
+        // ===== Assert =====
+        perms.set_readonly(false);
+        fs::set_permissions(&parent, perms).unwrap();
+        if writable {
+            assert!(result.is_ok());
+            return;
+        }
+        let Err(AppError::Io(error)) = result else {
+            panic!("読み取り専用の親ディレクトリで失敗するはずです");
+        };
+        assert_eq!(error.kind(), std::io::ErrorKind::PermissionDenied);
+        assert!(
+            error.to_string().contains(&parent.display().to_string()),
+            "{}",
+            error
+        );
+        assert!(!parent.join("2024").exists());
+    }
+
+    #[test]
+    fn destination_directory_path_ensure_templated_accepts_date_placeholder() {
+        // ===== Arrange =====
+        let paths = [
+            "/backup/{yyyy}-{mm}-{dd}",
+            "/backup/{strftime:%F}",
+            "/backup/{strftime:%Y%j}",
+        ];
+
+        for path in paths {
+            // ===== Act =====
+            let result = DestinationDirectoryPath::ensure_templated(path);
+
+            // ===== Assert =====
+            assert!(result.is_ok(), "{}", path);
+        }
+    }
+
+    #[test]
+    fn destination_directory_path_ensure_templated_rejects_static_path() {
+        // ===== Arrange =====
+        // エスケープされた括弧はプレースホルダーではなく、曜日や月は繰り返し同じ値になる
+        let paths = [
+            "/backup/photos",
+            "/backup/{{dd}}",
+            "/backup/{weekday}",
+            "/backup/{yyyy}/{mon}",
+            "/backup/{strftime:%A_%%d}",
+        ];
+
+        for path in paths {
+            // ===== Act =====
+            let result = DestinationDirectoryPath::ensure_templated(path);
+
+            // ===== Assert =====
+            assert!(matches!(result, Err(AppError::Io(_))), "{}", path);
+        }
     }
 }
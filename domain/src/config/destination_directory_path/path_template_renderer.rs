@@ -1,6 +1,13 @@
-use adapter::directory_path::virtual_directory_path::VirtualDirectoryPath;
-use chrono::{DateTime, Datelike, Local};
+use chrono::{
+    format::{Fixed, Item, Numeric, StrftimeItems},
+    DateTime, Datelike, TimeDelta, TimeZone, Timelike,
+};
 use shared::error::{AppError, AppResult};
+use std::path::PathBuf;
+
+use crate::config::weekday_set::WeekDaySet;
+
+const STRFTIME_PREFIX: &str = "strftime:";
 
 trait PadLeft {
     fn pad_left(&self, width: usize, pad_char: char) -> String;
@@ -20,40 +27,185 @@ impl PadLeft for u32 {
 
 #[derive(Debug)]
 pub struct PathTemplateRenderer {
-    template: VirtualDirectoryPath,
+    template: String,
+    date_offset_days: i64,
+    snap_to_weekdays: Option<WeekDaySet>,
 }
 
 impl PathTemplateRenderer {
-    pub fn new(template: VirtualDirectoryPath) -> Self {
-        Self { template }
+    pub fn new(template: impl Into<String>) -> Self {
+        Self {
+            template: template.into(),
+            date_offset_days: 0,
+            snap_to_weekdays: None,
+        }
     }
 
-    pub fn render(&self, date: &DateTime<Local>) -> AppResult<VirtualDirectoryPath> {
-        let rendered_template = self
-            .template
-            .to_str()?
-            .replace("{yyyy}", &date.year().to_string())
-            .replace("{mm}", &date.month().pad_left(2, '0'))
-            .replace("{dd}", &date.day().pad_left(2, '0'));
+    /// 日付をずらしてから展開する（`-1` で前日の日付になる）
+    pub fn with_date_offset_days(self, date_offset_days: i64) -> Self {
+        Self {
+            date_offset_days,
+            ..self
+        }
+    }
 
-        if rendered_template.contains("{") || rendered_template.contains("}") {
+    /// 日付を、いずれかの曜日に一致する直近の日（当日を含む）に戻してから展開する
+    ///
+    /// 月曜日のジョブが火曜日にずれ込んで実行されても、月曜日の日付で保存される。日付のずれの後に適用する。
+    pub fn with_snap_to_weekdays(self, weekdays: Option<WeekDaySet>) -> Self {
+        Self {
+            snap_to_weekdays: weekdays,
+            ..self
+        }
+    }
+
+    /// 日ごとに異なる値になるプレースホルダー（`{dd}` や日を含む strftime 書式）を含む場合に `true` を返す
+    ///
+    /// `{weekday}` や `{mon}` などは毎週・毎年同じ値に戻り、移動先が重なるため含めない。
+    pub fn has_daily_placeholder(&self) -> bool {
+        self.placeholders().into_iter().any(|placeholder| {
+            match placeholder.strip_prefix(STRFTIME_PREFIX) {
+                Some(format) => StrftimeItems::new(format).any(|item| {
+                    matches!(
+                        item,
+                        Item::Numeric(Numeric::Day | Numeric::Ordinal | Numeric::Timestamp, _)
+                            | Item::Fixed(Fixed::RFC2822 | Fixed::RFC3339)
+                    )
+                }),
+                None => placeholder == "dd",
+            }
+        })
+    }
+
+    /// エスケープされた括弧を除いた、括弧内の文字列
+    fn placeholders(&self) -> Vec<&str> {
+        let mut placeholders = Vec::new();
+        let mut rest = self.template.as_str();
+        while let Some(start) = rest.find('{') {
+            let after = &rest[start + 1..];
+            if let Some(escaped) = after.strip_prefix('{') {
+                rest = escaped;
+                continue;
+            }
+            let Some(end) = after.find('}') else {
+                break;
+            };
+            placeholders.push(&after[..end]);
+            rest = &after[end + 1..];
+        }
+        placeholders
+    }
+
+    pub fn render<Tz: TimeZone>(&self, date: &DateTime<Tz>) -> AppResult<PathBuf>
+    where
+        Tz::Offset: std::fmt::Display,
+    {
+        let date = date.clone() + TimeDelta::days(self.date_offset_days);
+        let date = &match &self.snap_to_weekdays {
+            Some(weekdays) => weekdays.previous_occurrence(&date),
+            None => date,
+        };
+        let template = self.template.as_str();
+        let mut rendered_template = String::with_capacity(template.len());
+        let mut chars = template.chars().peekable();
+        let mut unknown_placeholders = Vec::new();
+
+        while let Some(c) = chars.next() {
+            match c {
+                // `{{` と `}}` はエスケープされた括弧としてそのまま出力する
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    rendered_template.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    rendered_template.push('}');
+                }
+                '{' => {
+                    let mut placeholder = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => placeholder.push(c),
+                            None => return Err(Self::unexpected_bracket_error(template)),
+                        }
+                    }
+                    // 不明なプレースホルダーはすべて集めてからまとめて報告する
+                    match Self::render_placeholder(&placeholder, date)? {
+                        Some(rendered) => rendered_template.push_str(&rendered),
+                        None => unknown_placeholders.push(placeholder),
+                    }
+                }
+                '}' => return Err(Self::unexpected_bracket_error(template)),
+                _ => rendered_template.push(c),
+            }
+        }
+
+        if !unknown_placeholders.is_empty() {
             return Err(AppError::Io(std::io::Error::new(
                 std::io::ErrorKind::InvalidInput,
                 format!(
-                    "想定されていない文字列が括弧で囲われています: {}",
-                    rendered_template
+                    "不明なプレースホルダーが含まれています: {} ({})",
+                    unknown_placeholders.join(", "),
+                    template
                 ),
             )));
         }
 
-        VirtualDirectoryPath::new(rendered_template)
+        Ok(PathBuf::from(rendered_template))
+    }
+
+    fn render_placeholder<Tz: TimeZone>(
+        placeholder: &str,
+        date: &DateTime<Tz>,
+    ) -> AppResult<Option<String>>
+    where
+        Tz::Offset: std::fmt::Display,
+    {
+        let rendered = match placeholder {
+            "yyyy" => date.year().to_string(),
+            "yy" => (date.year().rem_euclid(100) as u32).pad_left(2, '0'),
+            "mon" => date.format("%b").to_string(),
+            "weekday" => date.format("%A").to_string(),
+            "mm" => date.month().pad_left(2, '0'),
+            "dd" => date.day().pad_left(2, '0'),
+            "HH" => date.hour().pad_left(2, '0'),
+            "MM" => date.minute().pad_left(2, '0'),
+            "SS" => date.second().pad_left(2, '0'),
+            _ => match placeholder.strip_prefix(STRFTIME_PREFIX) {
+                Some(format) => Self::render_strftime(format, date)?,
+                None => return Ok(None),
+            },
+        };
+        Ok(Some(rendered))
+    }
+
+    fn render_strftime<Tz: TimeZone>(format: &str, date: &DateTime<Tz>) -> AppResult<String>
+    where
+        Tz::Offset: std::fmt::Display,
+    {
+        let items = StrftimeItems::new(format).parse().map_err(|_| {
+            AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("無効なstrftime書式が指定されています: {}", format),
+            ))
+        })?;
+        Ok(date.format_with_items(items.iter()).to_string())
+    }
+
+    fn unexpected_bracket_error(template: &str) -> AppError {
+        AppError::Io(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("想定されていない文字列が括弧で囲われています: {}", template),
+        ))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::TimeZone;
+    use crate::config::weekday::WeekDay;
+    use chrono::Local;
 
     #[test]
     fn pad_left() {
@@ -65,7 +217,207 @@ mod tests {
     #[test]
     fn path_template_rendering_failure_when_invalid_template() {
         // ===== Arrange =====
-        let template = VirtualDirectoryPath::new("/test/files/{yyyy}/{invalid}/{dd}").unwrap();
+        let template = "/test/files/{yyyy}/{invalid}/{dd}";
+        let renderer = PathTemplateRenderer::new(template);
+        let date = Local.with_ymd_and_hms(2024, 3, 14, 0, 0, 0).unwrap();
+
+        // ===== Act =====
+        let result = renderer.render(&date);
+
+        // ===== Assert =====
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn path_template_rendering_failure_lists_all_unknown_placeholders() {
+        // ===== Arrange =====
+        let template = "/{yyyy}/{foo}/{bar}";
+        let renderer = PathTemplateRenderer::new(template);
+        let date = Local.with_ymd_and_hms(2024, 3, 14, 0, 0, 0).unwrap();
+
+        // ===== Act =====
+        let result = renderer.render(&date);
+
+        // ===== Assert =====
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains("foo, bar"));
+    }
+
+    #[test]
+    fn path_template_rendering_substitutes_date_and_time_placeholders() {
+        // ===== Arrange =====
+        let template = "/test/files/{yyyy}/{mm}/{dd}/{HH}{MM}{SS}";
+        let renderer = PathTemplateRenderer::new(template);
+        let date = Local.with_ymd_and_hms(2024, 3, 14, 7, 5, 9).unwrap();
+
+        // ===== Act =====
+        let result = renderer.render(&date).unwrap();
+
+        // ===== Assert =====
+        assert_eq!(result.to_str().unwrap(), "/test/files/2024/03/14/070509");
+    }
+
+    #[test]
+    fn path_template_rendering_substitutes_two_digit_year_and_month_abbreviation() {
+        // ===== Arrange =====
+        let template = "/arc/{yy}/{mon}";
+        let renderer = PathTemplateRenderer::new(template);
+        let date = Local.with_ymd_and_hms(2024, 3, 14, 0, 0, 0).unwrap();
+
+        // ===== Act =====
+        let result = renderer.render(&date).unwrap();
+
+        // ===== Assert =====
+        assert_eq!(result.to_str().unwrap(), "/arc/24/Mar");
+    }
+
+    #[test]
+    fn path_template_rendering_pads_two_digit_year() {
+        // ===== Arrange =====
+        let template = "/arc/{yy}-{mon}";
+        let renderer = PathTemplateRenderer::new(template);
+        let date = Local.with_ymd_and_hms(2005, 3, 1, 0, 0, 0).unwrap();
+
+        // ===== Act =====
+        let result = renderer.render(&date).unwrap();
+
+        // ===== Assert =====
+        assert_eq!(result.to_str().unwrap(), "/arc/05-Mar");
+    }
+
+    #[test]
+    fn path_template_rendering_substitutes_weekday_name() {
+        // ===== Arrange =====
+        let template = "/backups/{weekday}/{yyyy}";
+        let renderer = PathTemplateRenderer::new(template);
+        // 2024年3月14日は木曜日
+        let date = Local.with_ymd_and_hms(2024, 3, 14, 0, 0, 0).unwrap();
+
+        // ===== Act =====
+        let result = renderer.render(&date).unwrap();
+
+        // ===== Assert =====
+        assert_eq!(result.to_str().unwrap(), "/backups/Thursday/2024");
+    }
+
+    #[test]
+    fn path_template_rendering_applies_negative_date_offset() {
+        // ===== Arrange =====
+        let template = "/test/files/{yyyy}/{mm}/{dd}";
+        let renderer = PathTemplateRenderer::new(template).with_date_offset_days(-1);
+        let date = Local.with_ymd_and_hms(2024, 3, 1, 2, 0, 0).unwrap();
+
+        // ===== Act =====
+        let result = renderer.render(&date).unwrap();
+
+        // ===== Assert =====
+        assert_eq!(result.to_str().unwrap(), "/test/files/2024/02/29");
+    }
+
+    #[test]
+    fn path_template_rendering_applies_positive_date_offset() {
+        // ===== Arrange =====
+        let template = "/test/files/{yyyy}/{mm}/{dd}";
+        let renderer = PathTemplateRenderer::new(template).with_date_offset_days(1);
+        let date = Local.with_ymd_and_hms(2024, 12, 31, 23, 0, 0).unwrap();
+
+        // ===== Act =====
+        let result = renderer.render(&date).unwrap();
+
+        // ===== Assert =====
+        assert_eq!(result.to_str().unwrap(), "/test/files/2025/01/01");
+    }
+
+    #[test]
+    fn path_template_rendering_snaps_date_to_most_recent_weekday() {
+        // ===== Arrange =====
+        let template = "/test/files/{yyyy}/{mm}/{dd}";
+        let renderer = PathTemplateRenderer::new(template)
+            .with_snap_to_weekdays(Some(WeekDaySet::from(WeekDay::Monday)));
+        // 2024年3月4日は月曜日
+        let cases = [
+            (2024, 3, 4, "/test/files/2024/03/04"),
+            (2024, 3, 6, "/test/files/2024/03/04"),
+            (2024, 3, 3, "/test/files/2024/02/26"),
+        ];
+
+        for (year, month, day, expected) in cases {
+            let date = Local.with_ymd_and_hms(year, month, day, 9, 0, 0).unwrap();
+
+            // ===== Act =====
+            let result = renderer.render(&date).unwrap();
+
+            // ===== Assert =====
+            assert_eq!(result.to_str().unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn path_template_rendering_snaps_date_after_applying_offset() {
+        // ===== Arrange =====
+        let template = "/test/files/{yyyy}/{mm}/{dd}";
+        let renderer = PathTemplateRenderer::new(template)
+            .with_date_offset_days(-1)
+            .with_snap_to_weekdays(Some(
+                WeekDaySet::new(vec![WeekDay::Monday, WeekDay::Thursday]).unwrap(),
+            ));
+        // 2024年3月9日は土曜日。1日戻した金曜日から直近の木曜日に戻す
+        let date = Local.with_ymd_and_hms(2024, 3, 9, 2, 0, 0).unwrap();
+
+        // ===== Act =====
+        let result = renderer.render(&date).unwrap();
+
+        // ===== Assert =====
+        assert_eq!(result.to_str().unwrap(), "/test/files/2024/03/07");
+    }
+
+    #[test]
+    fn path_template_rendering_substitutes_strftime_section() {
+        // ===== Arrange =====
+        let template = "/backups/{strftime:%Y-%m-%d_%A}/{HH}";
+        let renderer = PathTemplateRenderer::new(template);
+        let date = Local.with_ymd_and_hms(2024, 3, 14, 7, 0, 0).unwrap();
+
+        // ===== Act =====
+        let result = renderer.render(&date).unwrap();
+
+        // ===== Assert =====
+        assert_eq!(result.to_str().unwrap(), "/backups/2024-03-14_Thursday/07");
+    }
+
+    #[test]
+    fn path_template_rendering_failure_when_invalid_strftime_format() {
+        // ===== Arrange =====
+        let template = "/backups/{strftime:%Y-%Q}";
+        let renderer = PathTemplateRenderer::new(template);
+        let date = Local.with_ymd_and_hms(2024, 3, 14, 0, 0, 0).unwrap();
+
+        // ===== Act =====
+        let result = renderer.render(&date);
+
+        // ===== Assert =====
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains("%Y-%Q"));
+    }
+
+    #[test]
+    fn path_template_rendering_keeps_escaped_braces() {
+        // ===== Arrange =====
+        let template = "/test/{{}}/{{{yyyy}}}";
+        let renderer = PathTemplateRenderer::new(template);
+        let date = Local.with_ymd_and_hms(2024, 3, 14, 0, 0, 0).unwrap();
+
+        // ===== Act =====
+        let result = renderer.render(&date).unwrap();
+
+        // ===== Assert =====
+        assert_eq!(result.to_str().unwrap(), "/test/{}/{2024}");
+    }
+
+    #[test]
+    fn path_template_rendering_failure_when_unknown_placeholder_next_to_escaped_brace() {
+        // ===== Arrange =====
+        let template = "/test/{{}}/{foo}";
         let renderer = PathTemplateRenderer::new(template);
         let date = Local.with_ymd_and_hms(2024, 3, 14, 0, 0, 0).unwrap();
 
@@ -76,10 +428,44 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn path_template_rendering_failure_when_bracket_is_unbalanced() {
+        // ===== Arrange =====
+        let date = Local.with_ymd_and_hms(2024, 3, 14, 0, 0, 0).unwrap();
+
+        for template in ["/test/{yyyy", "/test/yyyy}"] {
+            let renderer = PathTemplateRenderer::new(template);
+
+            // ===== Act =====
+            let result = renderer.render(&date);
+
+            // ===== Assert =====
+            assert!(result.is_err());
+        }
+    }
+
+    #[test]
+    fn path_template_rendering_uses_date_of_given_timezone() {
+        // ===== Arrange =====
+        let template = "/test/files/{yyyy}/{mm}/{dd}";
+        let renderer = PathTemplateRenderer::new(template);
+        // UTCでは12月31日、東京では1月1日
+        let date = chrono::Utc
+            .with_ymd_and_hms(2023, 12, 31, 20, 0, 0)
+            .unwrap()
+            .with_timezone(&chrono_tz::Asia::Tokyo);
+
+        // ===== Act =====
+        let result = renderer.render(&date).unwrap();
+
+        // ===== Assert =====
+        assert_eq!(result.to_str().unwrap(), "/test/files/2024/01/01");
+    }
+
     #[test]
     fn path_template_rendering_success_when_valid_template() {
         // ===== Arrange =====
-        let template = VirtualDirectoryPath::new("/test/files/{yyyy}/{mm}/{dd}").unwrap();
+        let template = "/test/files/{yyyy}/{mm}/{dd}";
         let renderer = PathTemplateRenderer::new(template);
         let date = Local.with_ymd_and_hms(2024, 3, 14, 0, 0, 0).unwrap();
 
@@ -0,0 +1,61 @@
+use shared::error::AppError;
+
+// 8進数で指定されたパーミッション（例: "0750"）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DirectoryMode(u32);
+
+impl DirectoryMode {
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+}
+
+impl TryFrom<String> for DirectoryMode {
+    type Error = AppError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let digits = value.strip_prefix("0o").unwrap_or(&value);
+        match u32::from_str_radix(digits, 8) {
+            Ok(mode) if !digits.is_empty() && mode <= 0o7777 => Ok(DirectoryMode(mode)),
+            _ => Err(AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("無効なパーミッションが指定されています: {}", value),
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directory_mode_creation_from_octal_string() {
+        // ===== Arrange =====
+        let modes = ["0750", "750", "0o750"];
+
+        // ===== Act =====
+        let results: Vec<DirectoryMode> = modes
+            .iter()
+            .map(|mode| DirectoryMode::try_from(mode.to_string()).unwrap())
+            .collect();
+
+        // ===== Assert =====
+        assert!(results.iter().all(|result| result.bits() == 0o750));
+    }
+
+    #[test]
+    fn directory_mode_creation_from_invalid_string() {
+        // ===== Arrange =====
+        let modes = ["0758", "rwxr-x---", "", "17777"];
+
+        // ===== Act =====
+        let results: Vec<_> = modes
+            .iter()
+            .map(|mode| DirectoryMode::try_from(mode.to_string()))
+            .collect();
+
+        // ===== Assert =====
+        assert!(results.iter().all(|result| result.is_err()));
+    }
+}
@@ -0,0 +1,58 @@
+use shared::error::{AppError, AppResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EmptySourcePolicy {
+    /// 警告を出力して処理を続ける
+    #[default]
+    Warn,
+    /// エラーとして処理を中止する
+    Error,
+}
+
+impl EmptySourcePolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmptySourcePolicy::Warn => "warn",
+            EmptySourcePolicy::Error => "error",
+        }
+    }
+}
+
+impl TryFrom<String> for EmptySourcePolicy {
+    type Error = AppError;
+
+    fn try_from(value: String) -> AppResult<Self> {
+        match value.to_lowercase().as_str() {
+            "warn" => Ok(EmptySourcePolicy::Warn),
+            "error" => Ok(EmptySourcePolicy::Error),
+            _ => Err(AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("無効な空の移動元の扱いが指定されています: {}", value),
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_source_policy_creation_from_string() {
+        // ===== Arrange =====
+        let values = ["warn", "ERROR"];
+
+        // ===== Act =====
+        let results: Vec<EmptySourcePolicy> = values
+            .iter()
+            .map(|value| EmptySourcePolicy::try_from(value.to_string()).unwrap())
+            .collect();
+
+        // ===== Assert =====
+        assert_eq!(
+            results,
+            vec![EmptySourcePolicy::Warn, EmptySourcePolicy::Error]
+        );
+        assert!(EmptySourcePolicy::try_from("ignore".to_string()).is_err());
+    }
+}
@@ -1,14 +1,239 @@
+use adapter::copy_options::{
+    BandwidthLimit, BufferSize, CaseSensitivity, GlobPatterns, HashAlgorithm, RetryPolicy,
+    SymlinkPolicy, VerificationMode,
+};
+
+use chrono::{DateTime, FixedOffset, Local, TimeDelta};
+use serde_json::json;
+
 use crate::config::{
-    destination_directory_path::DestinationDirectoryPath,
-    source_directory_path::SourceDirectoryPath, weekday::WeekDay,
+    destination_directory_path::DestinationDirectoryPath, empty_source_policy::EmptySourcePolicy,
+    source_directory_path::SourceDirectoryPath, timezone::ConfigTimeZone, weekday::WeekDay,
+    weekday_set::WeekDaySet,
 };
 
-pub(crate) mod destination_directory_path;
-pub(crate) mod source_directory_path;
-pub(crate) mod weekday;
+pub mod destination_directory_path;
+pub mod directory_mode;
+pub mod empty_source_policy;
+pub(crate) mod path_expansion;
+pub mod source_directory_path;
+pub mod timezone;
+pub mod weekday;
+pub mod weekday_set;
 
 pub struct Config {
     pub source_directory_path: SourceDirectoryPath,
     pub dest_directory_path: DestinationDirectoryPath,
-    pub weekday: WeekDay,
+    pub weekday: WeekDaySet,
+    pub timezone: ConfigTimeZone,
+    pub hash_algorithm: HashAlgorithm,
+    pub exclude_patterns: GlobPatterns,
+    // 指定された場合は一致するファイルのみを移動する
+    pub include_patterns: GlobPatterns,
+    pub symlink_policy: SymlinkPolicy,
+    pub retry_policy: RetryPolicy,
+    pub verification_mode: VerificationMode,
+    pub buffer_size: BufferSize,
+    // 指定された場合は、共有の NAS などで回線を占有しないよう書き込みの平均速度を抑える
+    pub bandwidth_limit: Option<BandwidthLimit>,
+    // 大文字と小文字を区別しないファイルシステムへの移動では、検証時に名前を区別せずに比較する
+    pub case_sensitivity: CaseSensitivity,
+    // false の場合は移動元を削除せず、検証済みのコピーのみを行う
+    pub delete_source: bool,
+    pub empty_source_policy: EmptySourcePolicy,
+    // true の場合は移動先に外部ツールで検証できる SHA256SUMS を書き出す
+    pub write_checksums: bool,
+    // 指定された場合は、移動元直下を深さ 0 としてその深さまでのエントリのみを移動する
+    pub max_depth: Option<usize>,
+    // true の場合は移動先に同じ内容のファイルが既にあればコピーを省略し、中断後の再実行を軽くする
+    pub skip_existing_matching: bool,
+    // true の場合は Unix で拡張属性（xattr、Linux では POSIX ACL を含む）も移動先に複製する
+    pub preserve_xattrs: bool,
+    // true の場合はファイルを複数のスレッドで並列にコピーする（ハードリンクは個別のファイルになる）
+    pub parallel: bool,
+    pub date_offset_days: i64,
+    // 曜日の判定にも日付のずれを適用する（月曜深夜の実行を日曜のジョブとして扱う場合など）
+    pub date_offset_applies_to_weekday: bool,
+    // 移動先の日付を、指定された曜日のうち直近の日（当日を含む）に戻す
+    pub snap_date_to_weekday: bool,
+}
+
+impl Config {
+    /// 曜日の判定に使う、設定のタイムゾーンでの日時（判定に日付のずれを適用する場合はずらした日時）
+    pub fn weekday_date(&self, now: &DateTime<Local>) -> DateTime<FixedOffset> {
+        self.timezone.localize(now) + self.weekday_offset()
+    }
+
+    /// 曜日の判定を通る、設定のタイムゾーンで次に実行される日時（今日が該当すれば今日）
+    pub fn next_run(&self, now: &DateTime<Local>) -> DateTime<FixedOffset> {
+        self.weekday.next_occurrence(self.weekday_date(now)) - self.weekday_offset()
+    }
+
+    fn weekday_offset(&self) -> TimeDelta {
+        match self.date_offset_applies_to_weekday {
+            true => TimeDelta::days(self.date_offset_days),
+            false => TimeDelta::zero(),
+        }
+    }
+
+    /// 既定値の適用や日付の展開を済ませた、実際に使われる設定の値（`--print-config` で表示する）
+    pub fn effective_values(&self, now: DateTime<Local>) -> serde_json::Value {
+        json!({
+            "source_directory_path": self.source_directory_path.display().to_string(),
+            "destination_directory_path": self.dest_directory_path.display().to_string(),
+            "weekday": self.weekday.iter().map(WeekDay::to_string).collect::<Vec<_>>(),
+            "next_run": self.next_run(&now).format("%Y-%m-%d").to_string(),
+            "timezone": self.timezone.name(),
+            "hash_algorithm": self.hash_algorithm.as_str(),
+            "exclude_patterns": &*self.exclude_patterns,
+            "include_patterns": &*self.include_patterns,
+            "symlink_policy": self.symlink_policy.as_str(),
+            "retry": {
+                "max_attempts": self.retry_policy.max_attempts(),
+                "base_delay_ms": self.retry_policy.base_delay().as_millis(),
+            },
+            "directory_mode": self
+                .dest_directory_path
+                .mode()
+                .map(|mode| format!("{:04o}", mode.bits())),
+            "verification_mode": self.verification_mode.as_str(),
+            "buffer_size": self.buffer_size.bytes(),
+            "bandwidth_limit": self.bandwidth_limit.map(|limit| limit.bytes_per_second()),
+            "case_sensitivity": self.case_sensitivity.as_str(),
+            "delete_source": self.delete_source,
+            "empty_source_policy": self.empty_source_policy.as_str(),
+            "write_checksums": self.write_checksums,
+            "max_depth": self.max_depth,
+            "skip_existing_matching": self.skip_existing_matching,
+            "preserve_xattrs": self.preserve_xattrs,
+            "parallel": self.parallel,
+            "date_offset_days": self.date_offset_days,
+            "date_offset_applies_to_weekday": self.date_offset_applies_to_weekday,
+            "snap_date_to_weekday": self.snap_date_to_weekday,
+        })
+    }
+
+    /// 検証済みのパスと曜日から、その他の設定を既定値にした設定を作成する
+    pub fn new(
+        source_directory_path: SourceDirectoryPath,
+        dest_directory_path: DestinationDirectoryPath,
+        weekday: impl Into<WeekDaySet>,
+    ) -> Self {
+        Self {
+            source_directory_path,
+            dest_directory_path,
+            weekday: weekday.into(),
+            timezone: ConfigTimeZone::default(),
+            hash_algorithm: HashAlgorithm::default(),
+            exclude_patterns: GlobPatterns::default(),
+            include_patterns: GlobPatterns::default(),
+            symlink_policy: SymlinkPolicy::default(),
+            retry_policy: RetryPolicy::default(),
+            verification_mode: VerificationMode::default(),
+            buffer_size: BufferSize::default(),
+            bandwidth_limit: None,
+            case_sensitivity: CaseSensitivity::default(),
+            delete_source: true,
+            empty_source_policy: EmptySourcePolicy::default(),
+            write_checksums: false,
+            max_depth: None,
+            skip_existing_matching: false,
+            preserve_xattrs: false,
+            parallel: false,
+            date_offset_days: 0,
+            date_offset_applies_to_weekday: false,
+            snap_date_to_weekday: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::{Clock, FixedClock};
+    use crate::config::weekday::WeekDay;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn config_new_uses_defaults_for_optional_settings() {
+        // ===== Arrange =====
+        let dir = std::env::temp_dir().to_str().unwrap().to_string();
+        let timezone = ConfigTimeZone::default();
+        let source = SourceDirectoryPath::new(dir.clone()).unwrap();
+        let destination = DestinationDirectoryPath::new(dir, &timezone, None, 0, None).unwrap();
+
+        // ===== Act =====
+        let config = Config::new(source, destination, WeekDay::Thursday);
+
+        // ===== Assert =====
+        assert_eq!(config.weekday, WeekDaySet::from(WeekDay::Thursday));
+        assert_eq!(config.timezone, ConfigTimeZone::Local);
+        assert_eq!(config.hash_algorithm, HashAlgorithm::default());
+        assert!(config.exclude_patterns.is_empty());
+        assert_eq!(config.date_offset_days, 0);
+        assert!(config.delete_source);
+    }
+
+    #[test]
+    fn config_next_run_uses_configured_timezone_and_offset() {
+        // ===== Arrange =====
+        let dir = std::env::temp_dir().to_str().unwrap().to_string();
+        let timezone = ConfigTimeZone::try_from("Asia/Tokyo".to_string()).unwrap();
+        let source = SourceDirectoryPath::new(dir.clone()).unwrap();
+        let destination = DestinationDirectoryPath::new(dir, &timezone, None, 0, None).unwrap();
+        let mut config = Config::new(source, destination, WeekDay::Monday);
+        config.timezone = timezone;
+        // UTC では2024年1月1日（月）20時、東京では1月2日（火）5時
+        let now = Utc
+            .with_ymd_and_hms(2024, 1, 1, 20, 0, 0)
+            .unwrap()
+            .with_timezone(&Local);
+
+        // ===== Act =====
+        let next_run = config.next_run(&now);
+        config.date_offset_days = -1;
+        config.date_offset_applies_to_weekday = true;
+        let next_run_with_offset = config.next_run(&now);
+
+        // ===== Assert =====
+        assert_eq!(next_run.format("%Y-%m-%d").to_string(), "2024-01-08");
+        // 火曜日の実行を月曜日のジョブとして扱うため、東京の今日が該当する
+        assert_eq!(
+            next_run_with_offset.format("%Y-%m-%d").to_string(),
+            "2024-01-02"
+        );
+    }
+
+    #[test]
+    fn config_effective_values_show_rendered_destination() {
+        // ===== Arrange =====
+        let dir = std::env::temp_dir().to_str().unwrap().to_string();
+        let timezone = ConfigTimeZone::default();
+        let source = SourceDirectoryPath::new(dir.clone()).unwrap();
+        let destination = DestinationDirectoryPath::new(
+            format!("{}/{{yyyy}}-{{mm}}-{{dd}}", dir),
+            &timezone,
+            None,
+            0,
+            None,
+        )
+        .unwrap();
+        let mut config = Config::new(source, destination, WeekDay::Thursday);
+        // 2024年1月2日は火曜日。日付をまたいで実行しても結果が変わらないよう時刻を固定する
+        let now = FixedClock(Local.with_ymd_and_hms(2024, 1, 2, 23, 59, 59).unwrap()).now();
+        config.dest_directory_path = config
+            .dest_directory_path
+            .rendered_at(&config.timezone.localize(&now))
+            .unwrap();
+
+        // ===== Act =====
+        let values = config.effective_values(now);
+
+        // ===== Assert =====
+        let destination = values["destination_directory_path"].as_str().unwrap();
+        assert!(destination.ends_with("2024-01-02"), "{}", destination);
+        assert_eq!(values["next_run"], "2024-01-04");
+        assert_eq!(values["weekday"], json!(["Thu"]));
+        assert_eq!(values["hash_algorithm"], "sha256");
+    }
 }
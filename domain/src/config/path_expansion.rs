@@ -0,0 +1,155 @@
+use std::{env, path::Path};
+
+use shared::error::{AppError, AppResult};
+
+/// 展開やテンプレートの処理は文字列で行うため、UTF-8として扱えないパスはエラーにする
+pub(crate) fn path_to_string(path: &Path) -> AppResult<String> {
+    path.to_str()
+        .map(str::to_string)
+        .ok_or_else(|| AppError::InvalidPath(path.to_path_buf()))
+}
+
+/// フィールド全体を環境変数の値で置き換える指定の接頭辞（`env:SROW_SOURCE` など）
+const ENV_FIELD_PREFIX: &str = "env:";
+
+/// 先頭の `~` をホームディレクトリに、`$VAR` / `${VAR}` を環境変数の値に展開する
+///
+/// `env:NAME` の形式はパスの一部ではなくフィールド全体の指定として扱い、環境変数 NAME の値を展開したものを返す。
+pub(crate) fn expand(path: &str) -> AppResult<String> {
+    let path = match path.strip_prefix(ENV_FIELD_PREFIX) {
+        Some(name) => env::var(name)?,
+        None => path.to_string(),
+    };
+    let path = expand_home(&path)?;
+    expand_env_vars(&path)
+}
+
+fn expand_home(path: &str) -> AppResult<String> {
+    let rest = match path.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with(['/', '\\']) => rest,
+        _ => return Ok(path.to_string()),
+    };
+
+    let home = env::var("HOME").or_else(|_| env::var("USERPROFILE"))?;
+    Ok(format!("{}{}", home, rest))
+}
+
+fn expand_env_vars(path: &str) -> AppResult<String> {
+    let mut expanded = String::with_capacity(path.len());
+    let mut chars = path.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '$' {
+            expanded.push(c);
+            continue;
+        }
+
+        let name = if chars.peek() == Some(&'{') {
+            chars.next();
+            let mut name = String::new();
+            loop {
+                match chars.next() {
+                    Some('}') => break,
+                    Some(c) => name.push(c),
+                    None => {
+                        return Err(AppError::Io(std::io::Error::new(
+                            std::io::ErrorKind::InvalidInput,
+                            format!("環境変数の括弧が閉じられていません: {}", path),
+                        )))
+                    }
+                }
+            }
+            name
+        } else {
+            let mut name = String::new();
+            while let Some(&c) = chars.peek() {
+                if !(c.is_ascii_alphanumeric() || c == '_') {
+                    break;
+                }
+                name.push(c);
+                chars.next();
+            }
+            name
+        };
+
+        if name.is_empty() {
+            expanded.push('$');
+            continue;
+        }
+        expanded.push_str(&env::var(&name)?);
+    }
+
+    Ok(expanded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expand_replaces_environment_variables() {
+        // ===== Arrange =====
+        env::set_var("SROW_TEST_EXPAND_ROOT", "/backup");
+
+        // ===== Act =====
+        let plain = expand("$SROW_TEST_EXPAND_ROOT/incoming").unwrap();
+        let braced = expand("${SROW_TEST_EXPAND_ROOT}/{yyyy}").unwrap();
+
+        // ===== Assert =====
+        assert_eq!(plain, "/backup/incoming");
+        assert_eq!(braced, "/backup/{yyyy}");
+    }
+
+    #[test]
+    fn expand_replaces_leading_tilde_with_home_directory() {
+        // ===== Arrange =====
+        let home = env::var("HOME")
+            .or_else(|_| env::var("USERPROFILE"))
+            .unwrap();
+
+        // ===== Act =====
+        let result = expand("~/Downloads").unwrap();
+
+        // ===== Assert =====
+        assert_eq!(result, format!("{}/Downloads", home));
+    }
+
+    #[test]
+    fn expand_keeps_paths_without_variables() {
+        // ===== Act =====
+        let result = expand("/data/~user/$/files").unwrap();
+
+        // ===== Assert =====
+        assert_eq!(result, "/data/~user/$/files");
+    }
+
+    #[test]
+    fn expand_replaces_whole_field_with_env_indirection() {
+        // ===== Arrange =====
+        env::set_var("SROW_TEST_EXPAND_FIELD", "/mnt/nas/incoming");
+
+        // ===== Act =====
+        let result = expand("env:SROW_TEST_EXPAND_FIELD").unwrap();
+
+        // ===== Assert =====
+        assert_eq!(result, "/mnt/nas/incoming");
+    }
+
+    #[test]
+    fn expand_fails_with_undefined_env_indirection() {
+        // ===== Act =====
+        let result = expand("env:SROW_TEST_EXPAND_FIELD_UNDEFINED");
+
+        // ===== Assert =====
+        assert!(matches!(result, Err(AppError::Env(_))));
+    }
+
+    #[test]
+    fn expand_fails_with_undefined_variable() {
+        // ===== Act =====
+        let result = expand("$SROW_TEST_EXPAND_UNDEFINED/incoming");
+
+        // ===== Assert =====
+        assert!(matches!(result, Err(AppError::Env(_))));
+    }
+}
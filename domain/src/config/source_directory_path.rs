@@ -1,19 +1,40 @@
-use adapter::directory_path::readonly_directory_path::ReadonlyDirectoryPath;
+use adapter::directory_path::readable_directory_path::ReadableDirectoryPath;
 use shared::error::AppResult;
 
-pub struct SourceDirectoryPath(ReadonlyDirectoryPath);
+use crate::config::path_expansion;
+
+pub struct SourceDirectoryPath(ReadableDirectoryPath);
 
 impl SourceDirectoryPath {
     pub fn new(path: String) -> AppResult<Self> {
-        let path = ReadonlyDirectoryPath::try_from(path)?;
+        let path = ReadableDirectoryPath::try_from(path_expansion::expand(&path)?)?;
         Ok(Self(path))
     }
 }
 
 impl std::ops::Deref for SourceDirectoryPath {
-    type Target = ReadonlyDirectoryPath;
+    type Target = ReadableDirectoryPath;
 
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn source_directory_path_accepts_writable_directory() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().to_str().unwrap().to_string();
+
+        // ===== Act =====
+        let result = SourceDirectoryPath::new(path);
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+    }
+}
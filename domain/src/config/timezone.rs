@@ -0,0 +1,113 @@
+use chrono::{DateTime, FixedOffset, Local};
+use chrono_tz::Tz;
+use shared::error::AppError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConfigTimeZone {
+    #[default]
+    Local,
+    Named(Tz),
+}
+
+impl ConfigTimeZone {
+    pub fn localize(&self, date: &DateTime<Local>) -> DateTime<FixedOffset> {
+        match self {
+            ConfigTimeZone::Local => date.fixed_offset(),
+            ConfigTimeZone::Named(tz) => date.with_timezone(tz).fixed_offset(),
+        }
+    }
+
+    /// IANA のタイムゾーン名。指定がなければ `local` を返す
+    pub fn name(&self) -> &'static str {
+        match self {
+            ConfigTimeZone::Local => "local",
+            ConfigTimeZone::Named(tz) => tz.name(),
+        }
+    }
+
+    pub fn now(&self) -> DateTime<FixedOffset> {
+        self.localize(&Local::now())
+    }
+}
+
+impl TryFrom<String> for ConfigTimeZone {
+    type Error = AppError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse::<Tz>().map(ConfigTimeZone::Named).map_err(|_| {
+            AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("無効なタイムゾーンが指定されています: {}", value),
+            ))
+        })
+    }
+}
+
+impl TryFrom<Option<String>> for ConfigTimeZone {
+    type Error = AppError;
+
+    fn try_from(value: Option<String>) -> Result<Self, Self::Error> {
+        value.map_or(Ok(ConfigTimeZone::Local), Self::try_from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::weekday::WeekDay;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn timezone_creation_from_iana_name() {
+        // ===== Arrange =====
+        let name = "Asia/Tokyo";
+
+        // ===== Act =====
+        let result = ConfigTimeZone::try_from(name.to_string()).unwrap();
+
+        // ===== Assert =====
+        assert_eq!(result, ConfigTimeZone::Named(Tz::Asia__Tokyo));
+    }
+
+    #[test]
+    fn timezone_creation_from_invalid_name() {
+        // ===== Arrange =====
+        let name = "Invalid/Zone";
+
+        // ===== Act =====
+        let result = ConfigTimeZone::try_from(name.to_string());
+
+        // ===== Assert =====
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn timezone_defaults_to_local_when_unspecified() {
+        // ===== Act =====
+        let result = ConfigTimeZone::try_from(None).unwrap();
+
+        // ===== Assert =====
+        assert_eq!(result, ConfigTimeZone::Local);
+    }
+
+    #[test]
+    fn weekday_check_differs_between_timezones() {
+        // ===== Arrange =====
+        // UTCでは月曜日23時、東京では火曜日8時
+        let instant = Utc
+            .with_ymd_and_hms(2024, 1, 1, 23, 0, 0)
+            .unwrap()
+            .with_timezone(&Local);
+        let utc = ConfigTimeZone::try_from("UTC".to_string()).unwrap();
+        let tokyo = ConfigTimeZone::try_from("Asia/Tokyo".to_string()).unwrap();
+
+        // ===== Act =====
+        let utc_date = utc.localize(&instant);
+        let tokyo_date = tokyo.localize(&instant);
+
+        // ===== Assert =====
+        assert!(WeekDay::Monday.matches_weekday(&utc_date));
+        assert!(!WeekDay::Monday.matches_weekday(&tokyo_date));
+        assert!(WeekDay::Tuesday.matches_weekday(&tokyo_date));
+    }
+}
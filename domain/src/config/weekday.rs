@@ -1,8 +1,9 @@
 use chrono::Datelike;
-use chrono::{DateTime, Local};
+use chrono::{DateTime, TimeDelta, TimeZone};
 use shared::error::AppError;
+use std::{fmt, str::FromStr};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum WeekDay {
     Sunday = 0,
     Monday = 1,
@@ -13,9 +14,141 @@ pub enum WeekDay {
     Saturday = 6,
 }
 
+/// `TryFrom` が受け付ける表記（小文字）。日曜日から順に、曜日ごとに同じ数の表記を並べる
+#[rustfmt::skip]
+const ACCEPTED_TOKENS: &[&str] = &[
+    "sun", "sunday", "0", "日",
+    "mon", "monday", "1", "月",
+    "tue", "tuesday", "2", "火",
+    "wed", "wednesday", "3", "水",
+    "thu", "thursday", "4", "木",
+    "fri", "friday", "5", "金",
+    "sat", "saturday", "6", "土",
+];
+
+const TOKENS_PER_DAY: usize = 4;
+
+/// `WeekDayGroup::parse` が受け付ける表記（小文字）
+const WEEKDAYS_TOKENS: &[&str] = &["weekday", "weekdays", "平日"];
+const WEEKENDS_TOKENS: &[&str] = &["weekend", "weekends", "週末", "土日"];
+
+const DAYS_FROM_SUNDAY: [WeekDay; 7] = [
+    WeekDay::Sunday,
+    WeekDay::Monday,
+    WeekDay::Tuesday,
+    WeekDay::Wednesday,
+    WeekDay::Thursday,
+    WeekDay::Friday,
+    WeekDay::Saturday,
+];
+
 impl WeekDay {
-    pub fn matches_weekday(&self, date: &DateTime<Local>) -> bool {
-        date.weekday().num_days_from_sunday() == self.clone() as u32
+    /// 曜日として解釈できるすべての表記。大文字と小文字は区別しない
+    pub fn accepted_tokens() -> &'static [&'static str] {
+        ACCEPTED_TOKENS
+    }
+
+    pub fn matches_weekday<Tz: TimeZone>(&self, date: &DateTime<Tz>) -> bool {
+        WeekDay::from(date.weekday()) == *self
+    }
+
+    /// `from` 以降で最初にこの曜日となる日時を返す（`from` 自体がこの曜日であれば `from` を返す）
+    pub fn next_occurrence<Tz: TimeZone>(&self, from: DateTime<Tz>) -> DateTime<Tz> {
+        let today = i64::from(from.weekday().num_days_from_sunday());
+        let days = (self.clone() as i64 - today).rem_euclid(7);
+        from + TimeDelta::days(days)
+    }
+
+    /// `from` 以前で最後にこの曜日となった日時を返す（`from` 自体がこの曜日であれば `from` を返す）
+    pub fn previous_occurrence<Tz: TimeZone>(&self, from: &DateTime<Tz>) -> DateTime<Tz> {
+        let today = i64::from(from.weekday().num_days_from_sunday());
+        let days = (today - self.clone() as i64).rem_euclid(7);
+        from.clone() - TimeDelta::days(days)
+    }
+
+    pub fn matches_group<Tz: TimeZone>(date: &DateTime<Tz>, group: WeekDayGroup) -> bool {
+        group.days().contains(&WeekDay::from(date.weekday()))
+    }
+}
+
+/// 複数の曜日をまとめて指定するためのグループ
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeekDayGroup {
+    /// 月曜日から金曜日
+    Weekdays,
+    /// 土曜日と日曜日
+    Weekends,
+}
+
+impl WeekDayGroup {
+    pub fn days(&self) -> &'static [WeekDay] {
+        match self {
+            Self::Weekdays => &[
+                WeekDay::Monday,
+                WeekDay::Tuesday,
+                WeekDay::Wednesday,
+                WeekDay::Thursday,
+                WeekDay::Friday,
+            ],
+            Self::Weekends => &[WeekDay::Saturday, WeekDay::Sunday],
+        }
+    }
+
+    fn tokens(&self) -> &'static [&'static str] {
+        match self {
+            Self::Weekdays => WEEKDAYS_TOKENS,
+            Self::Weekends => WEEKENDS_TOKENS,
+        }
+    }
+
+    /// グループとして解釈できるすべての表記。大文字と小文字は区別しない
+    pub fn accepted_tokens() -> impl Iterator<Item = &'static str> {
+        WEEKDAYS_TOKENS.iter().chain(WEEKENDS_TOKENS).copied()
+    }
+
+    /// グループ名でなければ `None` を返し、個別の曜日として解釈させる
+    pub fn parse(value: &str) -> Option<Self> {
+        let token = value.to_lowercase();
+        [Self::Weekdays, Self::Weekends]
+            .into_iter()
+            .find(|group| group.tokens().contains(&token.as_str()))
+    }
+}
+
+impl From<chrono::Weekday> for WeekDay {
+    fn from(weekday: chrono::Weekday) -> Self {
+        match weekday {
+            chrono::Weekday::Mon => WeekDay::Monday,
+            chrono::Weekday::Tue => WeekDay::Tuesday,
+            chrono::Weekday::Wed => WeekDay::Wednesday,
+            chrono::Weekday::Thu => WeekDay::Thursday,
+            chrono::Weekday::Fri => WeekDay::Friday,
+            chrono::Weekday::Sat => WeekDay::Saturday,
+            chrono::Weekday::Sun => WeekDay::Sunday,
+        }
+    }
+}
+
+impl fmt::Display for WeekDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            WeekDay::Sunday => "Sun",
+            WeekDay::Monday => "Mon",
+            WeekDay::Tuesday => "Tue",
+            WeekDay::Wednesday => "Wed",
+            WeekDay::Thursday => "Thu",
+            WeekDay::Friday => "Fri",
+            WeekDay::Saturday => "Sat",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for WeekDay {
+    type Err = AppError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        WeekDay::try_from(value.to_string())
     }
 }
 
@@ -23,19 +156,24 @@ impl TryFrom<String> for WeekDay {
     type Error = AppError;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        match value.as_str() {
-            "Mon" => Ok(WeekDay::Monday),
-            "Tue" => Ok(WeekDay::Tuesday),
-            "Wed" => Ok(WeekDay::Wednesday),
-            "Thu" => Ok(WeekDay::Thursday),
-            "Fri" => Ok(WeekDay::Friday),
-            "Sat" => Ok(WeekDay::Saturday),
-            "Sun" => Ok(WeekDay::Sunday),
-            _ => Err(AppError::Io(std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                "無効な曜日が指定されています",
-            ))),
-        }
+        let token = value.to_lowercase();
+        ACCEPTED_TOKENS
+            .iter()
+            .position(|accepted| *accepted == token)
+            .map(|index| DAYS_FROM_SUNDAY[index / TOKENS_PER_DAY].clone())
+            .ok_or_else(|| {
+                AppError::Io(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!(
+                        "無効な曜日が指定されています: '{}'（指定できる値: {}、曜日のグループ: {}）",
+                        value,
+                        ACCEPTED_TOKENS.join(", "),
+                        WeekDayGroup::accepted_tokens()
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                ))
+            })
     }
 }
 
@@ -44,6 +182,85 @@ mod tests {
     use super::*;
     use chrono::{Local, TimeZone};
 
+    #[test]
+    fn weekday_matches_group_distinguishes_weekdays_and_weekends() {
+        // ===== Arrange =====
+        let tuesday = Local.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap();
+        let sunday = Local.with_ymd_and_hms(2024, 1, 7, 0, 0, 0).unwrap();
+
+        // ===== Act & Assert =====
+        assert!(WeekDay::matches_group(&tuesday, WeekDayGroup::Weekdays));
+        assert!(!WeekDay::matches_group(&sunday, WeekDayGroup::Weekdays));
+        assert!(WeekDay::matches_group(&sunday, WeekDayGroup::Weekends));
+        assert!(!WeekDay::matches_group(&tuesday, WeekDayGroup::Weekends));
+    }
+
+    #[test]
+    fn weekday_previous_occurrence_returns_same_or_preceding_date() {
+        // ===== Arrange =====
+        // 2024年1月1日は月曜日
+        let monday = Local.with_ymd_and_hms(2024, 1, 1, 9, 30, 0).unwrap();
+        let wednesday = Local.with_ymd_and_hms(2024, 1, 3, 23, 0, 0).unwrap();
+        let cases = [
+            (WeekDay::Monday, monday, (2024, 1, 1)),
+            (WeekDay::Monday, wednesday, (2024, 1, 1)),
+            (WeekDay::Tuesday, monday, (2023, 12, 26)),
+        ];
+
+        for (weekday, from, (year, month, day)) in cases {
+            // ===== Act =====
+            let result = weekday.previous_occurrence(&from);
+
+            // ===== Assert =====
+            assert_eq!(
+                (result.year(), result.month(), result.day()),
+                (year, month, day),
+                "{} from {}",
+                weekday,
+                from
+            );
+            assert_eq!(result.time(), from.time());
+        }
+    }
+
+    #[test]
+    fn weekday_next_occurrence_returns_same_or_following_date() {
+        // ===== Arrange =====
+        let wednesday = Local.with_ymd_and_hms(2024, 1, 3, 9, 30, 0).unwrap();
+        let saturday = Local.with_ymd_and_hms(2024, 1, 6, 9, 30, 0).unwrap();
+        let cases = [
+            (WeekDay::Wednesday, wednesday, (2024, 1, 3)),
+            (WeekDay::Thursday, wednesday, (2024, 1, 4)),
+            (WeekDay::Tuesday, wednesday, (2024, 1, 9)),
+            (WeekDay::Sunday, saturday, (2024, 1, 7)),
+            (WeekDay::Saturday, saturday, (2024, 1, 6)),
+        ];
+
+        for (weekday, from, (year, month, day)) in cases {
+            // ===== Act =====
+            let next = weekday.next_occurrence(from);
+
+            // ===== Assert =====
+            assert_eq!((next.year(), next.month(), next.day()), (year, month, day));
+            assert!(weekday.matches_weekday(&next));
+        }
+    }
+
+    #[test]
+    fn weekday_orders_from_sunday_to_saturday() {
+        // ===== Arrange =====
+        let mut weekdays = vec![WeekDay::Saturday, WeekDay::Monday, WeekDay::Sunday];
+
+        // ===== Act =====
+        weekdays.sort();
+
+        // ===== Assert =====
+        assert_eq!(
+            weekdays,
+            [WeekDay::Sunday, WeekDay::Monday, WeekDay::Saturday]
+        );
+    }
+
     #[test]
     fn weekday_matches_thursday() {
         // ===== Arrange =====
@@ -56,6 +273,41 @@ mod tests {
         assert!(result);
     }
 
+    #[test]
+    fn weekday_round_trips_through_display_and_from_str() {
+        // ===== Arrange =====
+        let weekdays = [
+            WeekDay::Sunday,
+            WeekDay::Monday,
+            WeekDay::Tuesday,
+            WeekDay::Wednesday,
+            WeekDay::Thursday,
+            WeekDay::Friday,
+            WeekDay::Saturday,
+        ];
+
+        for weekday in weekdays {
+            // ===== Act =====
+            let result: WeekDay = weekday.to_string().parse().unwrap();
+
+            // ===== Assert =====
+            assert_eq!(result, weekday);
+        }
+    }
+
+    #[test]
+    fn weekday_creation_from_chrono_weekday() {
+        // ===== Arrange =====
+        let weekday = chrono::Weekday::Sat;
+
+        // ===== Act =====
+        let result = WeekDay::from(weekday);
+
+        // ===== Assert =====
+        assert_eq!(result, WeekDay::Saturday);
+        assert_eq!(result.to_string(), weekday.to_string());
+    }
+
     #[test]
     fn weekday_creation_from_string() {
         // ===== Arrange =====
@@ -68,6 +320,132 @@ mod tests {
         assert_eq!(result, WeekDay::Thursday);
     }
 
+    #[test]
+    fn weekday_creation_from_full_name() {
+        // ===== Arrange =====
+        let weekday = "Monday";
+
+        // ===== Act =====
+        let result = WeekDay::try_from(weekday.to_string()).unwrap();
+
+        // ===== Assert =====
+        assert_eq!(result, WeekDay::Monday);
+    }
+
+    #[test]
+    fn weekday_creation_ignores_case() {
+        // ===== Arrange =====
+        let weekdays = ["THURSDAY", "thursday", "thu", "THU"];
+
+        // ===== Act =====
+        let results: Vec<WeekDay> = weekdays
+            .iter()
+            .map(|weekday| WeekDay::try_from(weekday.to_string()).unwrap())
+            .collect();
+
+        // ===== Assert =====
+        assert!(results.iter().all(|result| *result == WeekDay::Thursday));
+    }
+
+    #[test]
+    fn weekday_accepted_tokens_all_parse() {
+        // ===== Act & Assert =====
+        assert_eq!(
+            WeekDay::accepted_tokens().len(),
+            TOKENS_PER_DAY * DAYS_FROM_SUNDAY.len()
+        );
+        for token in WeekDay::accepted_tokens() {
+            assert!(WeekDay::from_str(token).is_ok(), "{}", token);
+            assert!(
+                WeekDay::from_str(&token.to_uppercase()).is_ok(),
+                "{}",
+                token
+            );
+        }
+    }
+
+    #[test]
+    fn weekday_creation_error_lists_accepted_tokens() {
+        // ===== Act =====
+        let result = WeekDay::from_str("Funday");
+
+        // ===== Assert =====
+        let Err(AppError::Io(error)) = result else {
+            panic!("無効な曜日で失敗するはずです");
+        };
+        assert!(error.to_string().contains("'Funday'"));
+        assert!(error.to_string().contains("sun, sunday, 0, 日, mon"));
+        assert!(error
+            .to_string()
+            .contains("weekday, weekdays, 平日, weekend"));
+    }
+
+    #[test]
+    fn weekday_group_accepted_tokens_all_parse() {
+        // ===== Act & Assert =====
+        for token in WeekDayGroup::accepted_tokens() {
+            assert!(WeekDayGroup::parse(token).is_some(), "{}", token);
+            assert!(
+                WeekDayGroup::parse(&token.to_uppercase()).is_some(),
+                "{}",
+                token
+            );
+        }
+        assert_eq!(WeekDayGroup::parse("土日"), Some(WeekDayGroup::Weekends));
+        assert_eq!(WeekDayGroup::parse("mon"), None);
+    }
+
+    #[test]
+    fn weekday_creation_from_number() {
+        // ===== Arrange =====
+        let weekdays = ["0", "6"];
+
+        // ===== Act =====
+        let results: Vec<WeekDay> = weekdays
+            .iter()
+            .map(|weekday| WeekDay::try_from(weekday.to_string()).unwrap())
+            .collect();
+
+        // ===== Assert =====
+        assert_eq!(results, vec![WeekDay::Sunday, WeekDay::Saturday]);
+    }
+
+    #[test]
+    fn weekday_creation_from_out_of_range_number() {
+        // ===== Arrange =====
+        let weekday = "7";
+
+        // ===== Act =====
+        let result = WeekDay::try_from(weekday.to_string());
+
+        // ===== Assert =====
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn weekday_creation_from_japanese_kanji() {
+        // ===== Arrange =====
+        let weekday = "月";
+
+        // ===== Act =====
+        let result = WeekDay::try_from(weekday.to_string()).unwrap();
+
+        // ===== Assert =====
+        assert_eq!(result, WeekDay::Monday);
+    }
+
+    #[test]
+    fn weekday_creation_from_misspelled_full_name() {
+        // ===== Arrange =====
+        let weekday = "Thursdays";
+
+        // ===== Act =====
+        let result = WeekDay::try_from(weekday.to_string());
+
+        // ===== Assert =====
+        assert!(result.is_err());
+    }
+
     #[test]
     fn weekday_creation_from_invalid_string() {
         // ===== Arrange =====
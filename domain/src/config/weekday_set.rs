@@ -0,0 +1,166 @@
+use chrono::{DateTime, TimeZone};
+use shared::error::{AppError, AppResult};
+
+use crate::config::weekday::{WeekDay, WeekDayGroup};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeekDaySet(Vec<WeekDay>);
+
+impl WeekDaySet {
+    pub fn new(weekdays: Vec<WeekDay>) -> AppResult<Self> {
+        if weekdays.is_empty() {
+            return Err(AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "曜日が指定されていません",
+            )));
+        }
+
+        let mut unique = Vec::with_capacity(weekdays.len());
+        for weekday in weekdays {
+            if !unique.contains(&weekday) {
+                unique.push(weekday);
+            }
+        }
+
+        Ok(Self(unique))
+    }
+
+    pub fn matches_weekday<Tz: TimeZone>(&self, date: &DateTime<Tz>) -> bool {
+        self.0.iter().any(|weekday| weekday.matches_weekday(date))
+    }
+
+    /// いずれかの曜日に一致する、`from` 以前で最も近い日時を返す
+    pub fn previous_occurrence<Tz: TimeZone>(&self, from: &DateTime<Tz>) -> DateTime<Tz> {
+        self.0
+            .iter()
+            .map(|weekday| weekday.previous_occurrence(from))
+            .max()
+            .unwrap_or_else(|| from.clone())
+    }
+
+    /// いずれかの曜日に一致する、`from` 以降で最も近い日時を返す
+    pub fn next_occurrence<Tz: TimeZone>(&self, from: DateTime<Tz>) -> DateTime<Tz> {
+        self.0
+            .iter()
+            .map(|weekday| weekday.next_occurrence(from.clone()))
+            .min()
+            .unwrap_or(from)
+    }
+}
+
+impl From<WeekDay> for WeekDaySet {
+    fn from(weekday: WeekDay) -> Self {
+        Self(vec![weekday])
+    }
+}
+
+impl TryFrom<Vec<String>> for WeekDaySet {
+    type Error = AppError;
+
+    fn try_from(values: Vec<String>) -> Result<Self, Self::Error> {
+        let mut weekdays = Vec::with_capacity(values.len());
+        for value in values {
+            match WeekDayGroup::parse(&value) {
+                Some(group) => weekdays.extend_from_slice(group.days()),
+                None => weekdays.push(WeekDay::try_from(value)?),
+            }
+        }
+        Self::new(weekdays)
+    }
+}
+
+impl std::ops::Deref for WeekDaySet {
+    type Target = [WeekDay];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Local;
+
+    #[test]
+    fn weekday_set_matches_any_member() {
+        // ===== Arrange =====
+        let weekdays = WeekDaySet::try_from(vec!["Sat".to_string(), "Sun".to_string()]).unwrap();
+        let saturday = Local.with_ymd_and_hms(2024, 1, 6, 0, 0, 0).unwrap();
+        let sunday = Local.with_ymd_and_hms(2024, 1, 7, 0, 0, 0).unwrap();
+
+        // ===== Act =====
+        let matches_saturday = weekdays.matches_weekday(&saturday);
+        let matches_sunday = weekdays.matches_weekday(&sunday);
+
+        // ===== Assert =====
+        assert!(matches_saturday);
+        assert!(matches_sunday);
+    }
+
+    #[test]
+    fn weekday_set_does_not_match_other_days() {
+        // ===== Arrange =====
+        let weekdays = WeekDaySet::try_from(vec!["Sat".to_string(), "Sun".to_string()]).unwrap();
+        // 2024年1月1日は月曜日
+        let monday = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+        // ===== Act =====
+        let result = weekdays.matches_weekday(&monday);
+
+        // ===== Assert =====
+        assert!(!result);
+    }
+
+    #[test]
+    fn weekday_set_expands_weekday_and_weekend_groups() {
+        // ===== Arrange =====
+        let weekday = WeekDaySet::try_from(vec!["Weekday".to_string()]).unwrap();
+        let weekend = WeekDaySet::try_from(vec!["Weekend".to_string()]).unwrap();
+        // 2024年1月2日は火曜日、2024年1月7日は日曜日
+        let tuesday = Local.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap();
+        let sunday = Local.with_ymd_and_hms(2024, 1, 7, 0, 0, 0).unwrap();
+
+        // ===== Act & Assert =====
+        assert!(weekday.matches_weekday(&tuesday));
+        assert!(!weekday.matches_weekday(&sunday));
+        assert!(weekend.matches_weekday(&sunday));
+        assert!(!weekend.matches_weekday(&tuesday));
+    }
+
+    #[test]
+    fn weekday_set_removes_duplicates() {
+        // ===== Arrange =====
+        let weekdays = vec!["Sat".to_string(), "saturday".to_string()];
+
+        // ===== Act =====
+        let result = WeekDaySet::try_from(weekdays).unwrap();
+
+        // ===== Assert =====
+        assert_eq!(&*result, &[WeekDay::Saturday]);
+    }
+
+    #[test]
+    fn weekday_set_creation_fails_when_empty() {
+        // ===== Arrange =====
+        let weekdays: Vec<String> = Vec::new();
+
+        // ===== Act =====
+        let result = WeekDaySet::try_from(weekdays);
+
+        // ===== Assert =====
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn weekday_set_creation_fails_with_invalid_member() {
+        // ===== Arrange =====
+        let weekdays = vec!["Sat".to_string(), "Invalid".to_string()];
+
+        // ===== Act =====
+        let result = WeekDaySet::try_from(weekdays);
+
+        // ===== Assert =====
+        assert!(result.is_err());
+    }
+}
@@ -1,41 +1,250 @@
 use shared::error::AppResult;
+use std::path::PathBuf;
 
 use crate::{
-    config::{
-        destination_directory_path::DestinationDirectoryPath,
-        source_directory_path::SourceDirectoryPath, weekday::WeekDay, Config,
-    },
+    config::{weekday::WeekDay, Config},
     config_builder::ConfigBuilder,
+    transfer_builder::TransferBuilder,
 };
 
 pub struct ArgConfigBuilder {
-    source_directory_path: String,
-    destination_directory_path: String,
-    weekday: String,
+    builder: TransferBuilder,
 }
 
 impl ArgConfigBuilder {
     pub fn new(
-        source_directory_path: String,
-        destination_directory_path: String,
-        weekday: String,
+        source_directory_path: PathBuf,
+        destination_directory_path: PathBuf,
+        weekday: Vec<String>,
     ) -> AppResult<Self> {
+        let builder = TransferBuilder::new()
+            .source(source_directory_path)
+            .destination(destination_directory_path);
         Ok(Self {
-            source_directory_path,
-            destination_directory_path,
-            weekday,
+            builder: weekday.into_iter().fold(builder, TransferBuilder::weekday),
         })
     }
+
+    /// ライブラリから使う場合に、曜日を文字列ではなく `WeekDay` や `chrono::Weekday` で指定する
+    pub fn from_weekdays<W: Into<WeekDay>>(
+        source_directory_path: PathBuf,
+        destination_directory_path: PathBuf,
+        weekdays: impl IntoIterator<Item = W>,
+    ) -> AppResult<Self> {
+        let builder = TransferBuilder::new()
+            .source(source_directory_path)
+            .destination(destination_directory_path);
+        Ok(Self {
+            builder: weekdays
+                .into_iter()
+                .fold(builder, TransferBuilder::typed_weekday),
+        })
+    }
+
+    fn map_builder(self, f: impl FnOnce(TransferBuilder) -> TransferBuilder) -> Self {
+        Self {
+            builder: f(self.builder),
+        }
+    }
+
+    pub fn with_timezone(self, timezone: Option<String>) -> Self {
+        self.map_builder(|builder| {
+            timezone
+                .into_iter()
+                .fold(builder, TransferBuilder::timezone)
+        })
+    }
+
+    pub fn with_hash_algorithm(self, hash_algorithm: Option<String>) -> Self {
+        self.map_builder(|builder| {
+            hash_algorithm
+                .into_iter()
+                .fold(builder, TransferBuilder::hash_algorithm)
+        })
+    }
+
+    pub fn with_exclude_patterns(self, exclude_patterns: Vec<String>) -> Self {
+        self.map_builder(|builder| {
+            exclude_patterns
+                .into_iter()
+                .fold(builder, TransferBuilder::exclude)
+        })
+    }
+
+    pub fn with_include_patterns(self, include_patterns: Vec<String>) -> Self {
+        self.map_builder(|builder| {
+            include_patterns
+                .into_iter()
+                .fold(builder, TransferBuilder::include)
+        })
+    }
+
+    pub fn with_symlink_policy(self, symlink_policy: Option<String>) -> Self {
+        self.map_builder(|builder| {
+            symlink_policy
+                .into_iter()
+                .fold(builder, TransferBuilder::symlink_policy)
+        })
+    }
+
+    pub fn with_retry(self, max_attempts: Option<u32>, base_delay_ms: Option<u64>) -> Self {
+        self.map_builder(|builder| match max_attempts {
+            Some(max_attempts) => builder.retry(max_attempts, base_delay_ms.unwrap_or_default()),
+            None => builder,
+        })
+    }
+
+    pub fn with_directory_mode(self, directory_mode: Option<String>) -> Self {
+        self.map_builder(|builder| {
+            directory_mode
+                .into_iter()
+                .fold(builder, TransferBuilder::directory_mode)
+        })
+    }
+
+    pub fn with_verification_mode(self, verification_mode: Option<String>) -> Self {
+        self.map_builder(|builder| {
+            verification_mode
+                .into_iter()
+                .fold(builder, TransferBuilder::verification_mode)
+        })
+    }
+
+    pub fn with_buffer_size(self, buffer_size: Option<usize>) -> Self {
+        self.map_builder(|builder| {
+            buffer_size
+                .into_iter()
+                .fold(builder, TransferBuilder::buffer_size)
+        })
+    }
+
+    pub fn with_bandwidth_limit(self, bandwidth_limit: Option<u64>) -> Self {
+        self.map_builder(|builder| {
+            bandwidth_limit
+                .into_iter()
+                .fold(builder, TransferBuilder::bandwidth_limit)
+        })
+    }
+
+    pub fn with_case_sensitivity(self, case_sensitivity: Option<String>) -> Self {
+        self.map_builder(|builder| {
+            case_sensitivity
+                .into_iter()
+                .fold(builder, TransferBuilder::case_sensitivity)
+        })
+    }
+
+    pub fn with_max_depth(self, max_depth: Option<usize>) -> Self {
+        self.map_builder(|builder| {
+            max_depth
+                .into_iter()
+                .fold(builder, TransferBuilder::max_depth)
+        })
+    }
+
+    pub fn with_skip_existing_matching(self, skip_existing_matching: bool) -> Self {
+        self.map_builder(|builder| builder.skip_existing_matching(skip_existing_matching))
+    }
+
+    pub fn with_require_date_placeholder(self, require_date_placeholder: bool) -> Self {
+        self.map_builder(|builder| builder.require_date_placeholder(require_date_placeholder))
+    }
+
+    pub fn with_preserve_xattrs(self, preserve_xattrs: bool) -> Self {
+        self.map_builder(|builder| builder.preserve_xattrs(preserve_xattrs))
+    }
+
+    pub fn with_parallel(self, parallel: bool) -> Self {
+        self.map_builder(|builder| builder.parallel(parallel))
+    }
+
+    pub fn with_delete_source(self, delete_source: bool) -> Self {
+        self.map_builder(|builder| builder.delete_source(delete_source))
+    }
+
+    pub fn with_write_checksums(self, write_checksums: bool) -> Self {
+        self.map_builder(|builder| builder.write_checksums(write_checksums))
+    }
+
+    pub fn with_empty_source_policy(self, empty_source_policy: Option<String>) -> Self {
+        self.map_builder(|builder| {
+            empty_source_policy
+                .into_iter()
+                .fold(builder, TransferBuilder::empty_source_policy)
+        })
+    }
+
+    pub fn with_date_offset(self, date_offset_days: Option<i64>, applies_to_weekday: bool) -> Self {
+        self.map_builder(|builder| {
+            builder
+                .date_offset_days(date_offset_days.unwrap_or_default())
+                .date_offset_applies_to_weekday(applies_to_weekday)
+        })
+    }
+
+    pub fn with_snap_date_to_weekday(self, snap_date_to_weekday: bool) -> Self {
+        self.map_builder(|builder| builder.snap_date_to_weekday(snap_date_to_weekday))
+    }
 }
 
 impl ConfigBuilder for ArgConfigBuilder {
     fn build(&self) -> AppResult<Config> {
-        Ok(Config {
-            source_directory_path: SourceDirectoryPath::new(self.source_directory_path.clone())?,
-            dest_directory_path: DestinationDirectoryPath::new(
-                self.destination_directory_path.clone(),
-            )?,
-            weekday: WeekDay::try_from(self.weekday.clone())?,
-        })
+        self.builder.build_config()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::weekday_set::WeekDaySet;
+    use shared::error::AppError;
+    use tempfile::TempDir;
+
+    #[test]
+    fn arg_config_builder_builds_config_from_typed_weekdays() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source = temp_dir.path().join("source");
+        std::fs::create_dir(&source).unwrap();
+        let builder = ArgConfigBuilder::from_weekdays(
+            source,
+            temp_dir.path().join("dest"),
+            [chrono::Weekday::Sat, chrono::Weekday::Sun],
+        )
+        .unwrap();
+
+        // ===== Act =====
+        let result = builder.build();
+
+        // ===== Assert =====
+        let config = result.unwrap();
+        assert_eq!(
+            config.weekday,
+            WeekDaySet::new(vec![WeekDay::Saturday, WeekDay::Sunday]).unwrap()
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn arg_config_builder_fails_cleanly_with_non_utf8_path() {
+        use std::{ffi::OsString, os::unix::ffi::OsStringExt};
+
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let mut source_path = temp_dir.path().as_os_str().to_os_string();
+        source_path.push("/");
+        source_path.push(OsString::from_vec(vec![0x66, 0x6f, 0xff, 0x6f]));
+        let builder = ArgConfigBuilder::new(
+            PathBuf::from(source_path),
+            temp_dir.path().join("dest"),
+            vec!["Mon".to_string()],
+        )
+        .unwrap();
+
+        // ===== Act =====
+        let result = builder.build();
+
+        // ===== Assert =====
+        assert!(matches!(result, Err(AppError::InvalidPath(_))));
     }
 }
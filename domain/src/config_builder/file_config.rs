@@ -0,0 +1,189 @@
+use serde::Deserialize;
+use shared::error::{AppError, AppResult};
+
+use std::path::{Path, PathBuf};
+
+use crate::{
+    config::{path_expansion, Config},
+    transfer_builder::TransferBuilder,
+};
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct FileConfig {
+    source_directory_path: String,
+    destination_directory_path: String,
+    weekday: FileWeekDay,
+    #[serde(default)]
+    timezone: Option<String>,
+    #[serde(default)]
+    hash_algorithm: Option<String>,
+    #[serde(default)]
+    exclude_patterns: Vec<String>,
+    #[serde(default)]
+    include_patterns: Vec<String>,
+    #[serde(default)]
+    symlink_policy: Option<String>,
+    #[serde(default)]
+    retry: Option<FileRetryPolicy>,
+    #[serde(default)]
+    directory_mode: Option<String>,
+    #[serde(default)]
+    verification_mode: Option<String>,
+    #[serde(default)]
+    buffer_size: Option<usize>,
+    #[serde(default)]
+    bandwidth_limit: Option<u64>,
+    #[serde(default)]
+    case_sensitivity: Option<String>,
+    #[serde(default = "default_delete_source")]
+    delete_source: bool,
+    #[serde(default)]
+    empty_source_policy: Option<String>,
+    #[serde(default)]
+    write_checksums: bool,
+    #[serde(default)]
+    max_depth: Option<usize>,
+    #[serde(default)]
+    skip_existing_matching: bool,
+    #[serde(default)]
+    require_date_placeholder: bool,
+    #[serde(default)]
+    preserve_xattrs: bool,
+    #[serde(default)]
+    parallel: bool,
+    #[serde(default)]
+    date_offset_days: i64,
+    #[serde(default)]
+    date_offset_applies_to_weekday: bool,
+    #[serde(default)]
+    snap_date_to_weekday: bool,
+}
+
+fn default_delete_source() -> bool {
+    true
+}
+
+#[derive(Debug, Deserialize)]
+struct FileRetryPolicy {
+    max_attempts: u32,
+    #[serde(default)]
+    base_delay_ms: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum FileWeekDay {
+    Single(String),
+    Multiple(Vec<String>),
+}
+
+impl FileConfig {
+    /// 相対パスを、実行時のカレントディレクトリではなく設定ファイルのあるディレクトリから解決する
+    pub(crate) fn relative_to(self, config_dir: &Path) -> AppResult<Self> {
+        // 移動先のプレースホルダーとして解釈されないよう、基準のディレクトリの括弧はエスケープする
+        let escaped_dir = path_expansion::path_to_string(config_dir)?
+            .replace('{', "{{")
+            .replace('}', "}}");
+        Ok(Self {
+            source_directory_path: Self::resolve(
+                &self.source_directory_path,
+                &path_expansion::path_to_string(config_dir)?,
+            )?,
+            destination_directory_path: Self::resolve(
+                &self.destination_directory_path,
+                &escaped_dir,
+            )?,
+            ..self
+        })
+    }
+
+    fn resolve(path: &str, base: &str) -> AppResult<String> {
+        let expanded = path_expansion::expand(path)?;
+        match Path::new(&expanded).is_relative() {
+            true => path_expansion::path_to_string(&Path::new(base).join(expanded)),
+            false => Ok(expanded),
+        }
+    }
+}
+
+/// 設定ファイルのあるディレクトリを絶対パスで返す
+pub(crate) fn config_dir(config_path: &Path) -> AppResult<PathBuf> {
+    let config_path = std::path::absolute(config_path)?;
+    Ok(config_path
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or(config_path))
+}
+
+impl From<FileWeekDay> for Vec<String> {
+    fn from(weekday: FileWeekDay) -> Self {
+        match weekday {
+            FileWeekDay::Single(weekday) => vec![weekday],
+            FileWeekDay::Multiple(weekdays) => weekdays,
+        }
+    }
+}
+
+impl TryFrom<FileConfig> for Config {
+    type Error = AppError;
+
+    fn try_from(file_config: FileConfig) -> AppResult<Self> {
+        let mut builder = TransferBuilder::new()
+            .source(file_config.source_directory_path)
+            .destination(file_config.destination_directory_path)
+            .delete_source(file_config.delete_source)
+            .write_checksums(file_config.write_checksums)
+            .skip_existing_matching(file_config.skip_existing_matching)
+            .require_date_placeholder(file_config.require_date_placeholder)
+            .preserve_xattrs(file_config.preserve_xattrs)
+            .parallel(file_config.parallel)
+            .date_offset_days(file_config.date_offset_days)
+            .date_offset_applies_to_weekday(file_config.date_offset_applies_to_weekday)
+            .snap_date_to_weekday(file_config.snap_date_to_weekday);
+        builder = Vec::<String>::from(file_config.weekday)
+            .into_iter()
+            .fold(builder, TransferBuilder::weekday);
+        builder = file_config
+            .exclude_patterns
+            .into_iter()
+            .fold(builder, TransferBuilder::exclude);
+        builder = file_config
+            .include_patterns
+            .into_iter()
+            .fold(builder, TransferBuilder::include);
+        if let Some(timezone) = file_config.timezone {
+            builder = builder.timezone(timezone);
+        }
+        if let Some(hash_algorithm) = file_config.hash_algorithm {
+            builder = builder.hash_algorithm(hash_algorithm);
+        }
+        if let Some(symlink_policy) = file_config.symlink_policy {
+            builder = builder.symlink_policy(symlink_policy);
+        }
+        if let Some(retry) = file_config.retry {
+            builder = builder.retry(retry.max_attempts, retry.base_delay_ms);
+        }
+        if let Some(directory_mode) = file_config.directory_mode {
+            builder = builder.directory_mode(directory_mode);
+        }
+        if let Some(verification_mode) = file_config.verification_mode {
+            builder = builder.verification_mode(verification_mode);
+        }
+        if let Some(buffer_size) = file_config.buffer_size {
+            builder = builder.buffer_size(buffer_size);
+        }
+        if let Some(bandwidth_limit) = file_config.bandwidth_limit {
+            builder = builder.bandwidth_limit(bandwidth_limit);
+        }
+        if let Some(case_sensitivity) = file_config.case_sensitivity {
+            builder = builder.case_sensitivity(case_sensitivity);
+        }
+        if let Some(max_depth) = file_config.max_depth {
+            builder = builder.max_depth(max_depth);
+        }
+        if let Some(empty_source_policy) = file_config.empty_source_policy {
+            builder = builder.empty_source_policy(empty_source_policy);
+        }
+        builder.build_config()
+    }
+}
@@ -1,28 +1,20 @@
 use crate::{
-    config::{
-        destination_directory_path::DestinationDirectoryPath,
-        source_directory_path::SourceDirectoryPath, weekday::WeekDay, Config,
+    config::Config,
+    config_builder::{
+        file_config::{config_dir, FileConfig},
+        json_parse_error, validate_json_schema, ConfigBuilder,
     },
-    config_builder::ConfigBuilder,
 };
-use adapter::file_path::writable_file_path::WritableFilePath;
-use serde::Deserialize;
-use shared::error::{AppError, AppResult};
-
-#[derive(Debug, Deserialize)]
-struct JsonConfig {
-    source_directory_path: String,
-    destination_directory_path: String,
-    weekday: String,
-}
+use adapter::file_path::readable_file_path::ReadableFilePath;
+use shared::error::AppResult;
 
 pub struct JsonConfigBuilder {
-    config_path: WritableFilePath,
+    config_path: ReadableFilePath,
 }
 
 impl JsonConfigBuilder {
     pub fn new(config_path: &str) -> AppResult<Self> {
-        let config_path = WritableFilePath::try_from(config_path.to_string())?;
+        let config_path = ReadableFilePath::try_from(config_path.to_string())?;
         Ok(Self { config_path })
     }
 }
@@ -30,22 +22,19 @@ impl JsonConfigBuilder {
 impl ConfigBuilder for JsonConfigBuilder {
     fn build(&self) -> AppResult<Config> {
         let config_str = self.config_path.read_content()?;
-        let config_json: JsonConfig = serde_json::from_str(&config_str)
-            .map_err(|e| AppError::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, e)))?;
-
-        Ok(Config {
-            source_directory_path: SourceDirectoryPath::new(config_json.source_directory_path)?,
-            dest_directory_path: DestinationDirectoryPath::new(
-                config_json.destination_directory_path,
-            )?,
-            weekday: WeekDay::try_from(config_json.weekday)?,
-        })
+        validate_json_schema(&config_str)?;
+        let config_json: FileConfig =
+            serde_json::from_str(&config_str).map_err(|e| json_parse_error(&config_str, e))?;
+
+        Config::try_from(config_json.relative_to(&config_dir(&self.config_path)?)?)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::{weekday::WeekDay, weekday_set::WeekDaySet};
+    use shared::error::AppError;
     use std::fs;
     use tempfile::{NamedTempFile, TempDir};
 
@@ -132,7 +121,164 @@ mod tests {
         let config = result.unwrap();
         assert_eq!(config.source_directory_path.to_str().unwrap(), source_path);
         assert_eq!(config.dest_directory_path.to_str().unwrap(), dest_path);
-        assert_eq!(config.weekday, WeekDay::Thursday);
+        assert_eq!(config.weekday, WeekDaySet::from(WeekDay::Thursday));
+    }
+
+    #[test]
+    fn json_config_builder_fails_on_invalid_weekday_before_creating_destination() {
+        // ===== Arrange =====
+        let (source_dir, dest_dir) = create_temp_directories();
+        let source_path = source_dir.path().to_str().unwrap().replace("\\", "/");
+        let dest = dest_dir.path().join("2024").join("01");
+        let json_content = format!(
+            r#"{{
+                "source_directory_path": "{}",
+                "destination_directory_path": "{}",
+                "weekday": "Thurs"
+            }}"#,
+            source_path,
+            dest.to_str().unwrap().replace("\\", "/")
+        );
+        let temp_file = create_temp_config_file(&json_content);
+        let builder = JsonConfigBuilder::new(temp_file.path().to_str().unwrap()).unwrap();
+
+        // ===== Act =====
+        let result = builder.build();
+
+        // ===== Assert =====
+        assert!(
+            matches!(result, Err(AppError::ConfigSchema(ref violations)) if violations[0].starts_with("/weekday: "))
+        );
+        assert!(!dest_dir.path().join("2024").exists());
+    }
+
+    #[test]
+    fn json_config_builder_builds_config_with_multiple_weekdays() {
+        // ===== Arrange =====
+        let (source_dir, dest_dir) = create_temp_directories();
+        let source_path = source_dir.path().to_str().unwrap().replace("\\", "/");
+        let dest_path = dest_dir
+            .path()
+            .join("hoge")
+            .to_str()
+            .unwrap()
+            .replace("\\", "/");
+
+        let json_content = format!(
+            r#"{{
+                "source_directory_path": "{}",
+                "destination_directory_path": "{}",
+                "weekday": ["Sat", "Sun"]
+            }}"#,
+            source_path, dest_path
+        );
+
+        let temp_file = create_temp_config_file(&json_content);
+        let config_path = temp_file.path().to_str().unwrap();
+
+        let builder = JsonConfigBuilder::new(config_path).unwrap();
+
+        // ===== Act =====
+        let result = builder.build();
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        let config = result.unwrap();
+        assert_eq!(&*config.weekday, &[WeekDay::Saturday, WeekDay::Sunday]);
+    }
+
+    #[test]
+    fn json_config_builder_expands_environment_variables_in_paths() {
+        // ===== Arrange =====
+        let (source_dir, dest_dir) = create_temp_directories();
+        let source_path = source_dir.path().to_str().unwrap().replace("\\", "/");
+        let dest_root = dest_dir.path().to_str().unwrap().replace("\\", "/");
+        std::env::set_var("SROW_TEST_JSON_SOURCE", &source_path);
+        std::env::set_var("SROW_TEST_JSON_DEST_ROOT", &dest_root);
+
+        let json_content = r#"{
+                "source_directory_path": "$SROW_TEST_JSON_SOURCE",
+                "destination_directory_path": "${SROW_TEST_JSON_DEST_ROOT}/hoge",
+                "weekday": "Thu"
+            }"#;
+
+        let temp_file = create_temp_config_file(json_content);
+        let config_path = temp_file.path().to_str().unwrap();
+        let builder = JsonConfigBuilder::new(config_path).unwrap();
+
+        // ===== Act =====
+        let result = builder.build();
+
+        // ===== Assert =====
+        let config = result.unwrap();
+        assert_eq!(config.source_directory_path.to_str().unwrap(), source_path);
+        assert_eq!(
+            config.dest_directory_path.to_str().unwrap(),
+            format!("{}/hoge", dest_root)
+        );
+    }
+
+    #[test]
+    fn json_config_builder_reads_whole_path_field_from_environment() {
+        // ===== Arrange =====
+        let (source_dir, dest_dir) = create_temp_directories();
+        let source_path = source_dir.path().to_str().unwrap().replace("\\", "/");
+        std::env::set_var("SROW_TEST_JSON_ENV_SOURCE", &source_path);
+        let json_content = format!(
+            r#"{{
+                "source_directory_path": "env:SROW_TEST_JSON_ENV_SOURCE",
+                "destination_directory_path": "{}/hoge",
+                "weekday": "Thu"
+            }}"#,
+            dest_dir.path().to_str().unwrap().replace("\\", "/")
+        );
+        let temp_file = create_temp_config_file(&json_content);
+        let builder = JsonConfigBuilder::new(temp_file.path().to_str().unwrap()).unwrap();
+
+        // ===== Act =====
+        let result = builder.build();
+
+        // ===== Assert =====
+        let config = result.unwrap();
+        assert_eq!(config.source_directory_path.to_str().unwrap(), source_path);
+    }
+
+    #[test]
+    fn json_config_builder_fails_with_undefined_env_field() {
+        // ===== Arrange =====
+        let json_content = r#"{
+                "source_directory_path": "env:SROW_TEST_JSON_ENV_UNDEFINED",
+                "destination_directory_path": "/tmp/hoge",
+                "weekday": "Thu"
+            }"#;
+        let temp_file = create_temp_config_file(json_content);
+        let builder = JsonConfigBuilder::new(temp_file.path().to_str().unwrap()).unwrap();
+
+        // ===== Act =====
+        let result = builder.build();
+
+        // ===== Assert =====
+        assert!(matches!(result, Err(AppError::Env(_))));
+    }
+
+    #[test]
+    fn json_config_builder_fails_with_undefined_environment_variable() {
+        // ===== Arrange =====
+        let json_content = r#"{
+                "source_directory_path": "$SROW_TEST_JSON_UNDEFINED",
+                "destination_directory_path": "/tmp/hoge",
+                "weekday": "Thu"
+            }"#;
+
+        let temp_file = create_temp_config_file(json_content);
+        let config_path = temp_file.path().to_str().unwrap();
+        let builder = JsonConfigBuilder::new(config_path).unwrap();
+
+        // ===== Act =====
+        let result = builder.build();
+
+        // ===== Assert =====
+        assert!(matches!(result, Err(AppError::Env(_))));
     }
 
     #[test]
@@ -155,6 +301,28 @@ mod tests {
         let result = builder.build();
 
         // ===== Assert =====
-        assert!(result.is_err());
+        // 欠けているフィールドはすべて一度に報告する
+        assert!(
+            matches!(result, Err(AppError::ConfigSchema(ref violations)) if violations.len() == 2)
+        );
+    }
+
+    #[test]
+    fn json_config_builder_reports_position_of_syntax_error() {
+        // ===== Arrange =====
+        let temp_file = create_temp_config_file(
+            "{\n  \"source_directory_path\": \"/src\",\n  \"weekday\": \"Mon\",\n}",
+        );
+        let builder = JsonConfigBuilder::new(temp_file.path().to_str().unwrap()).unwrap();
+
+        // ===== Act =====
+        let result = builder.build();
+
+        // ===== Assert =====
+        let Err(error) = result else {
+            panic!("末尾のカンマがあるため失敗するはずです");
+        };
+        assert!(matches!(error, AppError::Config { line: 4, .. }));
+        assert!(error.to_string().contains("4行1列"));
     }
 }
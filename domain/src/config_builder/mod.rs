@@ -1,9 +1,244 @@
-use crate::config::Config;
-use shared::error::AppResult;
+use crate::config::{
+    weekday::{WeekDay, WeekDayGroup},
+    Config,
+};
+use serde_json::Value;
+use shared::error::{AppError, AppResult};
 
 pub mod arg_config_builder;
+pub(crate) mod file_config;
 pub mod json_config_builder;
+pub mod multi_job_config_builder;
+pub mod sample_config;
+pub mod toml_config_builder;
+pub mod yaml_config_builder;
 
 pub trait ConfigBuilder {
     fn build(&self) -> AppResult<Config>;
 }
+
+/// serde_json のエラーを、行と列（値の型の誤りであればそのキー）を持つ `AppError::Config` に変換する
+pub(crate) fn json_parse_error(content: &str, error: serde_json::Error) -> AppError {
+    let (line, column) = (error.line(), error.column());
+    // 欠けたフィールドなどはオブジェクトの終わりで報告され、直前のキーは無関係なため値の誤りに限る
+    let message = error.to_string();
+    let key = match error.classify() {
+        serde_json::error::Category::Data
+            if message.starts_with("invalid type") || message.starts_with("invalid value") =>
+        {
+            key_before(content, line, column)
+        }
+        _ => None,
+    };
+    // 位置は別のフィールドで示すため、メッセージ末尾の重複する記述を取り除く
+    let suffix = format!(" at line {} column {}", line, column);
+    AppError::Config {
+        line,
+        column,
+        key,
+        message: message
+            .strip_suffix(&suffix)
+            .unwrap_or(&message)
+            .to_string(),
+    }
+}
+
+/// 逆シリアル化の前に設定を JSON Schema で検証し、違反をすべてその位置（JSON Pointer）とともに返す
+///
+/// 複数ジョブの設定（`jobs` キー）であれば、ジョブごとに検証する。
+pub(crate) fn validate_json_schema(content: &str) -> AppResult<()> {
+    let instance: Value =
+        serde_json::from_str(content).map_err(|e| json_parse_error(content, e))?;
+    let validator = jsonschema::validator_for(&config_schema()?).map_err(|e| {
+        AppError::Io(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            e.to_string(),
+        ))
+    })?;
+    let jobs: Vec<(String, &Value)> = match instance.get("jobs").and_then(Value::as_array) {
+        Some(jobs) => jobs
+            .iter()
+            .enumerate()
+            .map(|(index, job)| (format!("/jobs/{}", index), job))
+            .collect(),
+        None => vec![(String::new(), &instance)],
+    };
+    let violations: Vec<String> = jobs
+        .into_iter()
+        .flat_map(|(prefix, job)| {
+            validator
+                .iter_errors(job)
+                .map(|error| match format!("{}{}", prefix, error.instance_path) {
+                    path if path.is_empty() => format!("/: {}", error),
+                    path => format!("{}: {}", path, error),
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    match violations.is_empty() {
+        true => Ok(()),
+        false => Err(AppError::ConfigSchema(violations)),
+    }
+}
+
+const CONFIG_SCHEMA: &str = include_str!("config.schema.json");
+
+/// 曜日の表記は `WeekDay` と `WeekDayGroup` の定義から生成し、解析で受け付ける値と一致させる
+fn config_schema() -> AppResult<Value> {
+    let mut schema: Value = serde_json::from_str(CONFIG_SCHEMA)
+        .map_err(|e| AppError::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, e)))?;
+    let tokens: Vec<&str> = WeekDay::accepted_tokens()
+        .iter()
+        .copied()
+        .chain(WeekDayGroup::accepted_tokens())
+        .collect();
+    schema["$defs"]["weekday"]["pattern"] = Value::from(format!("^(?i:{})$", tokens.join("|")));
+    Ok(schema)
+}
+
+/// エラー位置の行で、位置より前にある最後の `"キー":` を探す
+fn key_before(content: &str, line: usize, column: usize) -> Option<String> {
+    let text = content.lines().nth(line.checked_sub(1)?)?;
+    let text = text.get(..column.min(text.len()))?;
+    let end = text.rfind("\":")?;
+    let start = text[..end].rfind('"')?;
+    Some(text[start + 1..end].to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_parse_error_reports_key_of_invalid_value() {
+        // ===== Arrange =====
+        let content = "{\n  \"max_depth\": \"deep\"\n}";
+        let error =
+            serde_json::from_str::<std::collections::HashMap<String, usize>>(content).unwrap_err();
+
+        // ===== Act =====
+        let result = json_parse_error(content, error);
+
+        // ===== Assert =====
+        assert!(
+            matches!(result, AppError::Config { line: 2, key: Some(ref key), .. } if key == "max_depth")
+        );
+    }
+
+    #[test]
+    fn json_parse_error_omits_key_for_missing_field() {
+        // ===== Arrange =====
+        #[derive(Debug, serde::Deserialize)]
+        #[allow(dead_code)]
+        struct Paths {
+            source_directory_path: String,
+            destination_directory_path: String,
+            weekday: String,
+        }
+        let content = r#"{ "source_directory_path": "/src", "weekday": "Mon" }"#;
+        let error = serde_json::from_str::<Paths>(content).unwrap_err();
+
+        // ===== Act =====
+        let result = json_parse_error(content, error);
+
+        // ===== Assert =====
+        let AppError::Config { key, message, .. } = result else {
+            panic!("設定の解析エラーになるはずです");
+        };
+        assert_eq!(key, None);
+        assert!(
+            message.contains("destination_directory_path"),
+            "{}",
+            message
+        );
+    }
+
+    #[test]
+    fn validate_json_schema_reports_path_of_invalid_weekday() {
+        // ===== Arrange =====
+        let content = r#"{
+            "source_directory_path": "/src",
+            "destination_directory_path": "/dest",
+            "weekday": ["Mon", "Funday"]
+        }"#;
+
+        // ===== Act =====
+        let result = validate_json_schema(content);
+
+        // ===== Assert =====
+        let Err(AppError::ConfigSchema(violations)) = result else {
+            panic!("スキーマ違反になるはずです");
+        };
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].starts_with("/weekday: "), "{}", violations[0]);
+    }
+
+    #[test]
+    fn validate_json_schema_accepts_every_weekday_token() {
+        for token in WeekDay::accepted_tokens()
+            .iter()
+            .copied()
+            .chain(WeekDayGroup::accepted_tokens())
+        {
+            // ===== Arrange =====
+            let content = serde_json::json!({
+                "source_directory_path": "/src",
+                "destination_directory_path": "/dest",
+                "weekday": token,
+            })
+            .to_string();
+
+            // ===== Act =====
+            let result = validate_json_schema(&content);
+
+            // ===== Assert =====
+            assert!(result.is_ok(), "{}", token);
+        }
+    }
+
+    #[test]
+    fn validate_json_schema_reports_path_within_jobs() {
+        // ===== Arrange =====
+        let content = r#"{
+            "jobs": [
+                { "source_directory_path": "/a", "destination_directory_path": "/b", "weekday": "Mon" },
+                { "source_directory_path": "/c", "destination_directory_path": "/d", "weekday": "Funday" }
+            ]
+        }"#;
+
+        // ===== Act =====
+        let result = validate_json_schema(content);
+
+        // ===== Assert =====
+        let Err(AppError::ConfigSchema(violations)) = result else {
+            panic!("スキーマ違反になるはずです");
+        };
+        assert_eq!(violations.len(), 1);
+        assert!(
+            violations[0].starts_with("/jobs/1/weekday: "),
+            "{}",
+            violations[0]
+        );
+    }
+
+    #[test]
+    fn validate_json_schema_reports_missing_required_field() {
+        // ===== Arrange =====
+        let content = r#"{ "source_directory_path": "/src", "weekday": "Mon" }"#;
+
+        // ===== Act =====
+        let result = validate_json_schema(content);
+
+        // ===== Assert =====
+        let Err(AppError::ConfigSchema(violations)) = result else {
+            panic!("スキーマ違反になるはずです");
+        };
+        assert_eq!(violations.len(), 1);
+        assert!(
+            violations[0].starts_with("/: ")
+                && violations[0].contains("destination_directory_path"),
+            "{}",
+            violations[0]
+        );
+    }
+}
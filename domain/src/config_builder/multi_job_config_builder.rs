@@ -0,0 +1,352 @@
+use crate::{
+    config::Config,
+    config_builder::{
+        file_config::{config_dir, FileConfig},
+        json_parse_error, validate_json_schema,
+    },
+};
+use adapter::file_path::readable_file_path::ReadableFilePath;
+use serde::{de::DeserializeOwned, Deserialize};
+use shared::error::{AppError, AppResult};
+use std::{io::Read, path::Path};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConfigFileFormat {
+    Json,
+    Yaml,
+    Toml,
+}
+
+impl ConfigFileFormat {
+    fn from_path(path: &ReadableFilePath) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("yaml") | Some("yml") => Self::Yaml,
+            Some("toml") => Self::Toml,
+            _ => Self::Json,
+        }
+    }
+
+    fn parse<T: DeserializeOwned>(&self, content: &str) -> AppResult<T> {
+        match self {
+            Self::Json => serde_json::from_str(content).map_err(|e| json_parse_error(content, e)),
+            Self::Yaml => serde_yaml::from_str(content).map_err(Self::parse_error),
+            Self::Toml => toml::from_str(content).map_err(Self::parse_error),
+        }
+    }
+
+    fn parse_error(error: impl std::fmt::Display) -> AppError {
+        AppError::Io(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("設定ファイルの解析に失敗しました: {}", error),
+        ))
+    }
+}
+
+// `jobs` キーがあれば複数ジョブ、なければ従来の単一ジョブとして扱う
+#[derive(Debug, Deserialize)]
+struct MultiJobFileConfig {
+    #[serde(default)]
+    jobs: Option<Vec<FileConfig>>,
+}
+
+pub struct MultiJobConfigBuilder {
+    config_path: ReadableFilePath,
+    follow_config_dir: bool,
+}
+
+impl MultiJobConfigBuilder {
+    pub fn new(config_path: &str) -> AppResult<Self> {
+        let config_path = ReadableFilePath::try_from(config_path.to_string())?;
+        Ok(Self {
+            config_path,
+            follow_config_dir: true,
+        })
+    }
+
+    /// `false` を指定すると、相対パスを設定ファイルの場所ではなくカレントディレクトリから解決する
+    pub fn with_follow_config_dir(self, follow_config_dir: bool) -> Self {
+        Self {
+            follow_config_dir,
+            ..self
+        }
+    }
+
+    pub fn build(&self) -> AppResult<Vec<Config>> {
+        let config_str = self.config_path.read_content()?;
+        let config_dir = match self.follow_config_dir {
+            true => Some(config_dir(&self.config_path)?),
+            false => None,
+        };
+        Self::build_from_str(
+            ConfigFileFormat::from_path(&self.config_path),
+            &config_str,
+            config_dir.as_deref(),
+        )
+    }
+
+    /// 標準入力などのファイル以外から JSON 形式の設定を読み込む
+    pub fn build_from_reader(mut reader: impl Read) -> AppResult<Vec<Config>> {
+        let mut config_str = String::new();
+        reader.read_to_string(&mut config_str)?;
+        Self::build_from_str(ConfigFileFormat::Json, &config_str, None)
+    }
+
+    fn build_from_str(
+        format: ConfigFileFormat,
+        config_str: &str,
+        config_dir: Option<&Path>,
+    ) -> AppResult<Vec<Config>> {
+        if format == ConfigFileFormat::Json {
+            validate_json_schema(config_str)?;
+        }
+        let file_configs = match format.parse::<MultiJobFileConfig>(config_str)?.jobs {
+            Some(jobs) => jobs,
+            None => vec![format.parse::<FileConfig>(config_str)?],
+        };
+
+        if file_configs.is_empty() {
+            return Err(AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "ジョブが定義されていません",
+            )));
+        }
+
+        file_configs
+            .into_iter()
+            .map(|file_config| match config_dir {
+                Some(config_dir) => file_config.relative_to(config_dir),
+                None => Ok(file_config),
+            })
+            .map(|file_config| Config::try_from(file_config?))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{weekday::WeekDay, weekday_set::WeekDaySet};
+    use std::fs;
+    use tempfile::{Builder, NamedTempFile, TempDir};
+
+    fn create_temp_config_file(content: &str, suffix: &str) -> NamedTempFile {
+        let temp_file = Builder::new().suffix(suffix).tempfile().unwrap();
+        fs::write(&temp_file, content).unwrap();
+        temp_file
+    }
+
+    fn create_readonly_directory() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        let mut perms = std::fs::metadata(dir.path()).unwrap().permissions();
+        perms.set_readonly(true);
+        std::fs::set_permissions(dir.path(), perms).unwrap();
+        dir
+    }
+
+    #[test]
+    fn multi_job_config_builder_builds_all_jobs_from_json() {
+        // ===== Arrange =====
+        let source_a = create_readonly_directory();
+        let source_b = create_readonly_directory();
+        let dest_dir = TempDir::new().unwrap();
+        let source_a_path = source_a.path().to_str().unwrap().replace("\\", "/");
+        let source_b_path = source_b.path().to_str().unwrap().replace("\\", "/");
+        let dest_root = dest_dir.path().to_str().unwrap().replace("\\", "/");
+
+        let json_content = format!(
+            r#"{{
+                "jobs": [
+                    {{
+                        "source_directory_path": "{}",
+                        "destination_directory_path": "{}/a",
+                        "weekday": "Mon"
+                    }},
+                    {{
+                        "source_directory_path": "{}",
+                        "destination_directory_path": "{}/b",
+                        "weekday": ["Sat", "Sun"]
+                    }}
+                ]
+            }}"#,
+            source_a_path, dest_root, source_b_path, dest_root
+        );
+        let temp_file = create_temp_config_file(&json_content, ".json");
+        let builder = MultiJobConfigBuilder::new(temp_file.path().to_str().unwrap()).unwrap();
+
+        // ===== Act =====
+        let result = builder.build();
+
+        // ===== Assert =====
+        let configs = result.unwrap();
+        assert_eq!(configs.len(), 2);
+        assert_eq!(
+            configs[0].source_directory_path.to_str().unwrap(),
+            source_a_path
+        );
+        assert_eq!(
+            configs[0].dest_directory_path.to_str().unwrap(),
+            format!("{}/a", dest_root)
+        );
+        assert_eq!(configs[0].weekday, WeekDaySet::from(WeekDay::Monday));
+        assert_eq!(
+            configs[1].source_directory_path.to_str().unwrap(),
+            source_b_path
+        );
+        assert_eq!(
+            configs[1].dest_directory_path.to_str().unwrap(),
+            format!("{}/b", dest_root)
+        );
+        assert_eq!(&*configs[1].weekday, &[WeekDay::Saturday, WeekDay::Sunday]);
+    }
+
+    #[test]
+    fn multi_job_config_builder_accepts_readonly_config_file() {
+        // ===== Arrange =====
+        let source = create_readonly_directory();
+        let dest_dir = TempDir::new().unwrap();
+        let content = format!(
+            "source_directory_path = \"{}\"\ndestination_directory_path = \"{}/dest\"\nweekday = \"Mon\"\n",
+            source.path().to_str().unwrap().replace("\\", "/"),
+            dest_dir.path().to_str().unwrap().replace("\\", "/")
+        );
+        let temp_file = create_temp_config_file(&content, ".toml");
+        let mut perms = fs::metadata(temp_file.path()).unwrap().permissions();
+        perms.set_readonly(true);
+        fs::set_permissions(temp_file.path(), perms).unwrap();
+
+        // ===== Act =====
+        let result = MultiJobConfigBuilder::new(temp_file.path().to_str().unwrap())
+            .and_then(|builder| builder.build());
+
+        // ===== Assert =====
+        assert_eq!(result.map(|configs| configs.len()).ok(), Some(1));
+    }
+
+    #[test]
+    fn multi_job_config_builder_builds_config_from_reader() {
+        // ===== Arrange =====
+        let source = create_readonly_directory();
+        let dest_dir = TempDir::new().unwrap();
+        let source_path = source.path().to_str().unwrap().replace("\\", "/");
+        let dest_path = format!(
+            "{}/dest",
+            dest_dir.path().to_str().unwrap().replace("\\", "/")
+        );
+        let json_content = format!(
+            r#"{{
+                "source_directory_path": "{}",
+                "destination_directory_path": "{}",
+                "weekday": "Mon"
+            }}"#,
+            source_path, dest_path
+        );
+
+        // ===== Act =====
+        let result = MultiJobConfigBuilder::build_from_reader(json_content.as_bytes());
+
+        // ===== Assert =====
+        let configs = result.unwrap();
+        assert_eq!(configs.len(), 1);
+        assert_eq!(
+            configs[0].source_directory_path.to_str().unwrap(),
+            source_path
+        );
+        assert_eq!(configs[0].dest_directory_path.to_str().unwrap(), dest_path);
+    }
+
+    fn create_config_with_relative_paths() -> (TempDir, std::path::PathBuf) {
+        let root = TempDir::new().unwrap();
+        fs::create_dir_all(root.path().join("conf")).unwrap();
+        fs::create_dir_all(root.path().join("srow-relative-source")).unwrap();
+        let config_path = root.path().join("conf").join("srow.json");
+        fs::write(
+            &config_path,
+            r#"{
+                "source_directory_path": "../srow-relative-source",
+                "destination_directory_path": "out/{yyyy}",
+                "weekday": "Mon"
+            }"#,
+        )
+        .unwrap();
+        (root, config_path)
+    }
+
+    #[test]
+    fn multi_job_config_builder_resolves_relative_paths_against_config_dir() {
+        // ===== Arrange =====
+        let (root, config_path) = create_config_with_relative_paths();
+        let builder = MultiJobConfigBuilder::new(config_path.to_str().unwrap()).unwrap();
+
+        // ===== Act =====
+        let result = builder.build();
+
+        // ===== Assert =====
+        let configs = result.unwrap();
+        assert_eq!(
+            configs[0].source_directory_path.canonicalize().unwrap(),
+            root.path()
+                .join("srow-relative-source")
+                .canonicalize()
+                .unwrap()
+        );
+        let destination: &std::path::Path = &configs[0].dest_directory_path;
+        assert!(destination.is_absolute());
+        assert_eq!(
+            destination.parent().unwrap(),
+            std::path::absolute(root.path().join("conf").join("out")).unwrap()
+        );
+    }
+
+    #[test]
+    fn multi_job_config_builder_resolves_relative_paths_against_cwd_when_opted_out() {
+        // ===== Arrange =====
+        let (_root, config_path) = create_config_with_relative_paths();
+        let builder = MultiJobConfigBuilder::new(config_path.to_str().unwrap())
+            .unwrap()
+            .with_follow_config_dir(false);
+
+        // ===== Act =====
+        let result = builder.build();
+
+        // ===== Assert =====
+        // カレントディレクトリからは移動元が見つからない
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn multi_job_config_builder_builds_single_job_from_legacy_format() {
+        // ===== Arrange =====
+        let source_dir = create_readonly_directory();
+        let dest_dir = TempDir::new().unwrap();
+        let source_path = source_dir.path().to_str().unwrap().replace("\\", "/");
+        let dest_root = dest_dir.path().to_str().unwrap().replace("\\", "/");
+
+        let yaml_content = format!(
+            "source_directory_path: \"{}\"\ndestination_directory_path: \"{}/hoge\"\nweekday: Thu\n",
+            source_path, dest_root
+        );
+        let temp_file = create_temp_config_file(&yaml_content, ".yaml");
+        let builder = MultiJobConfigBuilder::new(temp_file.path().to_str().unwrap()).unwrap();
+
+        // ===== Act =====
+        let result = builder.build();
+
+        // ===== Assert =====
+        let configs = result.unwrap();
+        assert_eq!(configs.len(), 1);
+        assert_eq!(configs[0].weekday, WeekDaySet::from(WeekDay::Thursday));
+    }
+
+    #[test]
+    fn multi_job_config_builder_fails_when_jobs_is_empty() {
+        // ===== Arrange =====
+        let temp_file = create_temp_config_file(r#"{ "jobs": [] }"#, ".json");
+        let builder = MultiJobConfigBuilder::new(temp_file.path().to_str().unwrap()).unwrap();
+
+        // ===== Act =====
+        let result = builder.build();
+
+        // ===== Assert =====
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,74 @@
+use adapter::file_path::output_file_path::OutputFilePath;
+use shared::error::AppResult;
+use std::path::Path;
+
+// JSONはコメントを書けないため、説明は `_comment` キーに記載する（読み込み時は無視される）
+const SAMPLE_CONFIG_JSON: &str = r#"{
+    "_comment": "sRow motion の設定ファイルです。各値を環境に合わせて書き換えてください。",
+    "_comment_source_directory_path": "移動元ディレクトリの絶対パス",
+    "source_directory_path": "C:\\Users\\hoge\\Desktop\\",
+    "_comment_destination_directory_path": "移動先ディレクトリの絶対パス。{yyyy} {mm} {dd} {HH} {MM} {SS} などのプレースホルダが使えます",
+    "destination_directory_path": "C:\\Users\\hoge\\Files\\{yyyy}\\{mm}\\{dd}\\",
+    "_comment_weekday": "実行する曜日（Mon, Tue, Wed, Thu, Fri, Sat, Sun）。配列で複数指定できます",
+    "weekday": "Thu"
+}
+"#;
+
+pub fn write_sample_config(path: &Path, force: bool) -> AppResult<()> {
+    OutputFilePath::new(path, force)?.write_content(SAMPLE_CONFIG_JSON)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config_builder::{file_config::FileConfig, json_config_builder::JsonConfigBuilder};
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn write_sample_config_creates_file_readable_by_json_config_builder() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("config.json");
+
+        // ===== Act =====
+        let result = write_sample_config(&path, false);
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert!(JsonConfigBuilder::new(path.to_str().unwrap()).is_ok());
+        // プレースホルダのパスは実在しないため、構築前の解析段階までを検証する
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(serde_json::from_str::<FileConfig>(&content).is_ok());
+    }
+
+    #[test]
+    fn write_sample_config_refuses_to_overwrite_without_force() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("config.json");
+        fs::write(&path, "existing").unwrap();
+
+        // ===== Act =====
+        let result = write_sample_config(&path, false);
+
+        // ===== Assert =====
+        assert!(result.is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "existing");
+    }
+
+    #[test]
+    fn write_sample_config_overwrites_with_force() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("config.json");
+        fs::write(&path, "existing").unwrap();
+
+        // ===== Act =====
+        let result = write_sample_config(&path, true);
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert_eq!(fs::read_to_string(&path).unwrap(), SAMPLE_CONFIG_JSON);
+    }
+}
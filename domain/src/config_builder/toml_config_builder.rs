@@ -0,0 +1,143 @@
+use crate::{
+    config::Config,
+    config_builder::{
+        file_config::{config_dir, FileConfig},
+        ConfigBuilder,
+    },
+};
+use adapter::file_path::readable_file_path::ReadableFilePath;
+use shared::error::{AppError, AppResult};
+
+pub struct TomlConfigBuilder {
+    config_path: ReadableFilePath,
+}
+
+impl TomlConfigBuilder {
+    pub fn new(config_path: &str) -> AppResult<Self> {
+        let config_path = ReadableFilePath::try_from(config_path.to_string())?;
+        Ok(Self { config_path })
+    }
+}
+
+impl ConfigBuilder for TomlConfigBuilder {
+    fn build(&self) -> AppResult<Config> {
+        let config_str = self.config_path.read_content()?;
+        let config_toml: FileConfig = toml::from_str(&config_str).map_err(|e| {
+            AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("TOML設定ファイルの解析に失敗しました: {}", e),
+            ))
+        })?;
+
+        Config::try_from(config_toml.relative_to(&config_dir(&self.config_path)?)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{weekday::WeekDay, weekday_set::WeekDaySet};
+    use std::fs;
+    use tempfile::{NamedTempFile, TempDir};
+
+    fn create_temp_config_file(content: &str) -> NamedTempFile {
+        let temp_file = NamedTempFile::new().unwrap();
+        fs::write(&temp_file, content).unwrap();
+        temp_file
+    }
+
+    #[allow(clippy::permissions_set_readonly_false)]
+    fn create_temp_directories() -> (TempDir, TempDir) {
+        let source_dir = TempDir::new().unwrap();
+        let dest_dir = TempDir::new().unwrap();
+        // source_dir: 読み取り専用
+        let mut src_perms = std::fs::metadata(source_dir.path()).unwrap().permissions();
+        src_perms.set_readonly(true);
+        std::fs::set_permissions(source_dir.path(), src_perms).unwrap();
+        // dest_dir: 書き込み可能
+        let mut dst_perms = std::fs::metadata(dest_dir.path()).unwrap().permissions();
+        dst_perms.set_readonly(false);
+        std::fs::set_permissions(dest_dir.path(), dst_perms).unwrap();
+        (source_dir, dest_dir)
+    }
+
+    #[test]
+    fn toml_config_builder_fails_with_invalid_path() {
+        // ===== Arrange =====
+        let invalid_path = "/path/does/not/exist.toml";
+
+        // ===== Act =====
+        let result = TomlConfigBuilder::new(invalid_path);
+
+        // ===== Assert =====
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn toml_config_builder_builds_config_from_valid_toml() {
+        // ===== Arrange =====
+        let (source_dir, dest_dir) = create_temp_directories();
+        let source_path = source_dir.path().to_str().unwrap().replace("\\", "/");
+        let dest_path = dest_dir
+            .path()
+            .join("hoge")
+            .to_str()
+            .unwrap()
+            .replace("\\", "/");
+
+        let toml_content = format!(
+            "source_directory_path = \"{}\"\ndestination_directory_path = \"{}\"\nweekday = \"Thu\"\n",
+            source_path, dest_path
+        );
+
+        let temp_file = create_temp_config_file(&toml_content);
+        let config_path = temp_file.path().to_str().unwrap();
+
+        let builder = TomlConfigBuilder::new(config_path).unwrap();
+
+        // ===== Act =====
+        let result = builder.build();
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        let config = result.unwrap();
+        assert_eq!(config.source_directory_path.to_str().unwrap(), source_path);
+        assert_eq!(config.dest_directory_path.to_str().unwrap(), dest_path);
+        assert_eq!(config.weekday, WeekDaySet::from(WeekDay::Thursday));
+    }
+
+    #[test]
+    fn toml_config_builder_fails_with_missing_required_fields() {
+        // ===== Arrange =====
+        let (source_dir, _) = create_temp_directories();
+        let source_path = source_dir.path().to_str().unwrap().replace("\\", "/");
+
+        let toml_content = format!("source_directory_path = \"{}\"\n", source_path);
+        let temp_file = create_temp_config_file(&toml_content);
+        let config_path = temp_file.path().to_str().unwrap();
+        let builder = TomlConfigBuilder::new(config_path).unwrap();
+
+        // ===== Act =====
+        let result = builder.build();
+
+        // ===== Assert =====
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn toml_config_builder_fails_with_broken_toml_syntax() {
+        // ===== Arrange =====
+        let toml_content = "source_directory_path = \"/tmp\nweekday = Thu\n";
+        let temp_file = create_temp_config_file(toml_content);
+        let config_path = temp_file.path().to_str().unwrap();
+        let builder = TomlConfigBuilder::new(config_path).unwrap();
+
+        // ===== Act =====
+        let result = builder.build();
+
+        // ===== Assert =====
+        let message = result.err().unwrap().to_string();
+        assert!(message.contains("TOML設定ファイルの解析に失敗しました"));
+        assert!(message.contains("line 1"));
+    }
+}
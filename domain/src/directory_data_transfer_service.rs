@@ -1,74 +1,592 @@
-use chrono::{DateTime, Local};
-use shared::error::{AppError, AppResult};
+pub use adapter::copy_options::TransferSummary;
+use adapter::{
+    copy_options::{CopyOptions, Deadline, RateLimiter, SymlinkPolicy},
+    directory_path::writable_directory_path::WritableDirectoryPath,
+    storage_backend::{SharedStorageBackend, StorageBackend},
+    transfer_lock::TransferLock,
+};
+use chrono::{DateTime, Datelike, Local};
+use log::{debug, info, warn};
+use shared::{
+    error::{AppError, AppResult},
+    message::{Lang, Message},
+};
 
-use crate::config::Config;
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    clock::{Clock, SystemClock},
+    config::{empty_source_policy::EmptySourcePolicy, Config},
+    manifest::{Manifest, MANIFEST_FILE_NAME},
+};
+
+/// 実際には何も変更せずに、移動で削除・コピーされるエントリを示す
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TransferPlan {
+    /// `--force` によって削除される移動先の既存エントリ
+    pub removals: Vec<PathBuf>,
+    /// 移動先にコピーされる移動元のエントリ
+    pub copies: Vec<PathBuf>,
+}
+
+/// 進捗の表示に使う、移動の対象となるファイルの数とサイズの合計
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TransferTotals {
+    pub files: u64,
+    pub bytes: u64,
+}
+
+/// `validate` で行う事前検査の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationCheck {
+    Weekday,
+    NotNested,
+    SourceNotEmpty,
+    DestinationEmpty,
+    Capacity,
+}
+
+impl ValidationCheck {
+    /// 実行する順序どおりに並べたすべての検査
+    pub const ALL: [Self; 5] = [
+        Self::Weekday,
+        Self::NotNested,
+        Self::SourceNotEmpty,
+        Self::DestinationEmpty,
+        Self::Capacity,
+    ];
+}
+
+/// 各検査の結果。一覧として表示できるよう、失敗した検査があっても残りの検査を実行した結果を持つ
+#[derive(Debug)]
+pub struct ValidationReport {
+    pub checks: Vec<(ValidationCheck, AppResult<()>)>,
+}
+
+impl ValidationReport {
+    pub fn is_passed(&self) -> bool {
+        self.checks.iter().all(|(_, result)| result.is_ok())
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = (ValidationCheck, &AppError)> {
+        self.checks
+            .iter()
+            .filter_map(|(check, result)| result.as_ref().err().map(|e| (*check, e)))
+    }
+}
 
 pub struct DirectoryDataTransferService {
     config: Config,
     now: DateTime<Local>,
+    force: bool,
+    ignore_weekday: bool,
+    lang: Lang,
+    modified_since: Option<DateTime<Local>>,
+    timeout: Option<Duration>,
+    tree_hash: bool,
+    staging: bool,
+    storage_backend: SharedStorageBackend,
+    source_removal_confirmation: Option<Box<SourceRemovalConfirmation>>,
+    progress: Option<Box<TransferProgress>>,
 }
 
+/// 移動元と削除するエントリを受け取り、削除してよければ `true` を返す
+pub type SourceRemovalConfirmation = dyn Fn(&Path, &[PathBuf]) -> AppResult<bool>;
+
+/// コピーしたファイルの移動元のパスとバイト数を、ファイルごとに受け取る
+pub type TransferProgress = dyn Fn(&Path, u64);
+
 impl DirectoryDataTransferService {
     pub fn new(config: Config) -> Self {
-        let now = Local::now();
-        Self { config, now }
+        let now = SystemClock.now();
+        Self {
+            config,
+            now,
+            force: false,
+            ignore_weekday: false,
+            lang: Lang::default(),
+            modified_since: None,
+            timeout: None,
+            tree_hash: false,
+            staging: false,
+            storage_backend: SharedStorageBackend::default(),
+            source_removal_confirmation: None,
+            progress: None,
+        }
+    }
+
+    /// 曜日の判定、移動先の日付やロックファイルに記録する時刻を、指定した時計から取得する
+    pub fn with_clock(self, clock: &impl Clock) -> AppResult<Self> {
+        let now = clock.now();
+        let dest_directory_path = self
+            .config
+            .dest_directory_path
+            .rendered_at(&self.config.timezone.localize(&now))?;
+        Ok(Self {
+            config: Config {
+                dest_directory_path,
+                ..self.config
+            },
+            now,
+            ..self
+        })
     }
 
     #[cfg(test)]
     pub fn with_custom_now(self, now: DateTime<Local>) -> Self {
+        self.with_clock(&crate::clock::FixedClock(now)).unwrap()
+    }
+
+    /// 移動先が空でない場合に、中止せず中身を削除してから移動する
+    pub fn with_force(self, force: bool) -> Self {
+        Self { force, ..self }
+    }
+
+    /// 手動実行やテストのために曜日の判定を省略する（移動先やその他の検査は行う）
+    pub fn with_ignore_weekday(self, ignore_weekday: bool) -> Self {
         Self {
-            config: self.config,
-            now,
+            ignore_weekday,
+            ..self
         }
     }
 
-    pub fn validate(self) -> AppResult<Self> {
-        if !self.config.weekday.matches_weekday(&self.now) {
-            return Err(AppError::Io(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "今日は指定された曜日ではありません。終了します。: {:?}",
-                    self.config.weekday
-                ),
-            )));
+    pub fn with_lang(self, lang: Lang) -> Self {
+        Self { lang, ..self }
+    }
+
+    /// 指定日時より前に更新されたファイルは移動せず、移動元に残す
+    pub fn with_modified_since(self, modified_since: Option<DateTime<Local>>) -> Self {
+        Self {
+            modified_since,
+            ..self
+        }
+    }
+
+    /// 転送が指定時間を超えた場合、ファイルの区切りで中断して移動先をロールバックする
+    pub fn with_timeout(self, timeout: Option<Duration>) -> Self {
+        Self { timeout, ..self }
+    }
+
+    /// 移動の前後でツリー全体のハッシュ値を計算し、ログに記録して一致を確認する
+    ///
+    /// 一致を確認してから移動元を削除するため、同一ファイルシステム上でもリネームせずにコピーする。
+    pub fn with_tree_hash(self, tree_hash: bool) -> Self {
+        Self { tree_hash, ..self }
+    }
+
+    /// 移動先の隣の一時ディレクトリにコピーし、検証が済んでから移動先にリネームする
+    pub fn with_staging(self, staging: bool) -> Self {
+        Self { staging, ..self }
+    }
+
+    /// 移動先へのコピーを、ローカルのファイルシステム以外のストレージで行う
+    pub fn with_storage_backend(self, storage_backend: impl StorageBackend + 'static) -> Self {
+        Self {
+            storage_backend: SharedStorageBackend::new(storage_backend),
+            ..self
+        }
+    }
+
+    /// 移動元を削除する前に確認し、同意が得られなければ移動元を残したまま終了する
+    ///
+    /// 確認のために、同一ファイルシステム上でもリネームせずにコピーしてから削除する。
+    pub fn with_source_removal_confirmation(
+        self,
+        confirmation: impl Fn(&Path, &[PathBuf]) -> AppResult<bool> + 'static,
+    ) -> Self {
+        Self {
+            source_removal_confirmation: Some(Box::new(confirmation)),
+            ..self
+        }
+    }
+
+    /// ファイルをコピーするたびに呼び出す。同一ファイルシステム上のリネームでは、移動後にファイルごとに呼び出す
+    pub fn with_progress(self, progress: impl Fn(&Path, u64) + 'static) -> Self {
+        Self {
+            progress: Some(Box::new(progress)),
+            ..self
         }
+    }
+
+    /// `transfer` でコピーされるファイルの数とサイズの合計を、移動元を変更せずに数える
+    pub fn totals(&self) -> AppResult<TransferTotals> {
+        let options = self.copy_options();
+        let source = &self.config.source_directory_path;
+        Ok(TransferTotals {
+            files: source.file_count_with_options(&options)?,
+            bytes: source.total_size_with_options(&options)?,
+        })
+    }
 
-        if !self.config.dest_directory_path.is_empty()? {
-            return Err(AppError::Io(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                "移動先ディレクトリにデータが既に存在するため、処理を終了します",
-            )));
+    pub fn validate(self) -> AppResult<Self> {
+        for check in ValidationCheck::ALL {
+            self.run_check(check)?;
         }
 
+        info!(
+            "{}",
+            Message::PreflightPassed {
+                source: &self.config.source_directory_path,
+                destination: &self.config.dest_directory_path,
+            }
+            .render(self.lang)
+        );
         Ok(self)
     }
 
-    pub fn transfer(&self) -> AppResult<()> {
-        let result: AppResult<()> = {
-            self.config
-                .dest_directory_path
-                .copy_all_data_from(&self.config.source_directory_path)?;
+    /// 最初の失敗で中断せずにすべての検査を実行し、それぞれの結果を返す
+    pub fn validate_report(&self) -> ValidationReport {
+        ValidationReport {
+            checks: ValidationCheck::ALL
+                .into_iter()
+                .map(|check| (check, self.run_check(check)))
+                .collect(),
+        }
+    }
+
+    fn run_check(&self, check: ValidationCheck) -> AppResult<()> {
+        match check {
+            ValidationCheck::Weekday => self.check_weekday(),
+            ValidationCheck::NotNested => self.check_not_nested(),
+            ValidationCheck::SourceNotEmpty => self.check_source_not_empty(),
+            ValidationCheck::DestinationEmpty => self.check_destination_empty(),
+            ValidationCheck::Capacity => self.check_capacity(),
+        }
+    }
 
-            match self
+    fn check_weekday(&self) -> AppResult<()> {
+        let today = self.config.weekday_date(&self.now);
+        if self.ignore_weekday || self.config.weekday.matches_weekday(&today) {
+            return Ok(());
+        }
+        Err(AppError::WrongWeekday {
+            expected: self
                 .config
-                .dest_directory_path
-                .verify_directory_contents_match(&self.config.source_directory_path)?
-            {
-                true => Ok(()),
-                false => Err(AppError::Io(std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    "整合性エラー：コピー内容が一致しません。移動先を削除します。",
-                ))),
+                .weekday
+                .iter()
+                .map(|weekday| weekday.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+            actual: today.weekday().to_string(),
+        })
+    }
+
+    fn check_not_nested(&self) -> AppResult<()> {
+        if self
+            .config
+            .dest_directory_path
+            .is_nested_with(&self.config.source_directory_path)?
+        {
+            return Err(AppError::NestedDirectories {
+                source_path: self.config.source_directory_path.to_path_buf(),
+                destination_path: self.config.dest_directory_path.to_path_buf(),
+            });
+        }
+        Ok(())
+    }
+
+    fn check_destination_empty(&self) -> AppResult<()> {
+        // 既存のファイルを再利用する場合は、中断した前回の転送の続きとして空でない移動先を受け入れる
+        if !self.force
+            && !self.config.skip_existing_matching
+            && !self
+                .storage_backend
+                .is_empty(&self.config.dest_directory_path)?
+        {
+            return Err(AppError::DestinationNotEmpty(
+                self.config.dest_directory_path.to_path_buf(),
+            ));
+        }
+        Ok(())
+    }
+
+    // 空の移動元は設定ミスの可能性が高いため、何も移動せずに成功したように見せない
+    fn check_source_not_empty(&self) -> AppResult<()> {
+        let source = &self.config.source_directory_path;
+        if !source.is_empty()? {
+            return Ok(());
+        }
+        match self.config.empty_source_policy {
+            EmptySourcePolicy::Warn => {
+                warn!("{}", Message::SourceEmpty(source).render(self.lang));
+                Ok(())
+            }
+            EmptySourcePolicy::Error => Err(AppError::SourceEmpty(source.to_path_buf())),
+        }
+    }
+
+    fn check_capacity(&self) -> AppResult<()> {
+        let required = self
+            .config
+            .source_directory_path
+            .total_size_with_options(&self.copy_options())?;
+        let available = self.config.dest_directory_path.available_space()?;
+        debug!(
+            "{}",
+            Message::CapacityChecked {
+                required,
+                available
+            }
+            .render(self.lang)
+        );
+        Self::ensure_capacity(required, available)
+    }
+
+    fn ensure_capacity(required: u64, available: u64) -> AppResult<()> {
+        if required > available {
+            return Err(AppError::InsufficientSpace {
+                required,
+                available,
+            });
+        }
+        Ok(())
+    }
+
+    fn copy_options(&self) -> CopyOptions {
+        CopyOptions {
+            hash_algorithm: self.config.hash_algorithm,
+            exclude_patterns: self.config.exclude_patterns.clone(),
+            include_patterns: self.config.include_patterns.clone(),
+            symlink_policy: self.config.symlink_policy,
+            retry_policy: self.config.retry_policy,
+            verification_mode: self.config.verification_mode,
+            buffer_size: self.config.buffer_size,
+            rate_limiter: self.config.bandwidth_limit.map(RateLimiter::new),
+            modified_since: self.modified_since.map(SystemTime::from),
+            max_depth: self.config.max_depth,
+            skip_existing_matching: self.config.skip_existing_matching,
+            preserve_xattrs: self.config.preserve_xattrs,
+            parallel: self.config.parallel,
+            case_sensitivity: self.config.case_sensitivity,
+            storage_backend: self.storage_backend.clone(),
+            lang: self.lang,
+            // 制限時間は転送の開始時点から数えるため、`transfer` で設定する
+            deadline: None,
+        }
+    }
+
+    /// `transfer` と同じ条件で削除・コピーの対象を列挙する。ファイルシステムは変更しない
+    pub fn plan(&self) -> AppResult<TransferPlan> {
+        let destination = &self.config.dest_directory_path;
+        let source: &Path = &self.config.source_directory_path;
+        let removals = if self.force {
+            // リンク先は削除されないため、リンク自体を1つのエントリとして扱う
+            let options = CopyOptions {
+                symlink_policy: SymlinkPolicy::CopyAsLink,
+                ..CopyOptions::default()
+            };
+            destination
+                .list_entries(&options)?
+                .into_iter()
+                .map(|rel_path| destination.join(rel_path))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let copies = self
+            .config
+            .source_directory_path
+            .list_entries(&self.copy_options())?
+            .into_iter()
+            .map(|rel_path| source.join(rel_path))
+            .collect();
+        Ok(TransferPlan { removals, copies })
+    }
+
+    pub fn transfer(&self) -> AppResult<TransferSummary> {
+        let options = CopyOptions {
+            deadline: self.timeout.map(Deadline::start),
+            ..self.copy_options()
+        };
+        if self.staging {
+            return self.transfer_via_staging(&options);
+        }
+        // 設定の構築や検証ではファイルシステムを変更せず、移動の直前に移動先を作成する
+        let destination = self.config.dest_directory_path.create()?;
+        // cron などで実行が重なっても移動先を壊さないよう、終了まで移動先の親ディレクトリをロックする
+        let _lock = TransferLock::acquire(
+            destination.parent().unwrap_or(&destination),
+            &self.now.to_rfc3339(),
+        )?;
+
+        if self.config.skip_existing_matching {
+            // 一致するファイルはコピーを省略し、一致しないファイルはコピー時に上書きする
+        } else if self.force {
+            if !options.storage_backend.is_empty(&destination)? {
+                warn!(
+                    "{}",
+                    Message::ClearingDestination(&destination).render(self.lang)
+                );
+            }
+            destination.ensure_empty_or_clear_with_options(&options)?;
+        } else {
+            // 検証から移動までの間に別のプロセスが書き込んだ場合も、既存のデータを上書きしない
+            destination.ensure_empty_or_err_with_options(&options)?;
+        }
+
+        let confirm = self.source_removal_confirmation.is_some();
+        let summary = self.transfer_into(
+            &destination,
+            &options,
+            self.config.delete_source && !confirm,
+        )?;
+        if self.config.delete_source && confirm {
+            self.remove_source(&options)?;
+        }
+        Ok(summary)
+    }
+
+    /// 確認が設定されていれば、削除するエントリを示して同意を得てから移動元を削除する
+    fn remove_source(&self, options: &CopyOptions) -> AppResult<()> {
+        let source = &self.config.source_directory_path;
+        if let Some(confirmation) = &self.source_removal_confirmation {
+            if !confirmation(source.as_path(), &source.list_entries(options)?)? {
+                warn!("{}", Message::SourceKept(source).render(self.lang));
+                return Ok(());
+            }
+        }
+        source.remove_all_with_options(options)
+    }
+
+    /// 隣の一時ディレクトリにコピーして検証し、成功した場合に限り移動先へリネームする
+    ///
+    /// 移動先は完成した状態でのみ現れ、失敗した場合は一時ディレクトリを削除して移動先を作成しない。
+    fn transfer_via_staging(&self, options: &CopyOptions) -> AppResult<TransferSummary> {
+        let staging = self.config.dest_directory_path.create_staging()?;
+        let _lock =
+            TransferLock::acquire(staging.parent().unwrap_or(&staging), &self.now.to_rfc3339())?;
+
+        // 移動元はリネームが成功するまで削除しない
+        let result = self
+            .transfer_into(&staging, options, false)
+            .and_then(|summary| {
+                self.replace_destination_with(staging.clone())?;
+                Ok(summary)
+            });
+        let summary = match result {
+            Ok(summary) => summary,
+            Err(e) => {
+                warn!("{}", Message::RemovingStaging(&e).render(self.lang));
+                staging.remove()?;
+                return Err(e);
+            }
+        };
+        if self.config.delete_source {
+            self.remove_source(options)?;
+        }
+        Ok(summary)
+    }
+
+    fn replace_destination_with(
+        &self,
+        staging: WritableDirectoryPath,
+    ) -> AppResult<WritableDirectoryPath> {
+        let destination = &self.config.dest_directory_path;
+        // 検証から移動までの間に別のプロセスが書き込んだ場合も、既存のデータを上書きしない
+        if !destination.is_empty()? {
+            if !self.force {
+                return Err(AppError::DestinationNotEmpty(destination.to_path_buf()));
+            }
+            warn!(
+                "{}",
+                Message::ClearingDestination(destination).render(self.lang)
+            );
+        }
+        destination.replace_with(staging)
+    }
+
+    fn transfer_into(
+        &self,
+        destination: &WritableDirectoryPath,
+        options: &CopyOptions,
+        delete_source: bool,
+    ) -> AppResult<TransferSummary> {
+        info!(
+            "{}",
+            Message::TransferStarted {
+                source: &self.config.source_directory_path,
+                destination,
+            }
+            .render(self.lang)
+        );
+        // ファイルごとのログは、コピーとリネームを区別できる移動先の側で出力する
+        let progress = |path: &Path, bytes| {
+            if let Some(progress) = &self.progress {
+                progress(path, bytes);
+            }
+        };
+        let source = &self.config.source_directory_path;
+        // ツリーハッシュの一致を確認するまでは移動元を削除しない
+        let summary = if delete_source && !self.tree_hash {
+            destination.move_all_data_from_with_progress(source, options, progress)?
+        } else {
+            destination.copy_verified_data_from_with_progress(source, options, progress)?
+        };
+        if self.tree_hash {
+            self.verify_tree_hash(destination, options)?;
+            if delete_source {
+                source.remove_all_with_options(options)?;
+            }
+        }
+        if self.config.write_checksums {
+            destination.write_checksum_file(&self.digest_options())?;
+        }
+        self.write_manifest(destination)?;
+        Ok(summary)
+    }
+
+    /// 移動元と移動先のツリーハッシュを比較し、一致しなければ移動先をロールバックする
+    fn verify_tree_hash(
+        &self,
+        destination: &WritableDirectoryPath,
+        options: &CopyOptions,
+    ) -> AppResult<()> {
+        let source = &self.config.source_directory_path;
+        // チェックサムやマニフェストを書き出す前に、コピーしたファイルだけで計算する
+        let source_tree_hash = source.tree_hash(options)?;
+        let destination_tree_hash = destination.tree_hash(options)?;
+        info!(
+            "{}",
+            Message::TreeHash {
+                source: &source_tree_hash,
+                destination: &destination_tree_hash,
             }
+            .render(self.lang)
+        );
+        if source_tree_hash == destination_tree_hash {
+            return Ok(());
+        }
+        let error = AppError::IntegrityMismatch {
+            source_path: source.to_path_buf(),
+            destination_path: destination.to_path_buf(),
         };
+        warn!("{}", Message::RollingBack(&error).render(self.lang));
+        options.storage_backend.remove_all(destination)?;
+        Err(error)
+    }
 
-        if let Err(e) = result {
-            self.config.dest_directory_path.remove_all()?;
-            return Err(e);
+    /// 移動後の移動先全体のハッシュ値を計算するためのオプション
+    fn digest_options(&self) -> CopyOptions {
+        CopyOptions {
+            hash_algorithm: self.config.hash_algorithm,
+            symlink_policy: self.config.symlink_policy,
+            buffer_size: self.config.buffer_size,
+            ..CopyOptions::default()
         }
+    }
 
-        self.config.source_directory_path.remove_all()?;
-        println!("ファイルを正常に移動しました。");
+    /// 移動先に存在するファイルのハッシュ値を記録し、後から改ざんや欠落を検出できるようにする
+    fn write_manifest(&self, destination: &WritableDirectoryPath) -> AppResult<()> {
+        let options = self.digest_options();
+        Manifest::new(options.hash_algorithm, destination.digest_files(&options)?)?
+            .write_to(destination)?;
+        info!(
+            "{}",
+            Message::ManifestWritten(&destination.join(MANIFEST_FILE_NAME)).render(self.lang)
+        );
         Ok(())
     }
 }
@@ -76,12 +594,31 @@ impl DirectoryDataTransferService {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config_builder::{json_config_builder::JsonConfigBuilder, ConfigBuilder};
-    use chrono::TimeZone;
-    use std::{fs, path::Path};
+    use crate::clock::FixedClock;
+    use crate::config::timezone::ConfigTimeZone;
+    use crate::config_builder::{
+        arg_config_builder::ArgConfigBuilder, json_config_builder::JsonConfigBuilder, ConfigBuilder,
+    };
+    use adapter::{
+        copy_options::GlobPatterns,
+        directory_path::{readable_directory_path::ReadableDirectoryPath, CHECKSUM_FILE_NAME},
+        storage_backend::LocalStorageBackend,
+    };
+    use chrono::{TimeZone, Utc};
+    use std::{
+        fs,
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        },
+    };
     use tempfile::TempDir;
 
     fn create_test_config_with_weekday(weekday: &str) -> (Config, TempDir) {
+        create_test_config_with_weekday_json(&format!("\"{}\"", weekday))
+    }
+
+    fn create_test_config_with_weekday_json(weekday_json: &str) -> (Config, TempDir) {
         let temp_dir = TempDir::new().unwrap();
         let source_dir = temp_dir.path().join("source");
         let dest_dir = temp_dir.path().join("dest");
@@ -104,11 +641,11 @@ mod tests {
             r#"{{
                 "source_directory_path": "{}",
                 "destination_directory_path": "{}",
-                "weekday": "{}"
+                "weekday": {}
             }}"#,
             source_dir.to_str().unwrap().replace("\\", "/"),
             dest_dir.to_str().unwrap().replace("\\", "/"),
-            weekday
+            weekday_json
         );
 
         let temp_file = temp_dir.path().join("json_content.json");
@@ -127,94 +664,1178 @@ mod tests {
         let service = DirectoryDataTransferService::new(config);
 
         // ===== Assert =====
+        // 設定の構築では移動先ディレクトリを作成しない
         assert!(service.config.source_directory_path.exists());
-        assert!(service.config.dest_directory_path.exists());
+        assert!(!service.config.dest_directory_path.exists());
     }
 
     #[test]
-    fn directory_data_transfer_service_validate_fails_on_wrong_weekday() {
+    fn directory_data_transfer_service_transfer_creates_destination() {
         // ===== Arrange =====
-        let (config, _temp_dir) = create_test_config_with_weekday("Thu");
-        // 2024年1月1日は月曜日
-        let now = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
-        let service = DirectoryDataTransferService::new(config).with_custom_now(now);
+        let (config, _temp_dir) = create_test_config_with_weekday("Mon");
+        let service = DirectoryDataTransferService::new(config);
 
         // ===== Act =====
-        let result = service.validate();
+        let result = service.transfer();
 
         // ===== Assert =====
-        assert!(result.is_err());
+        assert!(result.is_ok());
+        assert!(service.config.dest_directory_path.is_dir());
+        assert!(service.config.dest_directory_path.join("test.txt").exists());
     }
 
     #[test]
-    fn directory_data_transfer_service_validate_fails_when_destination_not_empty() {
+    fn directory_data_transfer_service_transfer_with_tree_hash_succeeds() {
         // ===== Arrange =====
         let (config, _temp_dir) = create_test_config_with_weekday("Mon");
-        // 2024年1月1日は月曜日
-        let now = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
-        let service = DirectoryDataTransferService::new(config).with_custom_now(now);
-
-        // 移動先ディレクトリにファイルを作成
-        let test_file = service.config.dest_directory_path.join("test.txt");
-        let test_file = test_file.to_str().unwrap().replace("\\", "/");
-        let test_file = Path::new(&test_file);
-        fs::write(test_file, "test content").unwrap();
+        let service = DirectoryDataTransferService::new(config).with_tree_hash(true);
 
         // ===== Act =====
-        let result = service.validate();
+        let result = service.transfer();
 
         // ===== Assert =====
-        assert!(result.is_err());
+        assert!(result.is_ok());
+        assert!(service.config.dest_directory_path.join("test.txt").exists());
     }
 
     #[test]
-    fn directory_data_transfer_service_transfer_successfully_moves_files() {
+    fn directory_data_transfer_service_tree_hash_mismatch_keeps_source_and_rolls_back() {
         // ===== Arrange =====
         let (config, _temp_dir) = create_test_config_with_weekday("Mon");
-        // 2024年1月1日は月曜日
-        let now = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
-        let service = DirectoryDataTransferService::new(config).with_custom_now(now);
+        let source_dir = config.source_directory_path.to_path_buf();
+        // コピーの直後に移動元を同じサイズの別の内容に書き換え、構成とサイズの検証は通るようにする
+        let service = DirectoryDataTransferService::new(config)
+            .with_custom_now(Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap())
+            .with_tree_hash(true)
+            .with_progress(move |path, _| {
+                fs::write(source_dir.join(path), "TEST CONTENT").unwrap()
+            });
 
         // ===== Act =====
         let result = service.transfer();
 
         // ===== Assert =====
-        assert!(result.is_ok());
-        // ソースディレクトリが削除されていることを確認
-        assert!(service.config.source_directory_path.is_empty().unwrap());
-        // 移動先ディレクトリにファイルが存在することを確認
-        let dest_file = service.config.dest_directory_path.join("test.txt");
-        assert!(!service.config.dest_directory_path.is_empty().unwrap());
-        let content = fs::read_to_string(&dest_file).unwrap();
-        assert_eq!(content, "test content");
+        assert!(matches!(result, Err(AppError::IntegrityMismatch { .. })));
+        assert_eq!(
+            fs::read_to_string(service.config.source_directory_path.join("test.txt")).unwrap(),
+            "TEST CONTENT"
+        );
+        assert!(service.config.dest_directory_path.is_empty().unwrap());
+    }
+
+    /// 書き込みをすべて拒否する、ローカル以外を想定したストレージ
+    struct ReadOnlyStorageBackend;
+
+    impl StorageBackend for ReadOnlyStorageBackend {
+        fn create_dir(&self, _path: &Path) -> AppResult<()> {
+            Err(AppError::Io(std::io::Error::from(
+                std::io::ErrorKind::PermissionDenied,
+            )))
+        }
+
+        fn copy_file(&self, _from: &Path, _to: &Path, _options: &CopyOptions) -> AppResult<u64> {
+            Err(AppError::Io(std::io::Error::from(
+                std::io::ErrorKind::PermissionDenied,
+            )))
+        }
+
+        fn list(&self, _dir: &Path, _options: &CopyOptions) -> AppResult<Vec<PathBuf>> {
+            Ok(Vec::new())
+        }
+
+        fn hash(&self, _path: &Path, _options: &CopyOptions) -> AppResult<String> {
+            Ok(String::new())
+        }
+
+        fn remove_all(&self, _dir: &Path) -> AppResult<()> {
+            Ok(())
+        }
+
+        fn is_empty(&self, _dir: &Path) -> AppResult<bool> {
+            Ok(true)
+        }
+
+        fn is_file(&self, _path: &Path) -> AppResult<bool> {
+            Ok(false)
+        }
     }
 
     #[test]
-    fn directory_data_transfer_service_transfer_removes_destination_on_integrity_error() {
+    fn directory_data_transfer_service_transfer_writes_through_storage_backend() {
         // ===== Arrange =====
         let (config, _temp_dir) = create_test_config_with_weekday("Mon");
-        // 2024年1月1日は月曜日
-        let now = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
-        let service = DirectoryDataTransferService::new(config).with_custom_now(now);
+        let service =
+            DirectoryDataTransferService::new(config).with_storage_backend(ReadOnlyStorageBackend);
 
-        // 移動先ディレクトリに異なるファイルを作成（整合性エラーを引き起こす）
-        let dest_file = service
+        // ===== Act =====
+        let result = service.transfer();
+
+        // ===== Assert =====
+        // ローカル以外のストレージではリネームせず、ストレージの失敗がそのまま返る
+        assert!(
+            matches!(result, Err(AppError::Io(e)) if e.kind() == std::io::ErrorKind::PermissionDenied)
+        );
+        assert!(service
             .config
-            .dest_directory_path
-            .join("different.txt")
-            .to_str()
-            .unwrap()
-            .replace("\\", "/");
-        let dest_file = Path::new(&dest_file);
-        println!("移動先ディレクトリ: {:?}", dest_file.to_str());
-        fs::write(dest_file, "different content").unwrap();
+            .source_directory_path
+            .join("test.txt")
+            .exists());
+    }
+
+    /// ローカルに書き込みつつ、コピーの時点で最終的な移動先が存在したかを記録するストレージ
+    struct ObservingStorageBackend {
+        destination: PathBuf,
+        destination_seen: Arc<AtomicBool>,
+    }
+
+    impl StorageBackend for ObservingStorageBackend {
+        fn create_dir(&self, path: &Path) -> AppResult<()> {
+            LocalStorageBackend.create_dir(path)
+        }
+
+        fn copy_file(&self, from: &Path, to: &Path, options: &CopyOptions) -> AppResult<u64> {
+            if self.destination.exists() {
+                self.destination_seen.store(true, Ordering::SeqCst);
+            }
+            LocalStorageBackend.copy_file(from, to, options)
+        }
+
+        fn list(&self, dir: &Path, options: &CopyOptions) -> AppResult<Vec<PathBuf>> {
+            LocalStorageBackend.list(dir, options)
+        }
+
+        fn hash(&self, path: &Path, options: &CopyOptions) -> AppResult<String> {
+            LocalStorageBackend.hash(path, options)
+        }
+
+        fn remove_all(&self, dir: &Path) -> AppResult<()> {
+            LocalStorageBackend.remove_all(dir)
+        }
+
+        fn is_empty(&self, dir: &Path) -> AppResult<bool> {
+            LocalStorageBackend.is_empty(dir)
+        }
+
+        fn is_file(&self, path: &Path) -> AppResult<bool> {
+            LocalStorageBackend.is_file(path)
+        }
+    }
+
+    /// ローカルに書き込みつつ、移動先の確認には指定した結果を返すストレージ
+    struct MisreportingStorageBackend {
+        extra_entry: Option<PathBuf>,
+        reports_empty: bool,
+    }
+
+    impl StorageBackend for MisreportingStorageBackend {
+        fn create_dir(&self, path: &Path) -> AppResult<()> {
+            LocalStorageBackend.create_dir(path)
+        }
+
+        fn copy_file(&self, from: &Path, to: &Path, options: &CopyOptions) -> AppResult<u64> {
+            LocalStorageBackend.copy_file(from, to, options)
+        }
+
+        fn list(&self, dir: &Path, options: &CopyOptions) -> AppResult<Vec<PathBuf>> {
+            let mut entries = LocalStorageBackend.list(dir, options)?;
+            entries.extend(self.extra_entry.clone());
+            Ok(entries)
+        }
+
+        fn hash(&self, path: &Path, options: &CopyOptions) -> AppResult<String> {
+            LocalStorageBackend.hash(path, options)
+        }
+
+        fn remove_all(&self, dir: &Path) -> AppResult<()> {
+            LocalStorageBackend.remove_all(dir)
+        }
+
+        fn is_empty(&self, dir: &Path) -> AppResult<bool> {
+            Ok(self.reports_empty && LocalStorageBackend.is_empty(dir)?)
+        }
+
+        fn is_file(&self, path: &Path) -> AppResult<bool> {
+            LocalStorageBackend.is_file(path)
+        }
+    }
+
+    #[test]
+    fn directory_data_transfer_service_verifies_structure_through_storage_backend() {
+        // ===== Arrange =====
+        let (config, _temp_dir) = create_test_config_with_weekday("Mon");
+        let service = DirectoryDataTransferService::new(config)
+            .with_custom_now(Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap())
+            .with_storage_backend(MisreportingStorageBackend {
+                extra_entry: Some(PathBuf::from("unexpected.txt")),
+                reports_empty: true,
+            });
 
         // ===== Act =====
         let result = service.transfer();
 
         // ===== Assert =====
-        assert!(result.is_err());
-        assert!(service.config.dest_directory_path.is_empty().unwrap());
-        assert!(!service.config.source_directory_path.is_empty().unwrap());
+        assert!(matches!(result, Err(AppError::IntegrityMismatch { .. })));
+        assert!(service
+            .config
+            .source_directory_path
+            .join("test.txt")
+            .exists());
+    }
+
+    #[test]
+    fn directory_data_transfer_service_checks_destination_through_storage_backend() {
+        // ===== Arrange =====
+        let (config, _temp_dir) = create_test_config_with_weekday("Mon");
+        let service = DirectoryDataTransferService::new(config)
+            .with_custom_now(Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap())
+            .with_storage_backend(MisreportingStorageBackend {
+                extra_entry: None,
+                reports_empty: false,
+            });
+
+        // ===== Act =====
+        let result = service.validate();
+
+        // ===== Assert =====
+        assert!(matches!(result, Err(AppError::DestinationNotEmpty(_))));
+    }
+
+    fn staging_residue(destination: &Path) -> Vec<PathBuf> {
+        fs::read_dir(destination.parent().unwrap())
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.to_string_lossy().contains(".tmp-"))
+            .collect()
+    }
+
+    #[test]
+    fn directory_data_transfer_service_transfer_with_staging_creates_destination_after_verification(
+    ) {
+        // ===== Arrange =====
+        let (config, _temp_dir) = create_test_config_with_weekday("Mon");
+        let destination = config.dest_directory_path.to_path_buf();
+        let destination_seen = Arc::default();
+        let service = DirectoryDataTransferService::new(config)
+            .with_staging(true)
+            .with_storage_backend(ObservingStorageBackend {
+                destination: destination.clone(),
+                destination_seen: Arc::clone(&destination_seen),
+            });
+
+        // ===== Act =====
+        let result = service.transfer();
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert!(!destination_seen.load(Ordering::SeqCst));
+        assert!(destination.join("test.txt").exists());
+        assert!(destination.join(MANIFEST_FILE_NAME).exists());
+        assert!(!service
+            .config
+            .source_directory_path
+            .join("test.txt")
+            .exists());
+        assert!(staging_residue(&destination).is_empty());
+    }
+
+    #[test]
+    fn directory_data_transfer_service_transfer_with_staging_leaves_no_residue_on_failure() {
+        // ===== Arrange =====
+        let (config, _temp_dir) = create_test_config_with_weekday("Mon");
+        let destination = config.dest_directory_path.to_path_buf();
+        let service = DirectoryDataTransferService::new(config)
+            .with_staging(true)
+            .with_storage_backend(ReadOnlyStorageBackend);
+
+        // ===== Act =====
+        let result = service.transfer();
+
+        // ===== Assert =====
+        assert!(result.is_err());
+        assert!(!destination.exists());
+        assert!(staging_residue(&destination).is_empty());
+        assert!(service
+            .config
+            .source_directory_path
+            .join("test.txt")
+            .exists());
+    }
+
+    #[test]
+    fn directory_data_transfer_service_transfer_keeps_source_when_removal_is_declined() {
+        // ===== Arrange =====
+        let (config, _temp_dir) = create_test_config_with_weekday("Mon");
+        let service = DirectoryDataTransferService::new(config).with_source_removal_confirmation(
+            |_, entries| {
+                assert_eq!(entries, [PathBuf::from("test.txt")]);
+                Ok(false)
+            },
+        );
+
+        // ===== Act =====
+        let result = service.transfer();
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert!(service.config.dest_directory_path.join("test.txt").exists());
+        assert!(service
+            .config
+            .source_directory_path
+            .join("test.txt")
+            .exists());
+    }
+
+    #[test]
+    fn directory_data_transfer_service_transfer_removes_source_when_removal_is_confirmed() {
+        // ===== Arrange =====
+        let (config, _temp_dir) = create_test_config_with_weekday("Mon");
+        let service = DirectoryDataTransferService::new(config)
+            .with_source_removal_confirmation(|_, _| Ok(true));
+
+        // ===== Act =====
+        let result = service.transfer();
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert!(service.config.dest_directory_path.join("test.txt").exists());
+        assert!(!service
+            .config
+            .source_directory_path
+            .join("test.txt")
+            .exists());
+    }
+
+    #[test]
+    fn directory_data_transfer_service_transfer_reports_progress_up_to_totals() {
+        // ===== Arrange =====
+        let (mut config, _temp_dir) = create_test_config_with_weekday("Mon");
+        // リネームによる移動ではなく、ファイル単位のコピーを行わせる
+        config.delete_source = false;
+        let copied = std::rc::Rc::new(std::cell::Cell::new(TransferTotals::default()));
+        let observed = copied.clone();
+        let service = DirectoryDataTransferService::new(config).with_progress(move |_, bytes| {
+            let totals = observed.get();
+            observed.set(TransferTotals {
+                files: totals.files + 1,
+                bytes: totals.bytes + bytes,
+            });
+        });
+        let totals = service.totals().unwrap();
+
+        // ===== Act =====
+        let result = service.transfer();
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert_eq!(totals.files, 1);
+        assert_eq!(copied.get(), totals);
+    }
+
+    #[test]
+    fn directory_data_transfer_service_transfer_times_out_and_rolls_back_destination() {
+        // ===== Arrange =====
+        let (mut config, _temp_dir) = create_test_config_with_weekday("Mon");
+        // リネームによる移動ではなく、ファイル単位のコピーを行わせる
+        config.delete_source = false;
+        let service = DirectoryDataTransferService::new(config).with_timeout(Some(Duration::ZERO));
+
+        // ===== Act =====
+        let result = service.transfer();
+
+        // ===== Assert =====
+        assert!(matches!(result, Err(AppError::Timeout(_))));
+        let destination: &Path = &service.config.dest_directory_path;
+        assert!(!destination.join("test.txt").exists());
+        assert!(service
+            .config
+            .source_directory_path
+            .join("test.txt")
+            .exists());
+    }
+
+    #[test]
+    fn directory_data_transfer_service_validate_fails_on_wrong_weekday() {
+        // ===== Arrange =====
+        let (config, _temp_dir) = create_test_config_with_weekday("Thu");
+        // 2024年1月1日は月曜日
+        let now = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let service = DirectoryDataTransferService::new(config).with_custom_now(now);
+
+        // ===== Act =====
+        let result = service.validate();
+
+        // ===== Assert =====
+        assert!(matches!(result, Err(AppError::WrongWeekday { .. })));
+    }
+
+    #[test]
+    fn directory_data_transfer_service_validate_uses_injected_clock() {
+        // ===== Arrange =====
+        let (thursday_config, _thursday_dir) = create_test_config_with_weekday("Thu");
+        let (monday_config, _monday_dir) = create_test_config_with_weekday("Mon");
+        // 2024年1月4日は木曜日
+        let clock = FixedClock(Local.with_ymd_and_hms(2024, 1, 4, 12, 0, 0).unwrap());
+
+        // ===== Act =====
+        let thursday_result = DirectoryDataTransferService::new(thursday_config)
+            .with_clock(&clock)
+            .and_then(|service| service.validate());
+        let monday_result = DirectoryDataTransferService::new(monday_config)
+            .with_clock(&clock)
+            .and_then(|service| service.validate());
+
+        // ===== Assert =====
+        assert!(thursday_result.is_ok());
+        assert!(matches!(
+            monday_result,
+            Err(AppError::WrongWeekday { actual, .. }) if actual == "Thu"
+        ));
+    }
+
+    #[test]
+    fn directory_data_transfer_service_renders_destination_with_injected_clock() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        fs::create_dir(&source_dir).unwrap();
+        fs::write(source_dir.join("test.txt"), "test content").unwrap();
+        let config = ArgConfigBuilder::new(
+            source_dir,
+            temp_dir.path().join("{yyyy}-{mm}-{dd}"),
+            vec!["Thu".to_string()],
+        )
+        .unwrap()
+        .build()
+        .unwrap();
+        // 2024年1月4日は木曜日。実行日とは異なる日付にする
+        let clock = FixedClock(Local.with_ymd_and_hms(2024, 1, 4, 12, 0, 0).unwrap());
+
+        // ===== Act =====
+        let result = DirectoryDataTransferService::new(config)
+            .with_clock(&clock)
+            .and_then(|service| service.transfer());
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert!(temp_dir.path().join("2024-01-04").join("test.txt").exists());
+    }
+
+    #[test]
+    fn directory_data_transfer_service_validate_proceeds_on_wrong_weekday_when_ignored() {
+        // ===== Arrange =====
+        let (config, _temp_dir) = create_test_config_with_weekday("Thu");
+        // 2024年1月1日は月曜日
+        let now = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let service = DirectoryDataTransferService::new(config)
+            .with_custom_now(now)
+            .with_ignore_weekday(true);
+
+        // ===== Act =====
+        let result = service.validate().and_then(|service| service.transfer());
+
+        // ===== Assert =====
+        assert_eq!(result.unwrap().files_copied, 1);
+    }
+
+    #[test]
+    fn directory_data_transfer_service_validate_report_lists_every_failed_check() {
+        // ===== Arrange =====
+        let (config, _temp_dir) = create_test_config_with_weekday("Thu");
+        fs::create_dir_all(&*config.dest_directory_path).unwrap();
+        fs::write(config.dest_directory_path.join("existing.txt"), "existing").unwrap();
+        // 2024年1月1日は月曜日
+        let now = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let service = DirectoryDataTransferService::new(config).with_custom_now(now);
+
+        // ===== Act =====
+        let report = service.validate_report();
+
+        // ===== Assert =====
+        assert!(!report.is_passed());
+        let failures: Vec<ValidationCheck> = report.failures().map(|(check, _)| check).collect();
+        assert_eq!(
+            failures,
+            vec![ValidationCheck::Weekday, ValidationCheck::DestinationEmpty]
+        );
+        assert_eq!(report.checks.len(), ValidationCheck::ALL.len());
+        assert!(matches!(
+            report.failures().next(),
+            Some((_, AppError::WrongWeekday { .. }))
+        ));
+    }
+
+    #[test]
+    fn directory_data_transfer_service_validate_report_passes_for_valid_config() {
+        // ===== Arrange =====
+        let (config, _temp_dir) = create_test_config_with_weekday("Mon");
+        // 2024年1月1日は月曜日
+        let now = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let service = DirectoryDataTransferService::new(config).with_custom_now(now);
+
+        // ===== Act =====
+        let report = service.validate_report();
+
+        // ===== Assert =====
+        assert!(report.is_passed());
+        assert_eq!(report.failures().count(), 0);
+    }
+
+    #[test]
+    fn directory_data_transfer_service_validate_applies_date_offset_to_weekday() {
+        // ===== Arrange =====
+        // 2024年1月1日は月曜日。-1日で前日の日曜日、+1日で翌日の火曜日として判定する
+        let now = Local.with_ymd_and_hms(2024, 1, 1, 1, 0, 0).unwrap();
+
+        for (offset, weekday) in [(-1, "Sun"), (1, "Tue")] {
+            let (mut config, _temp_dir) = create_test_config_with_weekday(weekday);
+            config.date_offset_days = offset;
+            config.date_offset_applies_to_weekday = true;
+            let service = DirectoryDataTransferService::new(config).with_custom_now(now);
+
+            // ===== Act =====
+            let result = service.validate();
+
+            // ===== Assert =====
+            assert!(result.is_ok());
+        }
+    }
+
+    #[test]
+    fn directory_data_transfer_service_validate_ignores_date_offset_by_default() {
+        // ===== Arrange =====
+        let (mut config, _temp_dir) = create_test_config_with_weekday("Sun");
+        config.date_offset_days = -1;
+        // 2024年1月1日は月曜日
+        let now = Local.with_ymd_and_hms(2024, 1, 1, 1, 0, 0).unwrap();
+        let service = DirectoryDataTransferService::new(config).with_custom_now(now);
+
+        // ===== Act =====
+        let result = service.validate();
+
+        // ===== Assert =====
+        assert!(matches!(result, Err(AppError::WrongWeekday { .. })));
+    }
+
+    #[test]
+    fn directory_data_transfer_service_validate_succeeds_on_any_listed_weekday() {
+        // ===== Arrange =====
+        // 2024年1月6日は土曜日、1月7日は日曜日
+        let saturday = Local.with_ymd_and_hms(2024, 1, 6, 0, 0, 0).unwrap();
+        let sunday = Local.with_ymd_and_hms(2024, 1, 7, 0, 0, 0).unwrap();
+
+        for now in [saturday, sunday] {
+            let (config, _temp_dir) = create_test_config_with_weekday_json(r#"["Sat", "Sun"]"#);
+            let service = DirectoryDataTransferService::new(config).with_custom_now(now);
+
+            // ===== Act =====
+            let result = service.validate();
+
+            // ===== Assert =====
+            assert!(result.is_ok());
+        }
+    }
+
+    #[test]
+    fn directory_data_transfer_service_validate_fails_on_unlisted_weekday() {
+        // ===== Arrange =====
+        let (config, _temp_dir) = create_test_config_with_weekday_json(r#"["Sat", "Sun"]"#);
+        // 2024年1月1日は月曜日
+        let now = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let service = DirectoryDataTransferService::new(config).with_custom_now(now);
+
+        // ===== Act =====
+        let result = service.validate();
+
+        // ===== Assert =====
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn directory_data_transfer_service_validate_evaluates_weekday_in_configured_timezone() {
+        // ===== Arrange =====
+        // UTCでは月曜日23時、東京では火曜日8時
+        let now = Utc
+            .with_ymd_and_hms(2024, 1, 1, 23, 0, 0)
+            .unwrap()
+            .with_timezone(&Local);
+
+        let (mut utc_config, _utc_temp_dir) = create_test_config_with_weekday("Mon");
+        utc_config.timezone = ConfigTimeZone::try_from("UTC".to_string()).unwrap();
+        let utc_service = DirectoryDataTransferService::new(utc_config).with_custom_now(now);
+
+        let (mut tokyo_config, _tokyo_temp_dir) = create_test_config_with_weekday("Mon");
+        tokyo_config.timezone = ConfigTimeZone::try_from("Asia/Tokyo".to_string()).unwrap();
+        let tokyo_service = DirectoryDataTransferService::new(tokyo_config).with_custom_now(now);
+
+        // ===== Act =====
+        let utc_result = utc_service.validate();
+        let tokyo_result = tokyo_service.validate();
+
+        // ===== Assert =====
+        assert!(utc_result.is_ok());
+        assert!(tokyo_result.is_err());
+    }
+
+    #[test]
+    fn directory_data_transfer_service_validate_fails_when_destination_not_empty() {
+        // ===== Arrange =====
+        let (config, _temp_dir) = create_test_config_with_weekday("Mon");
+        // 2024年1月1日は月曜日
+        let now = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let service = DirectoryDataTransferService::new(config).with_custom_now(now);
+
+        // 移動先ディレクトリにファイルを作成
+        fs::create_dir_all(&*service.config.dest_directory_path).unwrap();
+        let test_file = service.config.dest_directory_path.join("test.txt");
+        let test_file = test_file.to_str().unwrap().replace("\\", "/");
+        let test_file = Path::new(&test_file);
+        fs::write(test_file, "test content").unwrap();
+
+        // ===== Act =====
+        let result = service.validate();
+
+        // ===== Assert =====
+        assert!(matches!(result, Err(AppError::DestinationNotEmpty(_))));
+    }
+
+    #[test]
+    fn directory_data_transfer_service_transfer_skips_existing_matching_files() {
+        // ===== Arrange =====
+        let (mut config, _temp_dir) = create_test_config_with_weekday("Mon");
+        config.skip_existing_matching = true;
+        let now = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let service = DirectoryDataTransferService::new(config).with_custom_now(now);
+        // 中断した前回の転送でコピー済みのファイル
+        fs::create_dir_all(&*service.config.dest_directory_path).unwrap();
+        fs::write(
+            service.config.dest_directory_path.join("test.txt"),
+            "test content",
+        )
+        .unwrap();
+
+        // ===== Act =====
+        let result = service.validate().and_then(|service| service.transfer());
+
+        // ===== Assert =====
+        let summary = result.unwrap();
+        assert_eq!(summary.files_skipped, 1);
+        assert_eq!(summary.files_copied, 0);
+    }
+
+    #[test]
+    fn directory_data_transfer_service_transfer_skipping_existing_files_can_run_twice() {
+        // ===== Arrange =====
+        let (mut config, _temp_dir) = create_test_config_with_weekday("Mon");
+        config.skip_existing_matching = true;
+        config.delete_source = false;
+        let now = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let destination = config.dest_directory_path.to_path_buf();
+        let service = DirectoryDataTransferService::new(config)
+            .with_custom_now(now)
+            .validate()
+            .unwrap();
+        service.transfer().unwrap();
+        // 前回の実行で書き出したマニフェストに加えて、利用者が置いたファイルも移動先に残っている
+        fs::write(destination.join("notes.txt"), "memo").unwrap();
+
+        // ===== Act =====
+        let result = service.validate().and_then(|service| service.transfer());
+
+        // ===== Assert =====
+        let summary = result.unwrap();
+        assert_eq!(summary.files_skipped, 1);
+        assert_eq!(summary.files_copied, 0);
+        assert!(destination.join(MANIFEST_FILE_NAME).exists());
+    }
+
+    fn create_test_config_with_paths(source_dir: &Path, dest_dir: &Path) -> Config {
+        let mut source_perms = fs::metadata(source_dir).unwrap().permissions();
+        source_perms.set_readonly(true);
+        fs::set_permissions(source_dir, source_perms).unwrap();
+
+        ArgConfigBuilder::new(
+            source_dir.to_path_buf(),
+            dest_dir.to_path_buf(),
+            vec!["Mon".to_string()],
+        )
+        .unwrap()
+        .build()
+        .unwrap()
+    }
+
+    #[test]
+    fn directory_data_transfer_service_validate_fails_when_source_is_inside_destination() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let dest_dir = temp_dir.path().join("dest");
+        let source_dir = dest_dir.join("source");
+        fs::create_dir_all(&source_dir).unwrap();
+        let config = create_test_config_with_paths(&source_dir, &dest_dir);
+        // 2024年1月1日は月曜日
+        let now = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let service = DirectoryDataTransferService::new(config).with_custom_now(now);
+
+        // ===== Act =====
+        let result = service.validate();
+
+        // ===== Assert =====
+        assert!(matches!(result, Err(AppError::NestedDirectories { .. })));
+    }
+
+    #[test]
+    fn directory_data_transfer_service_validate_fails_when_destination_is_inside_source() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = source_dir.join("dest");
+        fs::create_dir_all(&dest_dir).unwrap();
+        let config = create_test_config_with_paths(&source_dir, &dest_dir);
+        // 2024年1月1日は月曜日
+        let now = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let service = DirectoryDataTransferService::new(config).with_custom_now(now);
+
+        // ===== Act =====
+        let result = service.validate();
+
+        // ===== Assert =====
+        assert!(matches!(result, Err(AppError::NestedDirectories { .. })));
+    }
+
+    #[test]
+    fn directory_data_transfer_service_validate_succeeds_with_disjoint_directories() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+        fs::create_dir(&source_dir).unwrap();
+        let config = create_test_config_with_paths(&source_dir, &dest_dir);
+        // 2024年1月1日は月曜日
+        let now = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let service = DirectoryDataTransferService::new(config).with_custom_now(now);
+
+        // ===== Act =====
+        let result = service.validate();
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn directory_data_transfer_service_validate_warns_on_empty_source_by_default() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+        fs::create_dir(&source_dir).unwrap();
+        let config = create_test_config_with_paths(&source_dir, &dest_dir);
+        // 2024年1月1日は月曜日
+        let now = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let service = DirectoryDataTransferService::new(config).with_custom_now(now);
+
+        // ===== Act =====
+        let result = service.validate();
+
+        // ===== Assert =====
+        assert_eq!(
+            result.unwrap().config.empty_source_policy,
+            EmptySourcePolicy::Warn
+        );
+    }
+
+    #[test]
+    fn directory_data_transfer_service_validate_fails_on_empty_source_with_error_policy() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+        fs::create_dir(&source_dir).unwrap();
+        let mut config = create_test_config_with_paths(&source_dir, &dest_dir);
+        config.empty_source_policy = EmptySourcePolicy::Error;
+        // 2024年1月1日は月曜日
+        let now = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let service = DirectoryDataTransferService::new(config).with_custom_now(now);
+
+        // ===== Act =====
+        let result = service.validate();
+
+        // ===== Assert =====
+        assert!(matches!(result, Err(AppError::SourceEmpty(path)) if path == source_dir));
+    }
+
+    #[test]
+    fn directory_data_transfer_service_force_replaces_existing_destination_contents() {
+        // ===== Arrange =====
+        let (config, _temp_dir) = create_test_config_with_weekday("Mon");
+        // 2024年1月1日は月曜日
+        let now = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let service = DirectoryDataTransferService::new(config)
+            .with_custom_now(now)
+            .with_force(true);
+
+        // 移動先ディレクトリに古いデータを作成
+        let stale_dir = service.config.dest_directory_path.join("stale");
+        fs::create_dir_all(&stale_dir).unwrap();
+        fs::write(stale_dir.join("stale.txt"), "stale content").unwrap();
+
+        // ===== Act =====
+        let result = service.validate().and_then(|service| {
+            service.transfer()?;
+            Ok(service)
+        });
+
+        // ===== Assert =====
+        let service = result.unwrap();
+        assert!(!stale_dir.exists());
+        let content =
+            fs::read_to_string(service.config.dest_directory_path.join("test.txt")).unwrap();
+        assert_eq!(content, "test content");
+    }
+
+    #[test]
+    fn directory_data_transfer_service_required_capacity_matches_source_size() {
+        // ===== Arrange =====
+        let (config, _temp_dir) = create_test_config_with_weekday("Mon");
+        let service = DirectoryDataTransferService::new(config);
+
+        // ===== Act =====
+        let required = service
+            .config
+            .source_directory_path
+            .total_size_with_options(&service.copy_options())
+            .unwrap();
+
+        // ===== Assert =====
+        assert_eq!(required, "test content".len() as u64);
+    }
+
+    #[test]
+    fn directory_data_transfer_service_ensure_capacity_fails_when_space_is_insufficient() {
+        // ===== Act =====
+        let result = DirectoryDataTransferService::ensure_capacity(12, 11);
+
+        // ===== Assert =====
+        assert!(matches!(
+            result,
+            Err(AppError::InsufficientSpace {
+                required: 12,
+                available: 11
+            })
+        ));
+    }
+
+    #[test]
+    fn directory_data_transfer_service_ensure_capacity_succeeds_when_space_is_sufficient() {
+        // ===== Act =====
+        let result = DirectoryDataTransferService::ensure_capacity(12, 12);
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn directory_data_transfer_service_transfer_successfully_moves_files() {
+        // ===== Arrange =====
+        let (config, _temp_dir) = create_test_config_with_weekday("Mon");
+        // 2024年1月1日は月曜日
+        let now = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let service = DirectoryDataTransferService::new(config).with_custom_now(now);
+
+        // ===== Act =====
+        let result = service.transfer();
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        // ソースディレクトリが削除されていることを確認
+        assert!(service.config.source_directory_path.is_empty().unwrap());
+        // 移動先ディレクトリにファイルが存在することを確認
+        let dest_file = service.config.dest_directory_path.join("test.txt");
+        assert!(!service.config.dest_directory_path.is_empty().unwrap());
+        let content = fs::read_to_string(&dest_file).unwrap();
+        assert_eq!(content, "test content");
+    }
+
+    #[test]
+    fn directory_data_transfer_service_transfer_returns_summary_of_moved_entries() {
+        // ===== Arrange =====
+        let (config, _temp_dir) = create_test_config_with_weekday("Mon");
+        let source = config.source_directory_path.to_path_buf();
+        fs::create_dir_all(source.join("subdir").join("nested")).unwrap();
+        fs::write(source.join("subdir").join("nested.txt"), "nested").unwrap();
+        let service = DirectoryDataTransferService::new(config);
+
+        // ===== Act =====
+        let result = service.transfer();
+
+        // ===== Assert =====
+        assert_eq!(
+            result.unwrap(),
+            TransferSummary {
+                files_copied: 2,
+                directories_created: 2,
+                total_bytes: ("test content".len() + "nested".len()) as u64,
+                files_skipped: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn directory_data_transfer_service_transfer_leaves_excluded_files_in_source() {
+        // ===== Arrange =====
+        let (mut config, _temp_dir) = create_test_config_with_weekday("Mon");
+        let skipped_file = config.source_directory_path.join("skipped.tmp");
+        fs::write(&skipped_file, "temporary").unwrap();
+        config.exclude_patterns = GlobPatterns::new(vec!["*.tmp".to_string()]).unwrap();
+        let service = DirectoryDataTransferService::new(config);
+
+        // ===== Act =====
+        let result = service.transfer();
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert!(skipped_file.exists());
+        assert!(!service
+            .config
+            .dest_directory_path
+            .join("skipped.tmp")
+            .exists());
+        assert!(service.config.dest_directory_path.join("test.txt").exists());
+    }
+
+    #[test]
+    fn directory_data_transfer_service_transfer_clears_source_by_default() {
+        // ===== Arrange =====
+        let (config, _temp_dir) = create_test_config_with_weekday("Mon");
+        let service = DirectoryDataTransferService::new(config);
+
+        // ===== Act =====
+        let result = service.transfer();
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert!(service.config.source_directory_path.is_empty().unwrap());
+        assert!(service.config.dest_directory_path.join("test.txt").exists());
+    }
+
+    #[test]
+    fn directory_data_transfer_service_transfer_replicates_empty_subdirectories() {
+        // リネームによる移動と、除外パターン指定時のコピーによる移動の両方を確認する
+        for exclude_patterns in [vec![], vec!["*.never".to_string()]] {
+            // ===== Arrange =====
+            let (mut config, _temp_dir) = create_test_config_with_weekday("Mon");
+            config.exclude_patterns = GlobPatterns::new(exclude_patterns).unwrap();
+            let source = config.source_directory_path.to_path_buf();
+            fs::create_dir_all(source.join("logs")).unwrap();
+            fs::create_dir_all(source.join("cache").join("empty")).unwrap();
+            let service = DirectoryDataTransferService::new(config);
+
+            // ===== Act =====
+            let result = service.transfer();
+
+            // ===== Assert =====
+            assert!(result.is_ok());
+            let destination = &service.config.dest_directory_path;
+            assert!(destination.join("logs").is_dir());
+            assert!(destination.join("cache").join("empty").is_dir());
+            assert!(!source.join("logs").exists());
+        }
+    }
+
+    #[test]
+    fn directory_data_transfer_service_transfer_keeps_source_when_delete_source_is_false() {
+        // ===== Arrange =====
+        let (mut config, _temp_dir) = create_test_config_with_weekday("Mon");
+        config.delete_source = false;
+        let service = DirectoryDataTransferService::new(config);
+
+        // ===== Act =====
+        let result = service.transfer();
+
+        // ===== Assert =====
+        assert_eq!(result.unwrap().files_copied, 1);
+        let source_file = service.config.source_directory_path.join("test.txt");
+        let dest_file = service.config.dest_directory_path.join("test.txt");
+        assert_eq!(fs::read_to_string(source_file).unwrap(), "test content");
+        assert_eq!(fs::read_to_string(dest_file).unwrap(), "test content");
+    }
+
+    #[test]
+    fn directory_data_transfer_service_plan_lists_removals_and_copies_without_changes() {
+        // ===== Arrange =====
+        let (config, _temp_dir) = create_test_config_with_weekday("Mon");
+        let source = config.source_directory_path.to_path_buf();
+        let destination = config.dest_directory_path.to_path_buf();
+        fs::create_dir_all(destination.join("old_dir")).unwrap();
+        fs::write(destination.join("old_dir").join("old.txt"), "old").unwrap();
+        fs::write(destination.join("stale.txt"), "stale").unwrap();
+        let service = DirectoryDataTransferService::new(config).with_force(true);
+
+        // ===== Act =====
+        let result = service.plan();
+
+        // ===== Assert =====
+        assert_eq!(
+            result.unwrap(),
+            TransferPlan {
+                removals: vec![
+                    destination.join("old_dir"),
+                    destination.join("old_dir").join("old.txt"),
+                    destination.join("stale.txt"),
+                ],
+                copies: vec![source.join("test.txt")],
+            }
+        );
+        assert!(destination.join("stale.txt").exists());
+        assert!(source.join("test.txt").exists());
+        assert!(!destination.join("test.txt").exists());
+    }
+
+    #[test]
+    fn directory_data_transfer_service_transfer_writes_verifiable_checksum_file() {
+        // ===== Arrange =====
+        let (mut config, _temp_dir) = create_test_config_with_weekday("Mon");
+        config.write_checksums = true;
+        let service = DirectoryDataTransferService::new(config);
+
+        // ===== Act =====
+        let result = service.transfer();
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        let destination =
+            ReadableDirectoryPath::new(service.config.dest_directory_path.to_path_buf()).unwrap();
+        let checksums =
+            fs::read_to_string(service.config.dest_directory_path.join(CHECKSUM_FILE_NAME))
+                .unwrap();
+        assert!(checksums.ends_with("  test.txt\n"));
+        assert!(destination.verify_checksum_file().unwrap().is_empty());
+    }
+
+    #[test]
+    fn directory_data_transfer_service_transfer_moves_only_files_modified_since() {
+        // ===== Arrange =====
+        let (config, _temp_dir) = create_test_config_with_weekday("Mon");
+        let source = config.source_directory_path.to_path_buf();
+        let since = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        for (name, date) in [
+            (
+                "old.txt",
+                Local.with_ymd_and_hms(2023, 12, 31, 0, 0, 0).unwrap(),
+            ),
+            (
+                "new.txt",
+                Local.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap(),
+            ),
+        ] {
+            fs::write(source.join(name), name).unwrap();
+            fs::File::options()
+                .write(true)
+                .open(source.join(name))
+                .unwrap()
+                .set_modified(date.into())
+                .unwrap();
+        }
+        fs::remove_file(source.join("test.txt")).unwrap();
+        let service = DirectoryDataTransferService::new(config).with_modified_since(Some(since));
+
+        // ===== Act =====
+        let result = service.transfer();
+
+        // ===== Assert =====
+        assert_eq!(result.unwrap().files_copied, 1);
+        assert!(service.config.dest_directory_path.join("new.txt").exists());
+        assert!(!service.config.dest_directory_path.join("old.txt").exists());
+        assert!(source.join("old.txt").exists());
+        assert!(!source.join("new.txt").exists());
+    }
+
+    #[test]
+    fn directory_data_transfer_service_transfer_moves_only_included_files() {
+        // ===== Arrange =====
+        let (mut config, _temp_dir) = create_test_config_with_weekday("Mon");
+        let source = config.source_directory_path.to_path_buf();
+        fs::create_dir_all(source.join("DCIM")).unwrap();
+        fs::write(source.join("DCIM").join("photo.JPG"), "jpg").unwrap();
+        fs::write(source.join("DCIM").join("photo.raw"), "raw").unwrap();
+        fs::write(source.join("DCIM").join("photo.xmp"), "xmp").unwrap();
+        config.include_patterns =
+            GlobPatterns::new(vec!["*.{jpg,JPG}".to_string(), "*.raw".to_string()]).unwrap();
+        let service = DirectoryDataTransferService::new(config);
+
+        // ===== Act =====
+        let result = service.transfer();
+
+        // ===== Assert =====
+        let dest = service.config.dest_directory_path.join("DCIM");
+        assert_eq!(result.unwrap().files_copied, 2);
+        assert!(dest.join("photo.JPG").exists());
+        assert!(dest.join("photo.raw").exists());
+        assert!(!dest.join("photo.xmp").exists());
+        assert!(source.join("DCIM").join("photo.xmp").exists());
+        assert!(source.join("test.txt").exists());
+        assert!(!source.join("DCIM").join("photo.raw").exists());
+    }
+
+    #[test]
+    fn directory_data_transfer_service_transfer_keeps_destination_written_after_validation() {
+        // ===== Arrange =====
+        let (config, _temp_dir) = create_test_config_with_weekday("Mon");
+        // 2024年1月1日は月曜日
+        let now = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let service = DirectoryDataTransferService::new(config).with_custom_now(now);
+
+        // 検証の後に移動先ディレクトリへ別のファイルが書き込まれた状況を再現する
+        fs::create_dir_all(&*service.config.dest_directory_path).unwrap();
+        let dest_file = service
+            .config
+            .dest_directory_path
+            .join("different.txt")
+            .to_str()
+            .unwrap()
+            .replace("\\", "/");
+        let dest_file = Path::new(&dest_file);
+        println!("移動先ディレクトリ: {:?}", dest_file.to_str());
+        fs::write(dest_file, "different content").unwrap();
+
+        // ===== Act =====
+        let result = service.transfer();
+
+        // ===== Assert =====
+        assert!(matches!(result, Err(AppError::DestinationNotEmpty(_))));
+        assert!(dest_file.exists());
+        assert!(!service.config.source_directory_path.is_empty().unwrap());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn directory_data_transfer_service_transfer_rolls_back_partial_copy_on_io_error() {
+        // ===== Arrange =====
+        let (mut config, _temp_dir) = create_test_config_with_weekday("Mon");
+        // 除外パターンを指定してリネームではなくコピーで移動させる
+        config.exclude_patterns = GlobPatterns::new(vec!["*.never".to_string()]).unwrap();
+        let source = config.source_directory_path.to_path_buf();
+        fs::create_dir_all(source.join("subdir").join("nested")).unwrap();
+        fs::write(
+            source.join("subdir").join("nested").join("file.txt"),
+            "nested",
+        )
+        .unwrap();
+        // リンク先が存在しないシンボリックリンクはコピー時に必ず失敗する
+        std::os::unix::fs::symlink(source.join("missing"), source.join("subdir").join("broken"))
+            .unwrap();
+        let service = DirectoryDataTransferService::new(config);
+
+        // ===== Act =====
+        let result = service.transfer();
+
+        // ===== Assert =====
+        assert!(matches!(result, Err(AppError::Io(_))));
+        assert!(service.config.dest_directory_path.exists());
+        assert!(service.config.dest_directory_path.is_empty().unwrap());
+        assert!(source.join("test.txt").exists());
+        assert!(source
+            .join("subdir")
+            .join("nested")
+            .join("file.txt")
+            .exists());
     }
 }
@@ -0,0 +1,94 @@
+pub use adapter::copy_options::CaseSensitivity;
+use adapter::{
+    copy_options::{CopyOptions, DirectoryDifference},
+    directory_path::{readable_directory_path::ReadableDirectoryPath, CHECKSUM_FILE_NAME},
+};
+use shared::error::AppResult;
+use std::path::{Path, PathBuf};
+
+use crate::manifest::MANIFEST_FILE_NAME;
+
+pub struct DirectoryVerificationService {
+    source: ReadableDirectoryPath,
+    destination: ReadableDirectoryPath,
+    case_sensitivity: CaseSensitivity,
+}
+
+impl DirectoryVerificationService {
+    pub fn new(source: PathBuf, destination: PathBuf) -> AppResult<Self> {
+        Ok(Self {
+            source: ReadableDirectoryPath::new(source)?,
+            destination: ReadableDirectoryPath::new(destination)?,
+            case_sensitivity: CaseSensitivity::default(),
+        })
+    }
+
+    pub fn with_case_sensitivity(self, case_sensitivity: CaseSensitivity) -> Self {
+        Self {
+            case_sensitivity,
+            ..self
+        }
+    }
+
+    pub fn verify(&self) -> AppResult<Vec<DirectoryDifference>> {
+        let differences = self.destination.differences_from(
+            &self.source,
+            &CopyOptions {
+                case_sensitivity: self.case_sensitivity,
+                ..CopyOptions::default()
+            },
+        )?;
+        // 移動時に作成したマニフェストやチェックサムファイルは移動元に存在しないため、差分として扱わない
+        Ok(differences
+            .into_iter()
+            .filter(|difference| {
+                ![MANIFEST_FILE_NAME, CHECKSUM_FILE_NAME].iter().any(|name| {
+                    difference
+                        == &DirectoryDifference::MissingInSource(Path::new(name).to_path_buf())
+                })
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn directory_verification_service_returns_no_differences_for_identical_trees() {
+        // ===== Arrange =====
+        let source_dir = TempDir::new().unwrap();
+        let dest_dir = TempDir::new().unwrap();
+        for dir in [source_dir.path(), dest_dir.path()] {
+            fs::create_dir(dir.join("subdir")).unwrap();
+            fs::write(dir.join("subdir").join("file.txt"), "content").unwrap();
+        }
+        let service = DirectoryVerificationService::new(
+            source_dir.path().to_path_buf(),
+            dest_dir.path().to_path_buf(),
+        )
+        .unwrap();
+
+        // ===== Act =====
+        let result = service.verify();
+
+        // ===== Assert =====
+        assert!(result.unwrap().is_empty());
+    }
+
+    #[test]
+    fn directory_verification_service_fails_when_directory_does_not_exist() {
+        // ===== Arrange =====
+        let source_dir = TempDir::new().unwrap();
+        let missing = source_dir.path().join("missing");
+
+        // ===== Act =====
+        let result = DirectoryVerificationService::new(source_dir.path().to_path_buf(), missing);
+
+        // ===== Assert =====
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,89 @@
+use adapter::file_path::{output_file_path::OutputFilePath, readable_file_path::ReadableFilePath};
+use chrono::{DateTime, Local};
+use shared::error::{AppError, AppResult};
+use std::path::{Path, PathBuf};
+
+const STATE_FILE_SUFFIX: &str = ".last-run";
+
+/// 前回の実行日時を設定ファイルの隣の状態ファイルに記録し、それ以降に更新されたファイルのみを移動する
+pub struct IncrementalRun {
+    state_path: PathBuf,
+    started_at: DateTime<Local>,
+}
+
+impl IncrementalRun {
+    pub fn start(config_path: &Path) -> Self {
+        let mut state_path = config_path.as_os_str().to_os_string();
+        state_path.push(STATE_FILE_SUFFIX);
+        Self {
+            state_path: PathBuf::from(state_path),
+            started_at: Local::now(),
+        }
+    }
+
+    pub fn state_path(&self) -> &Path {
+        &self.state_path
+    }
+
+    /// 状態ファイルがない初回の実行では `None` を返し、すべてのファイルを移動する
+    pub fn last_run(&self) -> AppResult<Option<DateTime<Local>>> {
+        if !self.state_path.is_file() {
+            return Ok(None);
+        }
+        let content = ReadableFilePath::new(&self.state_path)?.read_content()?;
+        parse_timestamp(content.trim()).map(Some)
+    }
+
+    /// 実行中に更新されたファイルを次回の対象に含めるため、開始日時を記録する
+    pub fn finish(&self) -> AppResult<()> {
+        OutputFilePath::new(&self.state_path, true)?.write_content(&self.started_at.to_rfc3339())
+    }
+}
+
+/// RFC 3339 形式（`2024-03-14T07:00:00+09:00` など）の日時を読み込む
+pub fn parse_timestamp(value: &str) -> AppResult<DateTime<Local>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|date| date.with_timezone(&Local))
+        .map_err(|_| {
+            AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("無効な日時が指定されています（RFC 3339 形式）: {}", value),
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn incremental_run_records_start_time_for_next_run() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+        let first_run = IncrementalRun::start(&config_path);
+
+        // ===== Act =====
+        let before = first_run.last_run().unwrap();
+        first_run.finish().unwrap();
+        let after = IncrementalRun::start(&config_path).last_run().unwrap();
+
+        // ===== Assert =====
+        assert_eq!(before, None);
+        assert_eq!(after.unwrap().timestamp(), first_run.started_at.timestamp());
+        assert_eq!(
+            first_run.state_path(),
+            temp_dir.path().join("config.json.last-run")
+        );
+    }
+
+    #[test]
+    fn parse_timestamp_rejects_invalid_value() {
+        // ===== Act =====
+        let result = parse_timestamp("2024-03-14");
+
+        // ===== Assert =====
+        assert!(result.is_err());
+    }
+}
@@ -1,3 +1,9 @@
-mod config;
+pub mod clock;
+pub mod config;
 pub mod config_builder;
 pub mod directory_data_transfer_service;
+pub mod directory_verification_service;
+pub mod incremental_run;
+pub mod manifest;
+pub mod source_removal_prompt;
+pub mod transfer_builder;
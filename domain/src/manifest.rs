@@ -0,0 +1,194 @@
+use adapter::{
+    copy_options::{CopyOptions, FileDigest, HashAlgorithm},
+    directory_path::readable_directory_path::ReadableDirectoryPath,
+    file_path::{output_file_path::OutputFilePath, readable_file_path::ReadableFilePath},
+};
+use serde::{Deserialize, Serialize};
+use shared::error::{AppError, AppResult};
+use std::{
+    collections::BTreeMap,
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use crate::config::path_expansion::path_to_string;
+
+pub const MANIFEST_FILE_NAME: &str = "srow-manifest.json";
+
+/// 移動したファイルの一覧。監査のため移動先に保存し、後から内容の変化を確認できるようにする
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    pub hash_algorithm: String,
+    pub files: Vec<ManifestEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    /// OS に依存しないよう `/` 区切りで記録する
+    pub path: String,
+    pub size: u64,
+    pub hash: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ManifestDrift {
+    Missing(PathBuf),
+    Modified(PathBuf),
+    Unexpected(PathBuf),
+}
+
+impl fmt::Display for ManifestDrift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing(path) => write!(
+                f,
+                "マニフェストのファイルが存在しません: {}",
+                path.display()
+            ),
+            Self::Modified(path) => write!(f, "内容が変更されています: {}", path.display()),
+            Self::Unexpected(path) => {
+                write!(f, "マニフェストにないファイルです: {}", path.display())
+            }
+        }
+    }
+}
+
+impl Manifest {
+    pub fn new(hash_algorithm: HashAlgorithm, digests: Vec<FileDigest>) -> AppResult<Self> {
+        let files = digests
+            .into_iter()
+            .filter(|digest| digest.path != Path::new(MANIFEST_FILE_NAME))
+            .map(|digest| {
+                Ok(ManifestEntry {
+                    path: to_manifest_path(&digest.path)?,
+                    size: digest.size,
+                    hash: digest.hash,
+                })
+            })
+            .collect::<AppResult<Vec<_>>>()?;
+        Ok(Self {
+            hash_algorithm: hash_algorithm.as_str().to_string(),
+            files,
+        })
+    }
+
+    pub fn write_to(&self, dir: &Path) -> AppResult<()> {
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| AppError::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, e)))?;
+        OutputFilePath::new(dir.join(MANIFEST_FILE_NAME), true)?.write_content(&content)
+    }
+
+    pub fn read_from(dir: &Path) -> AppResult<Self> {
+        let content = ReadableFilePath::new(dir.join(MANIFEST_FILE_NAME))?.read_content()?;
+        serde_json::from_str(&content).map_err(|e| {
+            AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("マニフェストの形式が正しくありません: {}", e),
+            ))
+        })
+    }
+}
+
+/// 移動先のマニフェストを読み込み、現在のファイルのハッシュ値を再計算して差分を返す
+pub fn verify_manifest(dir: &Path) -> AppResult<Vec<ManifestDrift>> {
+    let manifest = Manifest::read_from(dir)?;
+    let options = CopyOptions {
+        hash_algorithm: HashAlgorithm::try_from(manifest.hash_algorithm.clone())?,
+        ..CopyOptions::default()
+    };
+    let current = Manifest::new(
+        options.hash_algorithm,
+        ReadableDirectoryPath::new(dir)?.digest_files(&options)?,
+    )?;
+
+    let mut expected: BTreeMap<String, ManifestEntry> = manifest
+        .files
+        .into_iter()
+        .map(|entry| (entry.path.clone(), entry))
+        .collect();
+    let mut drifts = Vec::new();
+    for entry in current.files {
+        match expected.remove(&entry.path) {
+            Some(recorded) if recorded == entry => {}
+            Some(_) => drifts.push(ManifestDrift::Modified(PathBuf::from(entry.path))),
+            None => drifts.push(ManifestDrift::Unexpected(PathBuf::from(entry.path))),
+        }
+    }
+    drifts.extend(
+        expected
+            .into_keys()
+            .map(|path| ManifestDrift::Missing(PathBuf::from(path))),
+    );
+    Ok(drifts)
+}
+
+fn to_manifest_path(path: &Path) -> AppResult<String> {
+    let components = path
+        .components()
+        .map(|component| path_to_string(Path::new(component.as_os_str())))
+        .collect::<AppResult<Vec<_>>>()?;
+    Ok(components.join("/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn write_manifest_for(dir: &Path) {
+        let options = CopyOptions::default();
+        let digests = ReadableDirectoryPath::new(dir)
+            .unwrap()
+            .digest_files(&options)
+            .unwrap();
+        Manifest::new(options.hash_algorithm, digests)
+            .unwrap()
+            .write_to(dir)
+            .unwrap();
+    }
+
+    #[test]
+    fn verify_manifest_returns_no_drift_for_unchanged_directory() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir(temp_dir.path().join("sub")).unwrap();
+        fs::write(temp_dir.path().join("root.txt"), "root").unwrap();
+        fs::write(temp_dir.path().join("sub").join("nested.txt"), "nested").unwrap();
+        write_manifest_for(temp_dir.path());
+
+        // ===== Act =====
+        let result = verify_manifest(temp_dir.path());
+
+        // ===== Assert =====
+        assert!(result.unwrap().is_empty());
+        let manifest = Manifest::read_from(temp_dir.path()).unwrap();
+        let paths: Vec<&str> = manifest.files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, vec!["root.txt", "sub/nested.txt"]);
+    }
+
+    #[test]
+    fn verify_manifest_reports_tampered_missing_and_unexpected_files() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("tampered.txt"), "original").unwrap();
+        fs::write(temp_dir.path().join("removed.txt"), "removed").unwrap();
+        write_manifest_for(temp_dir.path());
+        fs::write(temp_dir.path().join("tampered.txt"), "modified").unwrap();
+        fs::remove_file(temp_dir.path().join("removed.txt")).unwrap();
+        fs::write(temp_dir.path().join("added.txt"), "added").unwrap();
+
+        // ===== Act =====
+        let result = verify_manifest(temp_dir.path());
+
+        // ===== Assert =====
+        assert_eq!(
+            result.unwrap(),
+            vec![
+                ManifestDrift::Unexpected(PathBuf::from("added.txt")),
+                ManifestDrift::Modified(PathBuf::from("tampered.txt")),
+                ManifestDrift::Missing(PathBuf::from("removed.txt")),
+            ]
+        );
+    }
+}
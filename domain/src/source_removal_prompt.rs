@@ -0,0 +1,79 @@
+use std::{
+    io::{BufRead, Write},
+    path::{Path, PathBuf},
+};
+
+use shared::{
+    error::AppResult,
+    message::{Lang, Message},
+};
+
+/// 削除する移動元のエントリを表示し、`y` または `yes` と入力された場合に限り `true` を返す
+///
+/// 入力が終端に達した場合（パイプが閉じられた場合など）は削除しない。
+pub fn confirm_source_removal(
+    reader: &mut impl BufRead,
+    writer: &mut impl Write,
+    source: &Path,
+    entries: &[PathBuf],
+    lang: Lang,
+) -> AppResult<bool> {
+    for entry in entries {
+        writeln!(writer, "  {}", entry.display())?;
+    }
+    let message = Message::ConfirmSourceRemoval {
+        source,
+        count: entries.len(),
+    };
+    write!(writer, "{}", message.render(lang))?;
+    writer.flush()?;
+
+    let mut answer = String::new();
+    reader.read_line(&mut answer)?;
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn prompt(input: &str) -> (bool, String) {
+        let mut reader = Cursor::new(input.as_bytes());
+        let mut writer = Vec::new();
+        let confirmed = confirm_source_removal(
+            &mut reader,
+            &mut writer,
+            Path::new("/src"),
+            &[PathBuf::from("a.txt"), PathBuf::from("dir")],
+            Lang::En,
+        )
+        .unwrap();
+        (confirmed, String::from_utf8(writer).unwrap())
+    }
+
+    #[test]
+    fn confirm_source_removal_accepts_yes() {
+        // ===== Act =====
+        let (confirmed, output) = prompt("y\n");
+
+        // ===== Assert =====
+        assert!(confirmed);
+        assert!(output.contains("  a.txt\n  dir\n"));
+        assert!(output.contains("[y/N]"));
+    }
+
+    #[test]
+    fn confirm_source_removal_declines_by_default() {
+        // ===== Arrange =====
+        let answers = ["n\n", "\n", "", "maybe\n"];
+
+        for answer in answers {
+            // ===== Act =====
+            let (confirmed, _) = prompt(answer);
+
+            // ===== Assert =====
+            assert!(!confirmed, "{:?}", answer);
+        }
+    }
+}
@@ -0,0 +1,472 @@
+use adapter::copy_options::{
+    BandwidthLimit, BufferSize, CaseSensitivity, GlobPatterns, HashAlgorithm, RetryPolicy,
+    SymlinkPolicy, VerificationMode,
+};
+use chrono::{DateTime, Local};
+use shared::{
+    error::{AppError, AppResult},
+    message::Lang,
+};
+use std::{path::PathBuf, time::Duration};
+
+use crate::{
+    config::{
+        destination_directory_path::DestinationDirectoryPath, directory_mode::DirectoryMode,
+        empty_source_policy::EmptySourcePolicy, path_expansion::path_to_string,
+        source_directory_path::SourceDirectoryPath, timezone::ConfigTimeZone, weekday::WeekDay,
+        weekday_set::WeekDaySet, Config,
+    },
+    directory_data_transfer_service::DirectoryDataTransferService,
+};
+
+/// CLI を介さずに移動処理を組み立てるためのビルダー
+///
+/// ```no_run
+/// use domain::transfer_builder::TransferBuilder;
+///
+/// let summary = TransferBuilder::new()
+///     .source("/home/user/Desktop")
+///     .destination("/home/user/Files/{yyyy}/{mm}/{dd}")
+///     .weekday("Thu")
+///     .build()?
+///     .validate()?
+///     .transfer()?;
+/// # Ok::<(), shared::error::AppError>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TransferBuilder {
+    source: Option<PathBuf>,
+    destination: Option<PathBuf>,
+    weekdays: Vec<String>,
+    typed_weekdays: Vec<WeekDay>,
+    timezone: Option<String>,
+    hash_algorithm: Option<String>,
+    exclude_patterns: Vec<String>,
+    include_patterns: Vec<String>,
+    symlink_policy: Option<String>,
+    retry_max_attempts: Option<u32>,
+    retry_base_delay_ms: u64,
+    directory_mode: Option<String>,
+    verification_mode: Option<String>,
+    buffer_size: Option<usize>,
+    bandwidth_limit: Option<u64>,
+    case_sensitivity: Option<String>,
+    keep_source: bool,
+    empty_source_policy: Option<String>,
+    write_checksums: bool,
+    max_depth: Option<usize>,
+    skip_existing_matching: bool,
+    require_date_placeholder: bool,
+    preserve_xattrs: bool,
+    parallel: bool,
+    date_offset_days: i64,
+    date_offset_applies_to_weekday: bool,
+    snap_date_to_weekday: bool,
+    force: bool,
+    ignore_weekday: bool,
+    lang: Lang,
+    modified_since: Option<DateTime<Local>>,
+    timeout: Option<Duration>,
+    tree_hash: bool,
+    staging: bool,
+}
+
+impl TransferBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn source(self, source: impl Into<PathBuf>) -> Self {
+        Self {
+            source: Some(source.into()),
+            ..self
+        }
+    }
+
+    pub fn destination(self, destination: impl Into<PathBuf>) -> Self {
+        Self {
+            destination: Some(destination.into()),
+            ..self
+        }
+    }
+
+    /// 実行する曜日を追加する（複数回呼び出すと、いずれかの曜日に実行する）
+    pub fn weekday(mut self, weekday: impl Into<String>) -> Self {
+        self.weekdays.push(weekday.into());
+        self
+    }
+
+    /// 文字列を介さずに曜日を追加する。`chrono::Weekday` もそのまま渡せる
+    pub fn typed_weekday(mut self, weekday: impl Into<WeekDay>) -> Self {
+        self.typed_weekdays.push(weekday.into());
+        self
+    }
+
+    pub fn timezone(self, timezone: impl Into<String>) -> Self {
+        Self {
+            timezone: Some(timezone.into()),
+            ..self
+        }
+    }
+
+    pub fn hash_algorithm(self, hash_algorithm: impl Into<String>) -> Self {
+        Self {
+            hash_algorithm: Some(hash_algorithm.into()),
+            ..self
+        }
+    }
+
+    pub fn exclude(mut self, pattern: impl Into<String>) -> Self {
+        self.exclude_patterns.push(pattern.into());
+        self
+    }
+
+    pub fn include(mut self, pattern: impl Into<String>) -> Self {
+        self.include_patterns.push(pattern.into());
+        self
+    }
+
+    pub fn symlink_policy(self, symlink_policy: impl Into<String>) -> Self {
+        Self {
+            symlink_policy: Some(symlink_policy.into()),
+            ..self
+        }
+    }
+
+    pub fn retry(self, max_attempts: u32, base_delay_ms: u64) -> Self {
+        Self {
+            retry_max_attempts: Some(max_attempts),
+            retry_base_delay_ms: base_delay_ms,
+            ..self
+        }
+    }
+
+    pub fn directory_mode(self, directory_mode: impl Into<String>) -> Self {
+        Self {
+            directory_mode: Some(directory_mode.into()),
+            ..self
+        }
+    }
+
+    pub fn verification_mode(self, verification_mode: impl Into<String>) -> Self {
+        Self {
+            verification_mode: Some(verification_mode.into()),
+            ..self
+        }
+    }
+
+    pub fn buffer_size(self, buffer_size: usize) -> Self {
+        Self {
+            buffer_size: Some(buffer_size),
+            ..self
+        }
+    }
+
+    /// 移動先への書き込みの上限を 1 秒あたりのバイト数で指定する
+    pub fn bandwidth_limit(self, bytes_per_second: u64) -> Self {
+        Self {
+            bandwidth_limit: Some(bytes_per_second),
+            ..self
+        }
+    }
+
+    pub fn case_sensitivity(self, case_sensitivity: impl Into<String>) -> Self {
+        Self {
+            case_sensitivity: Some(case_sensitivity.into()),
+            ..self
+        }
+    }
+
+    /// `false` を指定すると、移動元を削除せずに検証済みのコピーのみを行う
+    pub fn delete_source(self, delete_source: bool) -> Self {
+        Self {
+            keep_source: !delete_source,
+            ..self
+        }
+    }
+
+    pub fn empty_source_policy(self, empty_source_policy: impl Into<String>) -> Self {
+        Self {
+            empty_source_policy: Some(empty_source_policy.into()),
+            ..self
+        }
+    }
+
+    /// `true` を指定すると、移動先に `SHA256SUMS` を書き出す
+    pub fn write_checksums(self, write_checksums: bool) -> Self {
+        Self {
+            write_checksums,
+            ..self
+        }
+    }
+
+    /// 移動元直下を深さ 0 として、指定の深さより深いエントリを移動元に残す
+    pub fn max_depth(self, max_depth: usize) -> Self {
+        Self {
+            max_depth: Some(max_depth),
+            ..self
+        }
+    }
+
+    /// `true` を指定すると、移動先に同じ内容のファイルが既にあればコピーせずに残す
+    pub fn skip_existing_matching(self, skip_existing_matching: bool) -> Self {
+        Self {
+            skip_existing_matching,
+            ..self
+        }
+    }
+
+    /// `true` を指定すると、日付のプレースホルダーを含まない移動先を設定の構築時に拒否する
+    pub fn require_date_placeholder(self, require_date_placeholder: bool) -> Self {
+        Self {
+            require_date_placeholder,
+            ..self
+        }
+    }
+
+    /// `true` を指定すると、Unix でファイルの拡張属性も移動先に複製する
+    pub fn preserve_xattrs(self, preserve_xattrs: bool) -> Self {
+        Self {
+            preserve_xattrs,
+            ..self
+        }
+    }
+
+    /// `true` を指定すると、ファイルを複数のスレッドで並列にコピーする
+    pub fn parallel(self, parallel: bool) -> Self {
+        Self { parallel, ..self }
+    }
+
+    pub fn date_offset_days(self, date_offset_days: i64) -> Self {
+        Self {
+            date_offset_days,
+            ..self
+        }
+    }
+
+    pub fn date_offset_applies_to_weekday(self, date_offset_applies_to_weekday: bool) -> Self {
+        Self {
+            date_offset_applies_to_weekday,
+            ..self
+        }
+    }
+
+    /// `true` を指定すると、移動先の日付を指定された曜日のうち直近の日に戻してから展開する
+    pub fn snap_date_to_weekday(self, snap_date_to_weekday: bool) -> Self {
+        Self {
+            snap_date_to_weekday,
+            ..self
+        }
+    }
+
+    pub fn force(self, force: bool) -> Self {
+        Self { force, ..self }
+    }
+
+    pub fn ignore_weekday(self, ignore_weekday: bool) -> Self {
+        Self {
+            ignore_weekday,
+            ..self
+        }
+    }
+
+    pub fn lang(self, lang: Lang) -> Self {
+        Self { lang, ..self }
+    }
+
+    pub fn modified_since(self, modified_since: DateTime<Local>) -> Self {
+        Self {
+            modified_since: Some(modified_since),
+            ..self
+        }
+    }
+
+    pub fn timeout(self, timeout: Duration) -> Self {
+        Self {
+            timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    pub fn tree_hash(self, tree_hash: bool) -> Self {
+        Self { tree_hash, ..self }
+    }
+
+    /// `true` を指定すると、一時ディレクトリで検証を済ませてから移動先にリネームする
+    pub fn staging(self, staging: bool) -> Self {
+        Self { staging, ..self }
+    }
+
+    pub fn build(&self) -> AppResult<DirectoryDataTransferService> {
+        Ok(DirectoryDataTransferService::new(self.build_config()?)
+            .with_force(self.force)
+            .with_ignore_weekday(self.ignore_weekday)
+            .with_lang(self.lang)
+            .with_modified_since(self.modified_since)
+            .with_timeout(self.timeout)
+            .with_tree_hash(self.tree_hash)
+            .with_staging(self.staging))
+    }
+
+    pub(crate) fn build_config(&self) -> AppResult<Config> {
+        // 曜日の指定ミスで日付入りの移動先ディレクトリが作成されないよう、パスより先に検証する
+        let mut weekdays = self.typed_weekdays.clone();
+        if !self.weekdays.is_empty() {
+            weekdays.extend_from_slice(&WeekDaySet::try_from(self.weekdays.clone())?);
+        }
+        let weekday = WeekDaySet::new(weekdays)?;
+        let source = self
+            .source
+            .as_deref()
+            .ok_or_else(|| Self::missing_value_error("移動元ディレクトリ"))?;
+        let destination = self
+            .destination
+            .as_deref()
+            .ok_or_else(|| Self::missing_value_error("移動先ディレクトリ"))?;
+        let timezone = ConfigTimeZone::try_from(self.timezone.clone())?;
+        let destination = path_to_string(destination)?;
+        if self.require_date_placeholder {
+            DestinationDirectoryPath::ensure_templated(&destination)?;
+        }
+        Ok(Config {
+            source_directory_path: SourceDirectoryPath::new(path_to_string(source)?)?,
+            dest_directory_path: DestinationDirectoryPath::new(
+                destination,
+                &timezone,
+                self.directory_mode
+                    .clone()
+                    .map(DirectoryMode::try_from)
+                    .transpose()?,
+                self.date_offset_days,
+                self.snap_date_to_weekday.then_some(&weekday),
+            )?,
+            weekday,
+            timezone,
+            hash_algorithm: self
+                .hash_algorithm
+                .clone()
+                .map_or(Ok(HashAlgorithm::default()), HashAlgorithm::try_from)?,
+            exclude_patterns: GlobPatterns::new(self.exclude_patterns.clone())?,
+            include_patterns: GlobPatterns::new(self.include_patterns.clone())?,
+            symlink_policy: self
+                .symlink_policy
+                .clone()
+                .map_or(Ok(SymlinkPolicy::default()), SymlinkPolicy::try_from)?,
+            retry_policy: self
+                .retry_max_attempts
+                .map_or(Ok(RetryPolicy::default()), |max| {
+                    RetryPolicy::new(max, Duration::from_millis(self.retry_base_delay_ms))
+                })?,
+            verification_mode: self
+                .verification_mode
+                .clone()
+                .map_or(Ok(VerificationMode::default()), VerificationMode::try_from)?,
+            buffer_size: self
+                .buffer_size
+                .map_or(Ok(BufferSize::default()), BufferSize::new)?,
+            bandwidth_limit: self.bandwidth_limit.map(BandwidthLimit::new).transpose()?,
+            case_sensitivity: self
+                .case_sensitivity
+                .clone()
+                .map_or(Ok(CaseSensitivity::default()), CaseSensitivity::try_from)?,
+            delete_source: !self.keep_source,
+            empty_source_policy: self.empty_source_policy.clone().map_or(
+                Ok(EmptySourcePolicy::default()),
+                EmptySourcePolicy::try_from,
+            )?,
+            write_checksums: self.write_checksums,
+            max_depth: self.max_depth,
+            skip_existing_matching: self.skip_existing_matching,
+            preserve_xattrs: self.preserve_xattrs,
+            parallel: self.parallel,
+            date_offset_days: self.date_offset_days,
+            date_offset_applies_to_weekday: self.date_offset_applies_to_weekday,
+            snap_date_to_weekday: self.snap_date_to_weekday,
+        })
+    }
+
+    fn missing_value_error(name: &str) -> AppError {
+        AppError::Io(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("{}が指定されていません", name),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn transfer_builder_builds_working_service() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source = temp_dir.path().join("source");
+        let destination = temp_dir.path().join("dest");
+        fs::create_dir(&source).unwrap();
+        fs::write(source.join("photo.jpg"), "jpg").unwrap();
+        fs::write(source.join("notes.txt"), "txt").unwrap();
+        // 2024年1月1日は月曜日
+        let now = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+        // ===== Act =====
+        let service = TransferBuilder::new()
+            .source(&source)
+            .destination(&destination)
+            .weekday("Mon")
+            .include("*.jpg")
+            .build()
+            .unwrap()
+            .with_custom_now(now);
+        let result = service.validate().and_then(|service| service.transfer());
+
+        // ===== Assert =====
+        assert_eq!(result.unwrap().files_copied, 1);
+        assert!(destination.join("photo.jpg").exists());
+        assert!(!source.join("photo.jpg").exists());
+        assert!(source.join("notes.txt").exists());
+    }
+
+    #[test]
+    fn transfer_builder_fails_without_source() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let builder = TransferBuilder::new()
+            .destination(temp_dir.path().join("dest"))
+            .weekday("Mon");
+
+        // ===== Act =====
+        let result = builder.build();
+
+        // ===== Assert =====
+        assert!(matches!(result, Err(AppError::Io(_))));
+    }
+
+    #[test]
+    fn transfer_builder_rejects_static_destination_when_date_placeholder_is_required() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source = temp_dir.path().join("source");
+        fs::create_dir(&source).unwrap();
+        let builder = TransferBuilder::new()
+            .source(&source)
+            .weekday("Mon")
+            .require_date_placeholder(true);
+
+        // ===== Act =====
+        let static_result = builder
+            .clone()
+            .destination(temp_dir.path().join("dest"))
+            .build_config();
+        let templated_result = builder
+            .destination(temp_dir.path().join("dest-{yyyy}{mm}{dd}"))
+            .build_config();
+
+        // ===== Assert =====
+        assert!(matches!(static_result, Err(AppError::Io(_))));
+        assert!(templated_result.is_ok());
+    }
+}
@@ -0,0 +1,115 @@
+use std::{
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use shared::error::{AppError, AppResult};
+
+/// 移動先への書き込みで許容する 1 秒あたりの平均バイト数
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BandwidthLimit(u64);
+
+impl BandwidthLimit {
+    pub fn new(bytes_per_second: u64) -> AppResult<Self> {
+        if bytes_per_second == 0 {
+            return Err(AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "帯域の上限には1以上を指定してください",
+            )));
+        }
+        Ok(Self(bytes_per_second))
+    }
+
+    pub fn bytes_per_second(&self) -> u64 {
+        self.0
+    }
+}
+
+/// トークンバケットで書き込みの速度を抑える。並列コピーのスレッド間でも 1 つのバケットを共有する
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    bucket: Arc<Mutex<TokenBucket>>,
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    limit: BandwidthLimit,
+    // 負の値は先に書き込んだ分の借りを表す
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl TokenBucket {
+    fn refill(&mut self, now: Instant) {
+        let rate = self.limit.bytes_per_second() as f64;
+        let elapsed = now.duration_since(self.refilled_at).as_secs_f64();
+        // 一度に溜められるのは 1 秒分までとし、長い待ちの後でも上限を大きく超えないようにする
+        self.tokens = (self.tokens + elapsed * rate).min(rate);
+        self.refilled_at = now;
+    }
+}
+
+impl RateLimiter {
+    pub fn new(limit: BandwidthLimit) -> Self {
+        Self {
+            bucket: Arc::new(Mutex::new(TokenBucket {
+                limit,
+                tokens: limit.bytes_per_second() as f64,
+                refilled_at: Instant::now(),
+            })),
+        }
+    }
+
+    /// `bytes` の書き込みを予約し、平均の速度が上限に収まるまで待つ
+    pub fn acquire(&self, bytes: u64) {
+        let wait = {
+            let mut bucket = self
+                .bucket
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            bucket.refill(Instant::now());
+            bucket.tokens -= bytes as f64;
+            match bucket.tokens < 0.0 {
+                true => {
+                    Duration::from_secs_f64(-bucket.tokens / bucket.limit.bytes_per_second() as f64)
+                }
+                false => Duration::ZERO,
+            }
+        };
+        if !wait.is_zero() {
+            thread::sleep(wait);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bandwidth_limit_creation_fails_with_zero() {
+        // ===== Act =====
+        let result = BandwidthLimit::new(0);
+
+        // ===== Assert =====
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn rate_limiter_waits_for_bytes_beyond_burst() {
+        // ===== Arrange =====
+        let limiter = RateLimiter::new(BandwidthLimit::new(1000).unwrap());
+
+        // ===== Act =====
+        let started = Instant::now();
+        limiter.acquire(1000);
+        let after_burst = started.elapsed();
+        limiter.acquire(500);
+        let elapsed = started.elapsed();
+
+        // ===== Assert =====
+        assert!(after_burst < Duration::from_millis(100));
+        assert!(elapsed >= Duration::from_millis(450), "{:?}", elapsed);
+    }
+}
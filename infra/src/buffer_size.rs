@@ -0,0 +1,56 @@
+use shared::error::{AppError, AppResult};
+
+/// コピーとハッシュ計算で一度に読み込むバイト数
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferSize(usize);
+
+impl BufferSize {
+    pub const DEFAULT: usize = 8192;
+
+    pub fn new(bytes: usize) -> AppResult<Self> {
+        if bytes == 0 {
+            return Err(AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "バッファサイズには1以上を指定してください",
+            )));
+        }
+        Ok(Self(bytes))
+    }
+
+    pub fn bytes(&self) -> usize {
+        self.0
+    }
+
+    pub(crate) fn allocate(&self) -> Vec<u8> {
+        vec![0u8; self.0]
+    }
+}
+
+impl Default for BufferSize {
+    fn default() -> Self {
+        Self(Self::DEFAULT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buffer_size_defaults_to_8192_bytes() {
+        // ===== Act =====
+        let result = BufferSize::default();
+
+        // ===== Assert =====
+        assert_eq!(result.bytes(), 8192);
+    }
+
+    #[test]
+    fn buffer_size_creation_fails_with_zero() {
+        // ===== Act =====
+        let result = BufferSize::new(0);
+
+        // ===== Assert =====
+        assert!(result.is_err());
+    }
+}
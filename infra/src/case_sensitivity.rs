@@ -0,0 +1,68 @@
+use std::path::{Path, PathBuf};
+
+use shared::error::{AppError, AppResult};
+
+/// 移動元と移動先の名前を比較するときに、大文字と小文字を区別するかどうか
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CaseSensitivity {
+    #[default]
+    Sensitive,
+    /// macOS や Windows の既定のファイルシステムのように、大文字と小文字を区別しない
+    Insensitive,
+}
+
+impl CaseSensitivity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CaseSensitivity::Sensitive => "sensitive",
+            CaseSensitivity::Insensitive => "insensitive",
+        }
+    }
+
+    /// 比較に使うキー。区別しない場合は小文字にそろえる
+    pub fn key(&self, path: &Path) -> PathBuf {
+        match self {
+            CaseSensitivity::Sensitive => path.to_path_buf(),
+            CaseSensitivity::Insensitive => PathBuf::from(path.to_string_lossy().to_lowercase()),
+        }
+    }
+}
+
+impl TryFrom<String> for CaseSensitivity {
+    type Error = AppError;
+
+    fn try_from(value: String) -> AppResult<Self> {
+        match value.to_lowercase().as_str() {
+            "sensitive" => Ok(CaseSensitivity::Sensitive),
+            "insensitive" => Ok(CaseSensitivity::Insensitive),
+            _ => Err(AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("無効な大文字・小文字の区別が指定されています: {}", value),
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn case_sensitivity_creation_from_string() {
+        // ===== Arrange =====
+        let values = ["sensitive", "INSENSITIVE"];
+
+        // ===== Act =====
+        let results: Vec<CaseSensitivity> = values
+            .iter()
+            .map(|value| CaseSensitivity::try_from(value.to_string()).unwrap())
+            .collect();
+
+        // ===== Assert =====
+        assert_eq!(
+            results,
+            vec![CaseSensitivity::Sensitive, CaseSensitivity::Insensitive]
+        );
+        assert!(CaseSensitivity::try_from("invalid".to_string()).is_err());
+    }
+}
@@ -0,0 +1,75 @@
+use std::{
+    fs::{self, FileType},
+    path::Path,
+    time::SystemTime,
+};
+
+use shared::{error::AppResult, message::Lang};
+
+use crate::{
+    bandwidth_limit::RateLimiter, buffer_size::BufferSize, case_sensitivity::CaseSensitivity,
+    deadline::Deadline, glob_patterns::GlobPatterns, hash_algorithm::HashAlgorithm,
+    retry_policy::RetryPolicy, storage_backend::SharedStorageBackend,
+    symlink_policy::SymlinkPolicy, verification_mode::VerificationMode,
+};
+
+#[derive(Debug, Clone, Default)]
+pub struct CopyOptions {
+    pub hash_algorithm: HashAlgorithm,
+    pub exclude_patterns: GlobPatterns,
+    pub include_patterns: GlobPatterns,
+    pub symlink_policy: SymlinkPolicy,
+    pub retry_policy: RetryPolicy,
+    pub verification_mode: VerificationMode,
+    pub buffer_size: BufferSize,
+    pub modified_since: Option<SystemTime>,
+    // 指定された場合は、移動元直下を深さ 0 として指定の深さまでのエントリのみを移動する
+    pub max_depth: Option<usize>,
+    // 指定された場合は、期限を過ぎた時点で次のファイルのコピーを始めずに中断する
+    pub deadline: Option<Deadline>,
+    pub case_sensitivity: CaseSensitivity,
+    // true の場合は、移動先に同じハッシュ値のファイルが既にあればコピーを省略する
+    pub skip_existing_matching: bool,
+    // true の場合は、Unix でファイルの拡張属性（xattr）も移動先に複製する
+    pub preserve_xattrs: bool,
+    // true の場合は、ファイルを複数のスレッドで並列にコピーする
+    pub parallel: bool,
+    // 指定された場合は、移動先への書き込みの平均速度を上限以下に抑える
+    pub rate_limiter: Option<RateLimiter>,
+    // 移動先への書き込みに使うストレージ
+    pub storage_backend: SharedStorageBackend,
+    // ログに出力するメッセージの言語
+    pub lang: Lang,
+}
+
+impl CopyOptions {
+    pub fn is_excluded(&self, rel_path: &Path) -> bool {
+        self.exclude_patterns.matches(rel_path)
+    }
+
+    /// 含めるパターンが指定されていない場合は、すべてのファイルを対象とする
+    pub fn is_included_file(&self, rel_path: &Path) -> bool {
+        self.include_patterns.is_empty() || self.include_patterns.matches(rel_path)
+    }
+
+    /// 含めるパターンに一致し、指定日時以降に更新されたファイルのみを移動の対象とする
+    pub fn is_target_file(&self, rel_path: &Path, path: &Path) -> AppResult<bool> {
+        if !self.is_included_file(rel_path) {
+            return Ok(false);
+        }
+        match self.modified_since {
+            Some(since) => Ok(fs::metadata(path)?.modified()? >= since),
+            None => Ok(true),
+        }
+    }
+
+    /// 上限より深いエントリはコピーも削除もせず、移動元に残す
+    pub fn is_beyond_max_depth(&self, rel_path: &Path) -> bool {
+        self.max_depth
+            .is_some_and(|max_depth| rel_path.components().count() > max_depth + 1)
+    }
+
+    pub fn is_skipped_symlink(&self, file_type: &FileType) -> bool {
+        self.symlink_policy == SymlinkPolicy::Skip && file_type.is_symlink()
+    }
+}
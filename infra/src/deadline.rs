@@ -0,0 +1,42 @@
+use std::time::{Duration, Instant};
+
+use shared::error::{AppError, AppResult};
+
+/// 転送全体の制限時間。コピー中のファイルを途中で打ち切らず、ファイルの区切りで確認する
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Deadline {
+    at: Instant,
+    timeout: Duration,
+}
+
+impl Deadline {
+    pub fn start(timeout: Duration) -> Self {
+        Self {
+            at: Instant::now() + timeout,
+            timeout,
+        }
+    }
+
+    pub fn check(&self) -> AppResult<()> {
+        match Instant::now() >= self.at {
+            true => Err(AppError::Timeout(self.timeout)),
+            false => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deadline_check_fails_after_timeout() {
+        // ===== Arrange =====
+        let expired = Deadline::start(Duration::ZERO);
+        let running = Deadline::start(Duration::from_secs(60));
+
+        // ===== Act & Assert =====
+        assert!(matches!(expired.check(), Err(AppError::Timeout(_))));
+        assert!(running.check().is_ok());
+    }
+}
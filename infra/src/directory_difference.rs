@@ -0,0 +1,20 @@
+use std::{fmt, path::PathBuf};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DirectoryDifference {
+    MissingInDestination(PathBuf),
+    MissingInSource(PathBuf),
+    ContentMismatch(PathBuf),
+}
+
+impl fmt::Display for DirectoryDifference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingInDestination(path) => {
+                write!(f, "移動先に存在しません: {}", path.display())
+            }
+            Self::MissingInSource(path) => write!(f, "移動元に存在しません: {}", path.display()),
+            Self::ContentMismatch(path) => write!(f, "内容が一致しません: {}", path.display()),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use std::path::Path;
+
+use shared::error::AppResult;
+
+/// 移動元ファイルの拡張属性（xattr）をすべて移動先ファイルに設定する
+///
+/// POSIX ACL も `system.posix_acl_access` などの拡張属性として複製される。
+/// シンボリックリンクはリンク先の属性を読み込む。Unix 以外では何もしない。
+#[cfg(unix)]
+pub fn copy_extended_attributes(from: &Path, to: &Path) -> AppResult<()> {
+    // 拡張属性に対応していないファイルシステムでは、属性がないものとして扱う
+    let names = match xattr::list_deref(from) {
+        Ok(names) => names,
+        Err(e) if e.kind() == std::io::ErrorKind::Unsupported => return Ok(()),
+        Err(e) => return Err(e.into()),
+    };
+    for name in names {
+        // 一覧の取得後に削除された属性は複製しない
+        if let Some(value) = xattr::get_deref(from, &name)? {
+            xattr::set_deref(to, &name, &value)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+pub fn copy_extended_attributes(_from: &Path, _to: &Path) -> AppResult<()> {
+    Ok(())
+}
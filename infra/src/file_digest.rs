@@ -0,0 +1,9 @@
+use std::path::PathBuf;
+
+/// ディレクトリからの相対パスと、ファイルのサイズ・ハッシュ値の組
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileDigest {
+    pub path: PathBuf,
+    pub size: u64,
+    pub hash: String,
+}
@@ -0,0 +1,55 @@
+//! 移動処理とは独立して利用できる、安定したファイル操作の API
+//!
+//! [`FileSystem`] の関数はレイヤー間の内部実装であり、予告なく変更されることがある。
+//! 他のクレートから単体で使う場合は、このモジュールの関数を利用する。
+
+use std::path::Path;
+
+use shared::error::AppResult;
+
+use crate::file_system::FileSystem;
+
+/// `from` 配下のファイルとディレクトリを `to` にコピーし、各ファイルの内容を SHA-256 で検証する
+///
+/// `to` は既に存在している必要がある。移動元は変更しない。
+///
+/// ```
+/// use infra::file_operations::{contents_match, copy_with_verification};
+///
+/// let source = tempfile::tempdir()?;
+/// let destination = tempfile::tempdir()?;
+/// std::fs::create_dir(source.path().join("sub"))?;
+/// std::fs::write(source.path().join("sub").join("a.txt"), "hello")?;
+///
+/// copy_with_verification(source.path(), destination.path())?;
+///
+/// assert!(contents_match(source.path(), destination.path())?);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn copy_with_verification(from: &Path, to: &Path) -> AppResult<()> {
+    FileSystem::copy_all_data_under_the_directory_with_hash_verification(from, to)
+}
+
+/// 2つのディレクトリが同じ構成で、対応するファイルの内容がすべて一致する場合に `true` を返す
+pub fn contents_match(path_1: &Path, path_2: &Path) -> AppResult<bool> {
+    FileSystem::verify_directory_contents_match(path_1, path_2)
+}
+
+/// ディレクトリ自体は残したまま、配下のファイルとディレクトリをすべて削除する
+///
+/// 読み取り専用のエントリも削除し、`dir` のパーミッションは元に戻す。
+///
+/// ```
+/// use infra::file_operations::clear_directory;
+///
+/// let dir = tempfile::tempdir()?;
+/// std::fs::write(dir.path().join("a.txt"), "hello")?;
+///
+/// clear_directory(dir.path())?;
+///
+/// assert_eq!(std::fs::read_dir(dir.path())?.count(), 0);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn clear_directory(dir: &Path) -> AppResult<()> {
+    FileSystem::clear_directory_contents(dir)
+}
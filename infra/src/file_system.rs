@@ -1,65 +1,678 @@
 use std::{
-    fs::{self, File},
-    io::Read,
-    path::Path,
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fs::{self, File, FileType, Permissions},
+    io::{self, BufWriter, ErrorKind, Read, Write},
+    path::{Path, PathBuf},
+    sync::mpsc,
+    thread,
 };
 
-use sha2::{Digest, Sha256};
+use filetime::FileTime;
+use rayon::prelude::*;
 use shared::error::{AppError, AppResult};
 
+use crate::{
+    copy_options::CopyOptions, directory_difference::DirectoryDifference,
+    extended_attributes::copy_extended_attributes, file_digest::FileDigest,
+    hash_algorithm::HashAlgorithm, storage_backend::StorageBackend, symlink_policy::SymlinkPolicy,
+    transfer_summary::TransferSummary, verification_mode::VerificationMode,
+};
+
+/// `sha256sum -c` などの外部ツールで検証できるチェックサムファイルの名前
+pub const CHECKSUM_FILE_NAME: &str = "SHA256SUMS";
+
+/// 上位のレイヤーから使う内部のファイル操作。単体で使う場合は [`crate::file_operations`] を利用する
 pub struct FileSystem;
 
+/// ファイル単位の IO エラーに、失敗した操作と対象のパスを添えるための区分
+#[derive(Debug, Clone, Copy)]
+enum FileOperation {
+    Open,
+    Read,
+    Copy,
+    Hash,
+}
+
+impl FileOperation {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Open => "オープン",
+            Self::Read => "読み込み",
+            Self::Copy => "コピー",
+            Self::Hash => "ハッシュ計算",
+        }
+    }
+
+    /// リトライの判定に使うため、元のエラーの種類は保ったままメッセージだけを補う
+    fn context(self, path: &Path, error: io::Error) -> AppError {
+        let path = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+        let hint = if error.kind() == ErrorKind::PermissionDenied {
+            "（アクセス権限を確認してください）"
+        } else {
+            ""
+        };
+        AppError::Io(io::Error::new(
+            error.kind(),
+            format!(
+                "ファイルの{}に失敗しました: {}: {}{}",
+                self.label(),
+                path.display(),
+                error,
+                hint
+            ),
+        ))
+    }
+
+    fn wrap<T>(self, path: &Path, result: io::Result<T>) -> AppResult<T> {
+        result.map_err(|e| self.context(path, e))
+    }
+
+    fn wrap_app<T>(self, path: &Path, result: AppResult<T>) -> AppResult<T> {
+        result.map_err(|e| match e {
+            AppError::Io(e) => self.context(path, e),
+            e => e,
+        })
+    }
+}
+
+/// 同じ inode を指すハードリンクを、最初にコピーした移動先のファイルへのリンクとして再現するための記録
+#[derive(Debug, Default)]
+struct HardLinks(HashMap<(u64, u64), PathBuf>);
+
+impl HardLinks {
+    #[cfg(unix)]
+    fn key(metadata: &fs::Metadata) -> Option<(u64, u64)> {
+        use std::os::unix::fs::MetadataExt;
+        (metadata.nlink() > 1).then(|| (metadata.dev(), metadata.ino()))
+    }
+
+    #[cfg(not(unix))]
+    fn key(_metadata: &fs::Metadata) -> Option<(u64, u64)> {
+        // inode を判定できない環境では常に内容をコピーする
+        None
+    }
+
+    /// 既にコピー済みの inode であれば移動先にハードリンクを作成し、作成できた場合は `true` を返す
+    fn link_if_copied(
+        &self,
+        key: Option<(u64, u64)>,
+        to: &Path,
+        backend: &dyn StorageBackend,
+    ) -> bool {
+        // ハードリンクに対応していないストレージでは、通常のコピーにフォールバックする
+        key.and_then(|key| self.0.get(&key))
+            .is_some_and(|first| backend.hard_link(first, to).is_ok())
+    }
+
+    fn record(&mut self, key: Option<(u64, u64)>, to: &Path) {
+        if let Some(key) = key {
+            self.0.entry(key).or_insert_with(|| to.to_path_buf());
+        }
+    }
+}
+
 impl FileSystem {
     pub fn copy_all_data_under_the_directory_with_hash_verification(
         from: &Path,
         to: &Path,
     ) -> AppResult<()> {
-        Self::copy_directory_recursively(from, to)
+        Self::copy_all_data_under_the_directory_with_hash_verification_and_progress(
+            from,
+            to,
+            &CopyOptions::default(),
+            |_, _| {},
+        )
+        .map(|_| ())
+    }
+
+    pub fn copy_all_data_under_the_directory_with_hash_verification_and_progress(
+        from: &Path,
+        to: &Path,
+        options: &CopyOptions,
+        mut progress: impl FnMut(&Path, u64),
+    ) -> AppResult<TransferSummary> {
+        if options.parallel {
+            return Self::copy_all_data_under_the_directory_parallel_with_progress(
+                from, to, options, progress,
+            );
+        }
+        let mut summary = TransferSummary::default();
+        Self::copy_directory_recursively(
+            from,
+            from,
+            to,
+            options,
+            &mut progress,
+            &mut summary,
+            &mut HardLinks::default(),
+        )?;
+        Ok(summary)
     }
 
-    fn copy_directory_recursively(from: &Path, to: &Path) -> AppResult<()> {
+    fn copy_directory_recursively(
+        base: &Path,
+        from: &Path,
+        to: &Path,
+        options: &CopyOptions,
+        progress: &mut dyn FnMut(&Path, u64),
+        summary: &mut TransferSummary,
+        hard_links: &mut HardLinks,
+    ) -> AppResult<()> {
         for entry in fs::read_dir(from)? {
             let entry = entry?;
             let entry_path = entry.path();
             let rel_path = entry_path.strip_prefix(from)?;
             let dest_path = to.join(rel_path);
 
-            if entry.file_type()?.is_dir() {
-                fs::create_dir_all(&dest_path)?;
-                Self::copy_directory_recursively(&entry_path, &dest_path)?;
-            } else {
-                fs::copy(entry.path(), dest_path.as_path())?;
+            let file_type = entry.file_type()?;
+            if options.is_excluded(entry_path.strip_prefix(base)?)
+                || options.is_beyond_max_depth(entry_path.strip_prefix(base)?)
+                || options.is_skipped_symlink(&file_type)
+            {
+                continue;
+            }
 
-                let entry_hash = Self::calculate_hash_from_file_content(&entry_path)?;
-                let dest_hash = Self::calculate_hash_from_file_content(&dest_path)?;
-                if entry_hash != dest_hash {
-                    return Err(AppError::Io(std::io::Error::new(
-                        std::io::ErrorKind::InvalidData,
-                        format!(
-                            "ハッシュ値が一致しません。: {} -> {}",
-                            entry_hash, dest_hash
-                        ),
-                    )));
+            if Self::is_directory(&entry_path, &file_type, options)? {
+                options.storage_backend.create_dir(&dest_path)?;
+                summary.add_directory();
+                Self::copy_directory_recursively(
+                    base,
+                    &entry_path,
+                    &dest_path,
+                    options,
+                    progress,
+                    summary,
+                    hard_links,
+                )?;
+            } else if options.is_target_file(entry_path.strip_prefix(base)?, &entry_path)? {
+                if Self::is_already_copied(&entry_path, &dest_path, options)? {
+                    summary.add_skipped_file();
+                    continue;
+                }
+                let bytes = if file_type.is_file() {
+                    let metadata = entry.metadata()?;
+                    let key = HardLinks::key(&metadata);
+                    if hard_links.link_if_copied(key, &dest_path, &*options.storage_backend) {
+                        metadata.len()
+                    } else {
+                        let bytes =
+                            options
+                                .storage_backend
+                                .copy_file(&entry_path, &dest_path, options)?;
+                        hard_links.record(key, &dest_path);
+                        bytes
+                    }
+                } else {
+                    options
+                        .storage_backend
+                        .copy_file(&entry_path, &dest_path, options)?
+                };
+                summary.add_file(bytes);
+                progress(entry_path.strip_prefix(base)?, bytes);
+            }
+        }
+        Ok(())
+    }
+
+    fn is_directory(
+        entry_path: &Path,
+        file_type: &FileType,
+        options: &CopyOptions,
+    ) -> AppResult<bool> {
+        if file_type.is_symlink() {
+            // リンクを辿る場合のみ、リンク先がディレクトリであれば中身を再帰的にコピーする
+            return Ok(options.symlink_policy == SymlinkPolicy::Follow
+                && fs::metadata(entry_path)?.is_dir());
+        }
+        Ok(file_type.is_dir())
+    }
+
+    /// 移動先に同じハッシュ値のファイルが既にあれば、コピー済みとみなす
+    fn is_already_copied(from: &Path, to: &Path, options: &CopyOptions) -> AppResult<bool> {
+        if !options.skip_existing_matching
+            || Self::is_link_to_copy(from, options)?
+            || !options.storage_backend.is_file(to)?
+        {
+            return Ok(false);
+        }
+        Ok(Self::calculate_hash_from_file_content(from, options)?
+            == options.storage_backend.hash(to, options)?)
+    }
+
+    fn is_link_to_copy(path: &Path, options: &CopyOptions) -> AppResult<bool> {
+        Ok(options.symlink_policy == SymlinkPolicy::CopyAsLink
+            && fs::symlink_metadata(path)?.file_type().is_symlink())
+    }
+
+    pub(crate) fn copy_file_entry(from: &Path, to: &Path, options: &CopyOptions) -> AppResult<u64> {
+        if let Some(deadline) = &options.deadline {
+            deadline.check()?;
+        }
+        // 既存のファイルを再利用する場合、一致しなかった移動先のファイルは読み取り専用でも上書きできるよう先に削除する
+        if options.skip_existing_matching && fs::symlink_metadata(to).is_ok() {
+            FileOperation::Copy.wrap(to, fs::remove_file(to))?;
+        }
+        if Self::is_link_to_copy(from, options)? {
+            Self::copy_symlink(from, to)?;
+            return Ok(0);
+        }
+        options
+            .retry_policy
+            .run(|| Self::copy_file_with_hash_verification(from, to, options))
+    }
+
+    #[cfg(unix)]
+    fn copy_symlink(from: &Path, to: &Path) -> AppResult<()> {
+        std::os::unix::fs::symlink(fs::read_link(from)?, to)?;
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    fn copy_symlink(from: &Path, to: &Path) -> AppResult<()> {
+        let target = fs::read_link(from)?;
+        if fs::metadata(from).is_ok_and(|metadata| metadata.is_dir()) {
+            std::os::windows::fs::symlink_dir(target, to)?;
+        } else {
+            std::os::windows::fs::symlink_file(target, to)?;
+        }
+        Ok(())
+    }
+
+    fn contains_symlink(dir: &Path) -> AppResult<bool> {
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let file_type = entry.file_type()?;
+            if file_type.is_symlink()
+                || (file_type.is_dir() && Self::contains_symlink(&entry.path())?)
+            {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// 同一ファイルシステム上であれば `fs::rename` でエントリを移動し、移動した内容の集計を返す
+    pub fn try_atomic_move(
+        from: &Path,
+        to: &Path,
+        options: &CopyOptions,
+    ) -> AppResult<Option<TransferSummary>> {
+        Self::try_atomic_move_with_progress(from, to, options, |_, _| {})
+    }
+
+    /// リネームがすべて成功した後に、移動したファイルごとに移動元からの相対パスとサイズで `progress` を呼び出す
+    pub fn try_atomic_move_with_progress(
+        from: &Path,
+        to: &Path,
+        options: &CopyOptions,
+        mut progress: impl FnMut(&Path, u64),
+    ) -> AppResult<Option<TransferSummary>> {
+        // 除外対象や対象外のファイルは任意の深さに存在し得るため、エントリ単位の判定が必要なコピーで処理する
+        // リネームはシンボリックリンクをリンクのまま移動するため、リンクとしてコピーする場合に限る
+        if !options.storage_backend.is_local()
+            || !options.exclude_patterns.is_empty()
+            || !options.include_patterns.is_empty()
+            || options.modified_since.is_some()
+            || options.max_depth.is_some()
+            || !Self::is_directory_empty(to)?
+            || !Self::is_same_device(from, to)?
+            || (options.symlink_policy != SymlinkPolicy::CopyAsLink
+                && Self::contains_symlink(from)?)
+        {
+            return Ok(None);
+        }
+
+        let (summary, files) = Self::summarize_directory_files(from, options)?;
+
+        let mut moved: Vec<(PathBuf, PathBuf)> = Vec::new();
+        for entry in fs::read_dir(from)? {
+            let entry_path = entry?.path();
+            let dest_path = to.join(entry_path.strip_prefix(from)?);
+            if let Err(e) = fs::rename(&entry_path, &dest_path) {
+                // 途中で失敗した場合は移動済みのエントリを元に戻す
+                for (moved_from, moved_to) in moved.iter().rev() {
+                    fs::rename(moved_to, moved_from)
+                        .map_err(|e| Self::rename_error(moved_to, moved_from, e))?;
+                }
+                // 読み取り専用の移動元などリネームできない場合は、コピーと検証による移動に切り替える
+                if Self::is_rename_not_applicable(&e) {
+                    return Ok(None);
                 }
+                return Err(Self::rename_error(&entry_path, &dest_path, e));
+            }
+            moved.push((entry_path, dest_path));
+        }
+        for (file, bytes) in &files {
+            progress(file, *bytes);
+        }
+        Ok(Some(summary))
+    }
+
+    fn is_rename_not_applicable(error: &io::Error) -> bool {
+        matches!(
+            error.kind(),
+            ErrorKind::PermissionDenied | ErrorKind::CrossesDevices | ErrorKind::ReadOnlyFilesystem
+        )
+    }
+
+    fn rename_error(from: &Path, to: &Path, error: io::Error) -> AppError {
+        AppError::Io(io::Error::new(
+            error.kind(),
+            format!(
+                "リネームに失敗しました: {} -> {}: {}",
+                from.display(),
+                to.display(),
+                error
+            ),
+        ))
+    }
+
+    #[cfg(unix)]
+    fn is_same_device(path_1: &Path, path_2: &Path) -> AppResult<bool> {
+        use std::os::unix::fs::MetadataExt;
+        Ok(fs::metadata(path_1)?.dev() == fs::metadata(path_2)?.dev())
+    }
+
+    #[cfg(not(unix))]
+    fn is_same_device(_path_1: &Path, _path_2: &Path) -> AppResult<bool> {
+        // デバイスを判定できない環境では常にコピーで移動する
+        Ok(false)
+    }
+
+    pub fn copy_all_data_under_the_directory_parallel(
+        from: &Path,
+        to: &Path,
+        options: &CopyOptions,
+    ) -> AppResult<TransferSummary> {
+        Self::copy_all_data_under_the_directory_parallel_with_progress(from, to, options, |_, _| {})
+    }
+
+    /// ハードリンクは共有せず、個別のファイルとしてコピーする
+    /// `progress` はコピーを終えた順に、呼び出し元のスレッドで呼び出す
+    pub fn copy_all_data_under_the_directory_parallel_with_progress(
+        from: &Path,
+        to: &Path,
+        options: &CopyOptions,
+        mut progress: impl FnMut(&Path, u64),
+    ) -> AppResult<TransferSummary> {
+        let mut directories = Vec::new();
+        let mut files = Vec::new();
+        Self::collect_relative_entries(from, from, options, &mut directories, &mut files)?;
+
+        let mut summary = TransferSummary::default();
+        // 親ディレクトリを先に作成してからファイルを並列にコピーする
+        for directory in &directories {
+            options.storage_backend.create_dir(&to.join(directory))?;
+            summary.add_directory();
+        }
+
+        files.sort();
+        // コピーを省略したファイルは `None` とする
+        let (sender, receiver) = mpsc::channel();
+        let results: Vec<AppResult<Option<u64>>> = thread::scope(|scope| {
+            let copying = scope.spawn(|| {
+                files
+                    .par_iter()
+                    .map_with(sender, |sender, file| {
+                        let (from, to) = (from.join(file), to.join(file));
+                        if Self::is_already_copied(&from, &to, options)? {
+                            return Ok(None);
+                        }
+                        let bytes = options.storage_backend.copy_file(&from, &to, options)?;
+                        // 受信側は最後まで待ち受けるため、送信の失敗は無視してよい
+                        let _ = sender.send((file, bytes));
+                        Ok(Some(bytes))
+                    })
+                    .collect()
+            });
+            // 進捗の通知は `Send` でないことがあるため、ワーカーからは送らずこのスレッドで呼び出す
+            for (file, bytes) in receiver {
+                progress(file, bytes);
+            }
+            copying
+                .join()
+                .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+        });
+
+        // 並列実行でも常に同じエラーを返すよう、パス順で最初のエラーを採用する
+        for bytes in results {
+            match bytes? {
+                Some(bytes) => summary.add_file(bytes),
+                None => summary.add_skipped_file(),
+            }
+        }
+        Ok(summary)
+    }
+
+    fn collect_relative_entries(
+        base: &Path,
+        current: &Path,
+        options: &CopyOptions,
+        directories: &mut Vec<PathBuf>,
+        files: &mut Vec<PathBuf>,
+    ) -> AppResult<()> {
+        for entry in fs::read_dir(current)? {
+            let entry = entry?;
+            let entry_path = entry.path();
+            let rel_path = entry_path.strip_prefix(base)?.to_path_buf();
+
+            let file_type = entry.file_type()?;
+            if options.is_excluded(&rel_path)
+                || options.is_beyond_max_depth(&rel_path)
+                || options.is_skipped_symlink(&file_type)
+            {
+                continue;
+            }
+
+            if Self::is_directory(&entry_path, &file_type, options)? {
+                directories.push(rel_path);
+                Self::collect_relative_entries(base, &entry_path, options, directories, files)?;
+            } else if options.is_target_file(&rel_path, &entry_path)? {
+                files.push(rel_path);
             }
         }
         Ok(())
     }
 
-    fn calculate_hash_from_file_content(path: &Path) -> AppResult<String> {
-        let mut file = File::open(path)?;
-        let mut hasher = Sha256::new();
-        let mut buffer = [0u8; 8192];
+    fn copy_file_with_hash_verification(
+        from: &Path,
+        to: &Path,
+        options: &CopyOptions,
+    ) -> AppResult<u64> {
+        let source_metadata = FileOperation::Open.wrap(from, fs::metadata(from))?;
+        // 移動元は一度だけ読み込み、書き込みと同時にハッシュ値を計算する
+        let (bytes, source_hash) = Self::copy_file_with_hash(from, to, options)?;
+        // 読み取り専用の権限を設定すると属性を書き込めなくなるため、権限より先に複製する
+        if options.preserve_xattrs {
+            FileOperation::Copy.wrap_app(to, copy_extended_attributes(from, to))?;
+        }
+        FileOperation::Copy.wrap(to, fs::set_permissions(to, source_metadata.permissions()))?;
+        FileOperation::Copy.wrap_app(to, Self::copy_file_times(&source_metadata, to))?;
+
+        // ハッシュ以外の検証方法では、移動先を読み直さずにメタデータだけを比較する
+        let matched = match options.verification_mode {
+            VerificationMode::Hash => {
+                Self::verify_written_file(to, &source_hash, options)?;
+                true
+            }
+            mode => FileOperation::Hash.wrap_app(
+                to,
+                mode.files_match(from, to, options.hash_algorithm, options.buffer_size),
+            )?,
+        };
+        if !matched {
+            return Err(AppError::IntegrityMismatch {
+                source_path: from.to_path_buf(),
+                destination_path: to.to_path_buf(),
+            });
+        }
+        Ok(bytes)
+    }
+
+    /// 移動先を読み直し、書き込んだバイト列のハッシュ値と比べる
+    ///
+    /// 移動元は読み直さないため、コピーの後に移動元が変更されても一致の判定には影響しない。
+    fn verify_written_file(to: &Path, written_hash: &str, options: &CopyOptions) -> AppResult<()> {
+        let hash = FileOperation::Hash
+            .wrap_app(to, Self::calculate_hash_from_file_content(to, options))?;
+        match hash == written_hash {
+            true => Ok(()),
+            false => Err(AppError::DestinationCorrupted(to.to_path_buf())),
+        }
+    }
+
+    fn copy_file_with_hash(
+        from: &Path,
+        to: &Path,
+        options: &CopyOptions,
+    ) -> AppResult<(u64, String)> {
+        let mut reader = FileOperation::Open.wrap(from, File::open(from))?;
+        let mut writer = BufWriter::with_capacity(
+            options.buffer_size.bytes(),
+            FileOperation::Open.wrap(to, File::create(to))?,
+        );
+        let mut hasher = options.hash_algorithm.hasher();
+        let mut buffer = options.buffer_size.allocate();
+        let mut bytes = 0;
 
         loop {
-            let n = file.read(&mut buffer)?;
+            let n = FileOperation::Read.wrap(from, reader.read(&mut buffer))?;
             if n == 0 {
                 break;
             }
             hasher.update(&buffer[..n]);
+            if let Some(rate_limiter) = &options.rate_limiter {
+                rate_limiter.acquire(n as u64);
+            }
+            FileOperation::Copy.wrap(to, writer.write_all(&buffer[..n]))?;
+            bytes += n as u64;
+        }
+        FileOperation::Copy.wrap(to, writer.flush())?;
+
+        Ok((bytes, hasher.finalize()))
+    }
+
+    fn copy_file_times(source_metadata: &fs::Metadata, to: &Path) -> AppResult<()> {
+        let accessed = FileTime::from_last_access_time(source_metadata);
+        let modified = FileTime::from_last_modification_time(source_metadata);
+        filetime::set_file_times(to, accessed, modified)?;
+        Ok(())
+    }
+
+    pub(crate) fn calculate_hash_from_file_content(
+        path: &Path,
+        options: &CopyOptions,
+    ) -> AppResult<String> {
+        options
+            .hash_algorithm
+            .calculate_hash_from_file_content_with_buffer_size(path, options.buffer_size)
+    }
+
+    pub fn calculate_directory_size(dir: &Path, options: &CopyOptions) -> AppResult<u64> {
+        Ok(Self::summarize_directory(dir, options)?.total_bytes)
+    }
+
+    /// コピーと同じ規則で辿った `(ファイル数, ディレクトリ数)` を返す（`dir` 自体は数えない）
+    pub fn count_entries(dir: &Path, options: &CopyOptions) -> AppResult<(u64, u64)> {
+        let mut directories = Vec::new();
+        let mut files = Vec::new();
+        Self::collect_relative_entries(dir, dir, options, &mut directories, &mut files)?;
+        Ok((files.len() as u64, directories.len() as u64))
+    }
+
+    fn summarize_directory(dir: &Path, options: &CopyOptions) -> AppResult<TransferSummary> {
+        Ok(Self::summarize_directory_files(dir, options)?.0)
+    }
+
+    /// 集計に加えて、ファイルごとの相対パスとサイズを返す
+    fn summarize_directory_files(
+        dir: &Path,
+        options: &CopyOptions,
+    ) -> AppResult<(TransferSummary, Vec<(PathBuf, u64)>)> {
+        let mut directories = Vec::new();
+        let mut files = Vec::new();
+        Self::collect_relative_entries(dir, dir, options, &mut directories, &mut files)?;
+
+        let mut summary = TransferSummary::default();
+        directories.iter().for_each(|_| summary.add_directory());
+        let mut file_sizes = Vec::with_capacity(files.len());
+        for file in files {
+            let path = dir.join(&file);
+            let bytes = match Self::is_link_to_copy(&path, options)? {
+                true => 0,
+                false => fs::metadata(path)?.len(),
+            };
+            summary.add_file(bytes);
+            file_sizes.push((file, bytes));
         }
+        Ok((summary, file_sizes))
+    }
+
+    pub fn create_directory_with_mode(path: &Path, mode: Option<u32>) -> AppResult<()> {
+        // 作成前に存在しない祖先を記録し、新たに作ったディレクトリだけにパーミッションを設定する
+        let created: Vec<PathBuf> = path
+            .ancestors()
+            .take_while(|ancestor| !ancestor.as_os_str().is_empty() && !ancestor.exists())
+            .map(Path::to_path_buf)
+            .collect();
+        fs::create_dir_all(path)?;
+
+        if let Some(mode) = mode {
+            for dir in created.iter().rev() {
+                Self::set_directory_mode(dir, mode)?;
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    fn set_directory_mode(path: &Path, mode: u32) -> AppResult<()> {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(path, fs::Permissions::from_mode(mode))?;
+        Ok(())
+    }
+
+    // Windows にはUnixのパーミッションに相当するものがないため無視する
+    #[cfg(not(unix))]
+    fn set_directory_mode(_path: &Path, _mode: u32) -> AppResult<()> {
+        Ok(())
+    }
+
+    /// まだ存在しないパスの場合は、存在する最も近い親ディレクトリのボリュームで判定する
+    pub fn available_space(path: &Path) -> AppResult<u64> {
+        Ok(fs2::available_space(Self::nearest_existing_ancestor(path))?)
+    }
+
+    /// パス自身を含め、存在する最も近い祖先を返す。いずれも存在しなければパスをそのまま返す
+    pub fn nearest_existing_ancestor(path: &Path) -> &Path {
+        path.ancestors()
+            .find(|ancestor| ancestor.exists())
+            .unwrap_or(path)
+    }
 
-        Ok(format!("{:x}", hasher.finalize()))
+    /// どちらか一方がもう一方の配下（または同一）にあるかを、正規化したパスで判定する
+    pub fn are_paths_nested(path_1: &Path, path_2: &Path) -> AppResult<bool> {
+        let path_1 = Self::canonicalize_existing_prefix(path_1)?;
+        let path_2 = Self::canonicalize_existing_prefix(path_2)?;
+        Ok(path_1.starts_with(&path_2) || path_2.starts_with(&path_1))
+    }
+
+    /// 存在する部分までを正規化し、まだ作成されていない残りの要素をそのまま付け加える
+    fn canonicalize_existing_prefix(path: &Path) -> AppResult<PathBuf> {
+        let mut missing = Vec::new();
+        let mut existing = path;
+        while !existing.exists() {
+            match (existing.parent(), existing.file_name()) {
+                (Some(parent), Some(name)) => {
+                    missing.push(name);
+                    existing = parent;
+                }
+                _ => break,
+            }
+        }
+        let mut canonical = fs::canonicalize(existing)?;
+        canonical.extend(missing.into_iter().rev());
+        Ok(canonical)
     }
 
     pub fn is_path_readonly(path: &Path) -> AppResult<bool> {
@@ -67,40 +680,313 @@ impl FileSystem {
         Ok(metadata.permissions().readonly())
     }
 
+    pub fn is_directory_readable(path: &Path) -> bool {
+        fs::read_dir(path).is_ok()
+    }
+
+    /// 権限ビットではなく、このプロセスが実際にファイルを作成できるかを一時ファイルの作成と削除で確かめる
+    pub fn is_directory_writable(path: &Path) -> bool {
+        let probe = path.join(format!(".srow-write-test-{}", std::process::id()));
+        match File::options().write(true).create_new(true).open(&probe) {
+            Ok(_) => fs::remove_file(&probe).is_ok(),
+            Err(_) => false,
+        }
+    }
+
     pub fn is_directory_empty(path: &Path) -> AppResult<bool> {
         let mut entries = fs::read_dir(path)?;
         Ok(entries.next().is_none())
     }
 
     pub fn verify_directory_contents_match(path_1: &Path, path_2: &Path) -> AppResult<bool> {
-        let list_1 = Self::list_relative_paths(path_1)?;
-        let list_2 = Self::list_relative_paths(path_2)?;
-        Ok(list_1 == list_2)
+        Self::verify_directory_contents_match_with_options(path_1, path_2, &CopyOptions::default())
     }
 
-    fn list_relative_paths(base: &Path) -> AppResult<Vec<String>> {
-        let mut list = Vec::new();
-        for entry in fs::read_dir(base)? {
-            let entry = entry?;
-            let entry_path = entry.path();
-            if entry_path == base {
+    pub fn verify_directory_contents_match_with_options(
+        path_1: &Path,
+        path_2: &Path,
+        options: &CopyOptions,
+    ) -> AppResult<bool> {
+        Ok(Self::find_directory_differences(path_1, path_2, options)?.is_empty())
+    }
+
+    pub fn find_directory_differences(
+        source: &Path,
+        destination: &Path,
+        options: &CopyOptions,
+    ) -> AppResult<Vec<DirectoryDifference>> {
+        let (source_directories, source_files) = Self::collect_entry_maps(source, options)?;
+        let (destination_directories, destination_files) =
+            Self::collect_entry_maps(destination, options)?;
+
+        // 比較はキーで行い、差分には移動元（存在しない場合は移動先）の実際の名前を示す
+        let mut differences = Vec::new();
+        let directory_keys: BTreeSet<&PathBuf> = source_directories
+            .keys()
+            .chain(destination_directories.keys())
+            .collect();
+        for key in directory_keys {
+            match (
+                source_directories.get(key),
+                destination_directories.get(key),
+            ) {
+                (Some(rel_path), None) if !destination_files.contains_key(key) => {
+                    differences.push(DirectoryDifference::MissingInDestination(rel_path.clone()));
+                }
+                (None, Some(rel_path)) if !source_files.contains_key(key) => {
+                    differences.push(DirectoryDifference::MissingInSource(rel_path.clone()));
+                }
+                _ => {}
+            }
+        }
+        let file_keys: BTreeSet<&PathBuf> = source_files
+            .keys()
+            .chain(destination_files.keys())
+            .collect();
+        for key in file_keys {
+            match (source_files.get(key), destination_files.get(key)) {
+                (Some(source_path), Some(destination_path)) => {
+                    if !Self::files_match(
+                        &source.join(source_path),
+                        &destination.join(destination_path),
+                        options,
+                    )? {
+                        differences.push(DirectoryDifference::ContentMismatch(source_path.clone()));
+                    }
+                }
+                // 片方ではファイル、もう片方ではディレクトリになっている
+                (Some(rel_path), None) if destination_directories.contains_key(key) => {
+                    differences.push(DirectoryDifference::ContentMismatch(rel_path.clone()));
+                }
+                (None, Some(rel_path)) if source_directories.contains_key(key) => {
+                    differences.push(DirectoryDifference::ContentMismatch(rel_path.clone()));
+                }
+                (Some(rel_path), None) => {
+                    differences.push(DirectoryDifference::MissingInDestination(rel_path.clone()));
+                }
+                (None, Some(rel_path)) => {
+                    differences.push(DirectoryDifference::MissingInSource(rel_path.clone()));
+                }
+                (None, None) => unreachable!(),
+            }
+        }
+        Ok(differences)
+    }
+
+    /// ディレクトリ配下のすべてのファイルの相対パスと内容を、1つのハッシュ値（Merkle ルート）にまとめる
+    pub fn tree_hash(dir: &Path) -> AppResult<String> {
+        Self::tree_hash_with_options(dir, &CopyOptions::default())
+    }
+
+    pub fn tree_hash_with_options(dir: &Path, options: &CopyOptions) -> AppResult<String> {
+        let algorithm = options.hash_algorithm;
+        // 相対パス順の `(パス, ハッシュ値)` を葉とし、葉と節点は先頭のバイトで区別する
+        let mut level = Self::digest_files(dir, options)?
+            .into_iter()
+            .map(|digest| {
+                let mut hasher = algorithm.hasher();
+                hasher.update(&[0]);
+                let rel_path = options.case_sensitivity.key(&digest.path);
+                hasher.update(Self::portable_path(&rel_path)?.as_bytes());
+                hasher.update(&[0]);
+                hasher.update(digest.hash.as_bytes());
+                Ok(hasher.finalize())
+            })
+            .collect::<AppResult<Vec<_>>>()?;
+        if level.is_empty() {
+            return Ok(algorithm.hasher().finalize());
+        }
+        while level.len() > 1 {
+            level = level
+                .chunks(2)
+                .map(|children| {
+                    let mut hasher = algorithm.hasher();
+                    hasher.update(&[1]);
+                    children
+                        .iter()
+                        .for_each(|child| hasher.update(child.as_bytes()));
+                    hasher.finalize()
+                })
+                .collect();
+        }
+        Ok(level.remove(0))
+    }
+
+    /// OS に依存しないよう、相対パスを `/` 区切りの文字列にする
+    fn portable_path(path: &Path) -> AppResult<String> {
+        let components = path
+            .components()
+            .map(|component| {
+                component
+                    .as_os_str()
+                    .to_str()
+                    .ok_or_else(|| AppError::InvalidPath(path.to_path_buf()))
+            })
+            .collect::<AppResult<Vec<_>>>()?;
+        Ok(components.join("/"))
+    }
+
+    /// ディレクトリ内の各ファイルのサイズとハッシュ値を相対パス順に返す
+    pub fn digest_files(dir: &Path, options: &CopyOptions) -> AppResult<Vec<FileDigest>> {
+        let (_, files) = Self::collect_entry_sets(dir, options)?;
+        let mut digests = Vec::with_capacity(files.len());
+        for rel_path in files {
+            let path = dir.join(&rel_path);
+            // リンク自体をコピーしたエントリは内容を持たないため対象外とする
+            if Self::is_link_to_copy(&path, options)? {
+                continue;
+            }
+            digests.push(FileDigest {
+                size: fs::metadata(&path)?.len(),
+                hash: Self::calculate_hash_from_file_content(&path, options)?,
+                path: rel_path,
+            });
+        }
+        Ok(digests)
+    }
+
+    /// 差分の検出と同じ走査で、対象となるディレクトリとファイルの相対パスを名前順に返す
+    pub fn list_entries(dir: &Path, options: &CopyOptions) -> AppResult<Vec<PathBuf>> {
+        let (directories, files) = Self::collect_entry_sets(dir, options)?;
+        let mut entries: Vec<PathBuf> = directories.into_iter().chain(files).collect();
+        entries.sort();
+        Ok(entries)
+    }
+
+    /// ディレクトリ配下の各ファイルについて `ハッシュ値  相対パス` の形式で SHA-256 を書き出す
+    pub fn write_checksum_file(dir: &Path, options: &CopyOptions) -> AppResult<PathBuf> {
+        let options = CopyOptions {
+            hash_algorithm: HashAlgorithm::Sha256,
+            ..options.clone()
+        };
+        let mut content = String::new();
+        for digest in Self::digest_files(dir, &options)? {
+            if digest.path == Path::new(CHECKSUM_FILE_NAME) {
                 continue;
             }
-            let rel = entry_path.strip_prefix(base).unwrap().to_path_buf();
-            list.push(rel.to_string_lossy().to_string());
+            content.push_str(&format!(
+                "{}  {}\n",
+                digest.hash,
+                Self::portable_path(&digest.path)?
+            ));
+        }
+        let path = dir.join(CHECKSUM_FILE_NAME);
+        fs::write(&path, content)?;
+        Ok(path)
+    }
+
+    /// チェックサムファイルに記載された各ファイルのハッシュ値を再計算し、欠落や内容の不一致を返す
+    pub fn verify_checksum_file(dir: &Path) -> AppResult<Vec<DirectoryDifference>> {
+        let checksum_path = dir.join(CHECKSUM_FILE_NAME);
+        let content = fs::read_to_string(&checksum_path)?;
+        let mut differences = Vec::new();
+        for line in content.lines().filter(|line| !line.is_empty()) {
+            // `sha256sum` のバイナリモードで出力される `*` 付きの形式も受け付ける
+            let (hash, rel_path) = line
+                .split_once(' ')
+                .and_then(|(hash, rest)| {
+                    rest.strip_prefix(' ')
+                        .or_else(|| rest.strip_prefix('*'))
+                        .map(|rel_path| (hash, rel_path))
+                })
+                .ok_or_else(|| {
+                    AppError::Io(io::Error::new(
+                        ErrorKind::InvalidData,
+                        format!(
+                            "チェックサムファイルの形式が正しくありません: {}: {}",
+                            checksum_path.display(),
+                            line
+                        ),
+                    ))
+                })?;
+            let rel_path: PathBuf = rel_path.split('/').collect();
+            let path = dir.join(&rel_path);
+            if !path.is_file() {
+                differences.push(DirectoryDifference::MissingInDestination(rel_path));
+            } else if !HashAlgorithm::Sha256
+                .calculate_hash_from_file_content(&path)?
+                .eq_ignore_ascii_case(hash)
+            {
+                differences.push(DirectoryDifference::ContentMismatch(rel_path));
+            }
         }
-        list.sort();
-        Ok(list)
+        Ok(differences)
     }
 
+    fn files_match(path_1: &Path, path_2: &Path, options: &CopyOptions) -> AppResult<bool> {
+        // リンク自体をコピーする場合は、リンク先ではなくリンクの参照先パスを比較する
+        if options.symlink_policy == SymlinkPolicy::CopyAsLink
+            && fs::symlink_metadata(path_1)?.file_type().is_symlink()
+        {
+            let link_1 = fs::read_link(path_1)?;
+            return Ok(fs::read_link(path_2).is_ok_and(|link_2| link_2 == link_1));
+        }
+        options.verification_mode.files_match(
+            path_1,
+            path_2,
+            options.hash_algorithm,
+            options.buffer_size,
+        )
+    }
+
+    fn collect_entry_sets(
+        base: &Path,
+        options: &CopyOptions,
+    ) -> AppResult<(BTreeSet<PathBuf>, BTreeSet<PathBuf>)> {
+        let mut directories = Vec::new();
+        let mut files = Vec::new();
+        Self::collect_relative_entries(base, base, options, &mut directories, &mut files)?;
+        Ok((
+            directories.into_iter().collect(),
+            files.into_iter().collect(),
+        ))
+    }
+
+    /// 名前の比較に使うキーから、実際の相対パスを引けるようにする
+    fn collect_entry_maps(
+        base: &Path,
+        options: &CopyOptions,
+    ) -> AppResult<(BTreeMap<PathBuf, PathBuf>, BTreeMap<PathBuf, PathBuf>)> {
+        let (directories, files) = Self::collect_entry_sets(base, options)?;
+        let by_key = |paths: BTreeSet<PathBuf>| {
+            paths
+                .into_iter()
+                .map(|path| (options.case_sensitivity.key(&path), path))
+                .collect()
+        };
+        Ok((by_key(directories), by_key(files)))
+    }
+
+    /// 読み取り専用のディレクトリでも中身を削除し、ディレクトリ自体の権限は元に戻す
     pub fn clear_directory_contents<P: AsRef<Path>>(dir: P) -> AppResult<()> {
-        for entry in fs::read_dir(&dir)? {
+        Self::with_writable_directory(dir.as_ref(), Self::remove_entries)
+    }
+
+    /// 中身ごとディレクトリを削除する
+    pub fn remove_directory(dir: &Path) -> AppResult<()> {
+        Self::clear_directory_contents(dir)?;
+        fs::remove_dir(dir)?;
+        Ok(())
+    }
+
+    /// `to` に既存のディレクトリがあれば削除してから、`from` を `to` にリネームする
+    pub fn replace_directory(from: &Path, to: &Path) -> AppResult<()> {
+        if to.is_dir() {
+            Self::remove_directory(to)?;
+        }
+        fs::rename(from, to)?;
+        Ok(())
+    }
+
+    fn remove_entries(dir: &Path) -> AppResult<()> {
+        for entry in fs::read_dir(dir)? {
             let entry = entry?;
             let path = entry.path();
             let metadata = entry.metadata()?;
+            Self::make_writable(&path, &metadata)?;
 
             if metadata.is_dir() {
-                Self::clear_directory_contents(&path)?;
+                Self::remove_entries(&path)?;
                 fs::remove_dir(&path)?;
             } else {
                 fs::remove_file(&path)?;
@@ -108,13 +994,100 @@ impl FileSystem {
         }
         Ok(())
     }
+
+    /// 除外対象のエントリを残したままディレクトリの中身を削除する
+    pub fn clear_directory_contents_with_options(
+        dir: &Path,
+        options: &CopyOptions,
+    ) -> AppResult<()> {
+        Self::with_writable_directory(dir, |dir| {
+            Self::clear_transferred_entries(dir, dir, options)
+        })
+    }
+
+    fn clear_transferred_entries(base: &Path, dir: &Path, options: &CopyOptions) -> AppResult<()> {
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if options.is_excluded(path.strip_prefix(base)?)
+                || options.is_beyond_max_depth(path.strip_prefix(base)?)
+                || options.is_skipped_symlink(&entry.file_type()?)
+            {
+                continue;
+            }
+
+            let metadata = entry.metadata()?;
+            if metadata.is_dir() {
+                Self::with_writable_directory(&path, |path| {
+                    Self::clear_transferred_entries(base, path, options)
+                })?;
+                if Self::is_directory_empty(&path)? {
+                    Self::make_writable(&path, &metadata)?;
+                    fs::remove_dir(&path)?;
+                }
+            } else if options.is_target_file(path.strip_prefix(base)?, &path)? {
+                Self::make_writable(&path, &metadata)?;
+                fs::remove_file(&path)?;
+            }
+        }
+        Ok(())
+    }
+
+    // 読み取り専用のディレクトリ内のエントリは削除できない環境があるため、一時的に書き込み権限を付与する
+    fn with_writable_directory(
+        dir: &Path,
+        f: impl FnOnce(&Path) -> AppResult<()>,
+    ) -> AppResult<()> {
+        let metadata = fs::symlink_metadata(dir)?;
+        let original = metadata.permissions();
+        Self::make_writable(dir, &metadata)?;
+        let result = f(dir);
+        fs::set_permissions(dir, original)?;
+        result
+    }
+
+    fn make_writable(path: &Path, metadata: &fs::Metadata) -> AppResult<()> {
+        // シンボリックリンクの権限を変更するとリンク先が変わってしまうため対象外とする
+        if metadata.file_type().is_symlink() {
+            return Ok(());
+        }
+        let mut permissions = metadata.permissions();
+        if Self::allow_owner_write(&mut permissions) {
+            fs::set_permissions(path, permissions)?;
+        }
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    fn allow_owner_write(permissions: &mut Permissions) -> bool {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = permissions.mode();
+        permissions.set_mode(mode | 0o200);
+        mode & 0o200 == 0
+    }
+
+    #[cfg(not(unix))]
+    fn allow_owner_write(permissions: &mut Permissions) -> bool {
+        let readonly = permissions.readonly();
+        #[allow(clippy::permissions_set_readonly_false)]
+        permissions.set_readonly(false);
+        readonly
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        bandwidth_limit::{BandwidthLimit, RateLimiter},
+        buffer_size::BufferSize,
+        case_sensitivity::CaseSensitivity,
+        deadline::Deadline,
+        glob_patterns::GlobPatterns,
+    };
     use std::fs::{self, File};
     use std::io::Write;
+    use std::time::Duration;
     use tempfile::TempDir;
 
     #[test]
@@ -164,11 +1137,667 @@ mod tests {
     }
 
     #[test]
-    fn copy_all_data_under_the_directory_with_hash_verification_returns_error_when_source_directory_does_not_exist(
-    ) {
+    fn copy_all_data_under_the_directory_with_hash_verification_and_progress_reports_each_file() {
         // ===== Arrange =====
         let temp_dir = TempDir::new().unwrap();
-        let non_existent_source = temp_dir.path().join("non_existent");
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+
+        fs::create_dir_all(source_dir.join("subdir")).unwrap();
+        fs::create_dir(&dest_dir).unwrap();
+
+        File::create(source_dir.join("file1.txt"))
+            .unwrap()
+            .write_all(b"12345")
+            .unwrap();
+        File::create(source_dir.join("subdir").join("file2.txt"))
+            .unwrap()
+            .write_all(b"123")
+            .unwrap();
+
+        let mut reported = Vec::new();
+
+        // ===== Act =====
+        let result =
+            FileSystem::copy_all_data_under_the_directory_with_hash_verification_and_progress(
+                &source_dir,
+                &dest_dir,
+                &CopyOptions::default(),
+                |path, bytes| reported.push((path.to_path_buf(), bytes)),
+            );
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        reported.sort();
+        assert_eq!(
+            reported,
+            vec![
+                (PathBuf::from("file1.txt"), 5),
+                (Path::new("subdir").join("file2.txt"), 3),
+            ]
+        );
+    }
+
+    fn create_tree_with_excluded_entries(source_dir: &Path) {
+        fs::create_dir_all(source_dir.join("node_modules").join("pkg")).unwrap();
+        fs::create_dir_all(source_dir.join("src")).unwrap();
+        File::create(source_dir.join("node_modules").join("pkg").join("index.js")).unwrap();
+        File::create(source_dir.join("src").join("main.rs")).unwrap();
+        File::create(source_dir.join("src").join("cache.tmp")).unwrap();
+        File::create(source_dir.join("README.md")).unwrap();
+    }
+
+    fn exclude_options() -> CopyOptions {
+        CopyOptions {
+            exclude_patterns: GlobPatterns::new(vec![
+                "node_modules".to_string(),
+                "*.tmp".to_string(),
+            ])
+            .unwrap(),
+            ..CopyOptions::default()
+        }
+    }
+
+    #[test]
+    fn copy_all_data_under_the_directory_with_hash_verification_and_progress_skips_excluded_entries(
+    ) {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+        fs::create_dir(&dest_dir).unwrap();
+        create_tree_with_excluded_entries(&source_dir);
+        let options = exclude_options();
+
+        // ===== Act =====
+        let result =
+            FileSystem::copy_all_data_under_the_directory_with_hash_verification_and_progress(
+                &source_dir,
+                &dest_dir,
+                &options,
+                |_, _| {},
+            );
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert!(!dest_dir.join("node_modules").exists());
+        assert!(!dest_dir.join("src").join("cache.tmp").exists());
+        assert!(dest_dir.join("src").join("main.rs").exists());
+        assert!(dest_dir.join("README.md").exists());
+        assert!(FileSystem::verify_directory_contents_match_with_options(
+            &dest_dir,
+            &source_dir,
+            &options
+        )
+        .unwrap());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn copy_all_data_under_the_directory_with_hash_verification_preserves_hard_links() {
+        use std::os::unix::fs::MetadataExt;
+
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+        fs::create_dir_all(source_dir.join("sub")).unwrap();
+        fs::create_dir(&dest_dir).unwrap();
+        fs::write(source_dir.join("original.txt"), "shared content").unwrap();
+        fs::hard_link(
+            source_dir.join("original.txt"),
+            source_dir.join("sub").join("link.txt"),
+        )
+        .unwrap();
+
+        // ===== Act =====
+        let result = FileSystem::copy_all_data_under_the_directory_with_hash_verification(
+            &source_dir,
+            &dest_dir,
+        );
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        let original = fs::metadata(dest_dir.join("original.txt")).unwrap();
+        let link = fs::metadata(dest_dir.join("sub").join("link.txt")).unwrap();
+        assert_eq!(original.ino(), link.ino());
+        assert_eq!(original.nlink(), 2);
+        assert_eq!(
+            fs::read_to_string(dest_dir.join("sub").join("link.txt")).unwrap(),
+            "shared content"
+        );
+    }
+
+    #[test]
+    fn copy_all_data_under_the_directory_with_hash_verification_and_progress_stops_at_deadline() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+        fs::create_dir_all(&source_dir).unwrap();
+        fs::create_dir(&dest_dir).unwrap();
+        for i in 0..5 {
+            fs::write(source_dir.join(format!("file{}.txt", i)), "content").unwrap();
+        }
+        let options = CopyOptions {
+            deadline: Some(Deadline::start(Duration::from_millis(50))),
+            ..CopyOptions::default()
+        };
+        let mut copied = 0;
+
+        // ===== Act =====
+        // 1ファイルごとに待機して、遅いコピーを再現する
+        let result =
+            FileSystem::copy_all_data_under_the_directory_with_hash_verification_and_progress(
+                &source_dir,
+                &dest_dir,
+                &options,
+                |_, _| {
+                    copied += 1;
+                    std::thread::sleep(Duration::from_millis(40));
+                },
+            );
+
+        // ===== Assert =====
+        assert!(matches!(result, Err(AppError::Timeout(_))));
+        assert!((1..5).contains(&copied));
+    }
+
+    fn create_three_level_tree(source_dir: &Path) {
+        fs::create_dir_all(source_dir.join("a").join("b")).unwrap();
+        fs::write(source_dir.join("level0.txt"), "0").unwrap();
+        fs::write(source_dir.join("a").join("level1.txt"), "1").unwrap();
+        fs::write(source_dir.join("a").join("b").join("level2.txt"), "2").unwrap();
+    }
+
+    #[test]
+    fn copy_all_data_under_the_directory_with_hash_verification_and_progress_respects_max_depth() {
+        let cases = [
+            (Some(0), [true, false, false]),
+            (Some(1), [true, true, false]),
+            (None, [true, true, true]),
+        ];
+        for (max_depth, expected) in cases {
+            // ===== Arrange =====
+            let temp_dir = TempDir::new().unwrap();
+            let source_dir = temp_dir.path().join("source");
+            let dest_dir = temp_dir.path().join("dest");
+            create_three_level_tree(&source_dir);
+            fs::create_dir(&dest_dir).unwrap();
+            let options = CopyOptions {
+                max_depth,
+                ..CopyOptions::default()
+            };
+
+            // ===== Act =====
+            let result =
+                FileSystem::copy_all_data_under_the_directory_with_hash_verification_and_progress(
+                    &source_dir,
+                    &dest_dir,
+                    &options,
+                    |_, _| {},
+                );
+
+            // ===== Assert =====
+            assert!(result.is_ok());
+            let copied = [
+                dest_dir.join("level0.txt").exists(),
+                dest_dir.join("a").join("level1.txt").exists(),
+                dest_dir.join("a").join("b").join("level2.txt").exists(),
+            ];
+            assert_eq!(copied, expected, "max_depth: {:?}", max_depth);
+            assert!(FileSystem::verify_directory_contents_match_with_options(
+                &source_dir,
+                &dest_dir,
+                &options
+            )
+            .unwrap());
+        }
+    }
+
+    #[test]
+    fn copy_all_data_under_the_directory_with_hash_verification_and_progress_skips_existing_matching_files(
+    ) {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+        create_three_level_tree(&source_dir);
+        fs::create_dir_all(dest_dir.join("a")).unwrap();
+        fs::write(dest_dir.join("level0.txt"), "0").unwrap();
+        fs::write(dest_dir.join("a").join("level1.txt"), "stale").unwrap();
+        let options = CopyOptions {
+            skip_existing_matching: true,
+            ..CopyOptions::default()
+        };
+        let mut copied = Vec::new();
+
+        // ===== Act =====
+        let result =
+            FileSystem::copy_all_data_under_the_directory_with_hash_verification_and_progress(
+                &source_dir,
+                &dest_dir,
+                &options,
+                |path, _| copied.push(path.to_path_buf()),
+            );
+
+        // ===== Assert =====
+        let summary = result.unwrap();
+        assert_eq!(summary.files_skipped, 1);
+        assert_eq!(summary.files_copied, 2);
+        assert!(!copied.contains(&PathBuf::from("level0.txt")));
+        assert_eq!(
+            fs::read_to_string(dest_dir.join("a").join("level1.txt")).unwrap(),
+            "1"
+        );
+        assert!(FileSystem::verify_directory_contents_match(&source_dir, &dest_dir).unwrap());
+    }
+
+    #[test]
+    fn copy_all_data_under_the_directory_with_hash_verification_and_progress_overwrites_readonly_mismatching_file(
+    ) {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+        create_three_level_tree(&source_dir);
+        fs::create_dir_all(&dest_dir).unwrap();
+        let stale_file = dest_dir.join("level0.txt");
+        fs::write(&stale_file, "stale").unwrap();
+        let mut permissions = fs::metadata(&stale_file).unwrap().permissions();
+        permissions.set_readonly(true);
+        fs::set_permissions(&stale_file, permissions).unwrap();
+        let options = CopyOptions {
+            skip_existing_matching: true,
+            ..CopyOptions::default()
+        };
+
+        // ===== Act =====
+        let result =
+            FileSystem::copy_all_data_under_the_directory_with_hash_verification_and_progress(
+                &source_dir,
+                &dest_dir,
+                &options,
+                |_, _| {},
+            );
+
+        // ===== Assert =====
+        let summary = result.unwrap();
+        assert_eq!(summary.files_skipped, 0);
+        assert_eq!(fs::read_to_string(&stale_file).unwrap(), "0");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn copy_all_data_under_the_directory_with_hash_verification_and_progress_preserves_xattrs() {
+        // ===== Arrange =====
+        let source_dir = TempDir::new().unwrap();
+        let dest_dir = TempDir::new().unwrap();
+        let source_file = source_dir.path().join("tagged.txt");
+        fs::write(&source_file, "content").unwrap();
+        let name = "user.srow.test";
+        if let Err(e) = xattr::set(&source_file, name, b"value") {
+            // tmpfs の古いカーネルなど、ユーザー属性に対応していない環境では確認できない
+            eprintln!("拡張属性を設定できないためスキップします: {}", e);
+            return;
+        }
+        let options = CopyOptions {
+            preserve_xattrs: true,
+            ..CopyOptions::default()
+        };
+
+        // ===== Act =====
+        let result =
+            FileSystem::copy_all_data_under_the_directory_with_hash_verification_and_progress(
+                source_dir.path(),
+                dest_dir.path(),
+                &options,
+                |_, _| {},
+            );
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert_eq!(
+            xattr::get(dest_dir.path().join("tagged.txt"), name).unwrap(),
+            Some(b"value".to_vec())
+        );
+    }
+
+    #[test]
+    fn clear_directory_contents_with_options_keeps_entries_beyond_max_depth() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        create_three_level_tree(&source_dir);
+        let options = CopyOptions {
+            max_depth: Some(1),
+            ..CopyOptions::default()
+        };
+
+        // ===== Act =====
+        let result = FileSystem::clear_directory_contents_with_options(&source_dir, &options);
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert!(!source_dir.join("level0.txt").exists());
+        assert!(!source_dir.join("a").join("level1.txt").exists());
+        assert!(source_dir.join("a").join("b").join("level2.txt").exists());
+    }
+
+    #[test]
+    fn clear_directory_contents_with_options_keeps_excluded_entries() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        create_tree_with_excluded_entries(&source_dir);
+
+        // ===== Act =====
+        let result =
+            FileSystem::clear_directory_contents_with_options(&source_dir, &exclude_options());
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert!(source_dir
+            .join("node_modules")
+            .join("pkg")
+            .join("index.js")
+            .exists());
+        assert!(source_dir.join("src").join("cache.tmp").exists());
+        assert!(!source_dir.join("src").join("main.rs").exists());
+        assert!(!source_dir.join("README.md").exists());
+    }
+
+    #[test]
+    fn copy_all_data_under_the_directory_with_hash_verification_and_progress_copies_only_modified_files(
+    ) {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+        fs::create_dir_all(&source_dir).unwrap();
+        fs::create_dir(&dest_dir).unwrap();
+        let since = std::time::UNIX_EPOCH + std::time::Duration::from_secs(2_000_000);
+        for (name, secs) in [("old.txt", 1_000_000), ("new.txt", 3_000_000)] {
+            let path = source_dir.join(name);
+            fs::write(&path, name).unwrap();
+            filetime::set_file_mtime(&path, FileTime::from_unix_time(secs, 0)).unwrap();
+        }
+        let options = CopyOptions {
+            modified_since: Some(since),
+            ..CopyOptions::default()
+        };
+
+        // ===== Act =====
+        let result =
+            FileSystem::copy_all_data_under_the_directory_with_hash_verification_and_progress(
+                &source_dir,
+                &dest_dir,
+                &options,
+                |_, _| {},
+            );
+        FileSystem::clear_directory_contents_with_options(&source_dir, &options).unwrap();
+
+        // ===== Assert =====
+        assert_eq!(result.unwrap().files_copied, 1);
+        assert!(dest_dir.join("new.txt").exists());
+        assert!(!dest_dir.join("old.txt").exists());
+        assert!(source_dir.join("old.txt").exists());
+        assert!(!source_dir.join("new.txt").exists());
+    }
+
+    #[test]
+    fn copy_all_data_under_the_directory_with_hash_verification_and_progress_copies_only_included_files(
+    ) {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+        fs::create_dir(&dest_dir).unwrap();
+        fs::create_dir_all(source_dir.join("DCIM")).unwrap();
+        File::create(source_dir.join("DCIM").join("photo.jpg")).unwrap();
+        File::create(source_dir.join("DCIM").join("photo.raw")).unwrap();
+        File::create(source_dir.join("DCIM").join("photo.xmp")).unwrap();
+        File::create(source_dir.join("notes.txt")).unwrap();
+        let options = CopyOptions {
+            include_patterns: GlobPatterns::new(vec!["*.jpg".to_string(), "*.raw".to_string()])
+                .unwrap(),
+            ..CopyOptions::default()
+        };
+
+        // ===== Act =====
+        let result =
+            FileSystem::copy_all_data_under_the_directory_with_hash_verification_and_progress(
+                &source_dir,
+                &dest_dir,
+                &options,
+                |_, _| {},
+            );
+        let verified = FileSystem::verify_directory_contents_match_with_options(
+            &dest_dir,
+            &source_dir,
+            &options,
+        );
+        FileSystem::clear_directory_contents_with_options(&source_dir, &options).unwrap();
+
+        // ===== Assert =====
+        assert_eq!(result.unwrap().files_copied, 2);
+        assert!(verified.unwrap());
+        assert!(dest_dir.join("DCIM").join("photo.jpg").exists());
+        assert!(dest_dir.join("DCIM").join("photo.raw").exists());
+        assert!(!dest_dir.join("DCIM").join("photo.xmp").exists());
+        assert!(!dest_dir.join("notes.txt").exists());
+        assert!(source_dir.join("DCIM").join("photo.xmp").exists());
+        assert!(source_dir.join("notes.txt").exists());
+        assert!(!source_dir.join("DCIM").join("photo.jpg").exists());
+    }
+
+    #[test]
+    fn copy_all_data_under_the_directory_parallel_copies_large_generated_tree() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+
+        fs::create_dir(&source_dir).unwrap();
+        fs::create_dir(&dest_dir).unwrap();
+
+        for i in 0..20 {
+            let subdir = source_dir.join(format!("dir{}", i)).join("nested");
+            fs::create_dir_all(&subdir).unwrap();
+            for j in 0..50 {
+                File::create(subdir.join(format!("file{}.txt", j)))
+                    .unwrap()
+                    .write_all(format!("content {} {}", i, j).as_bytes())
+                    .unwrap();
+            }
+        }
+
+        // ===== Act =====
+        let result = FileSystem::copy_all_data_under_the_directory_parallel(
+            &source_dir,
+            &dest_dir,
+            &CopyOptions::default(),
+        );
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        for i in 0..20 {
+            let subdir = dest_dir.join(format!("dir{}", i)).join("nested");
+            for j in 0..50 {
+                let content = fs::read_to_string(subdir.join(format!("file{}.txt", j))).unwrap();
+                assert_eq!(content, format!("content {} {}", i, j));
+            }
+        }
+    }
+
+    #[test]
+    fn copy_with_progress_copies_in_parallel_when_enabled() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+        fs::create_dir_all(source_dir.join("nested")).unwrap();
+        fs::create_dir(&dest_dir).unwrap();
+        for i in 0..10 {
+            fs::write(
+                source_dir.join("nested").join(format!("file{}.txt", i)),
+                "12345",
+            )
+            .unwrap();
+        }
+        let options = CopyOptions {
+            parallel: true,
+            ..CopyOptions::default()
+        };
+        let mut reported = Vec::new();
+
+        // ===== Act =====
+        let result =
+            FileSystem::copy_all_data_under_the_directory_with_hash_verification_and_progress(
+                &source_dir,
+                &dest_dir,
+                &options,
+                |path, bytes| reported.push((path.to_path_buf(), bytes)),
+            );
+
+        // ===== Assert =====
+        let summary = result.unwrap();
+        assert_eq!(summary.files_copied, 10);
+        assert_eq!(summary.directories_created, 1);
+        reported.sort();
+        let expected: Vec<(PathBuf, u64)> = (0..10)
+            .map(|i| (Path::new("nested").join(format!("file{}.txt", i)), 5))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        assert_eq!(reported, expected);
+        assert!(FileSystem::verify_directory_contents_match(&source_dir, &dest_dir).unwrap());
+    }
+
+    #[test]
+    fn copy_all_data_under_the_directory_parallel_returns_error_when_source_directory_does_not_exist(
+    ) {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let non_existent_source = temp_dir.path().join("non_existent");
+        let dest_dir = temp_dir.path().join("dest");
+
+        fs::create_dir(&dest_dir).unwrap();
+
+        // ===== Act =====
+        let result = FileSystem::copy_all_data_under_the_directory_parallel(
+            &non_existent_source,
+            &dest_dir,
+            &CopyOptions::default(),
+        );
+
+        // ===== Assert =====
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn copy_all_data_under_the_directory_with_hash_verification_and_progress_supports_blake3() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+
+        fs::create_dir(&source_dir).unwrap();
+        fs::create_dir(&dest_dir).unwrap();
+
+        File::create(source_dir.join("file.txt"))
+            .unwrap()
+            .write_all(b"content")
+            .unwrap();
+        let options = CopyOptions {
+            hash_algorithm: HashAlgorithm::Blake3,
+            ..CopyOptions::default()
+        };
+
+        // ===== Act =====
+        let result =
+            FileSystem::copy_all_data_under_the_directory_with_hash_verification_and_progress(
+                &source_dir,
+                &dest_dir,
+                &options,
+                |_, _| {},
+            );
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert_eq!(
+            fs::read_to_string(dest_dir.join("file.txt")).unwrap(),
+            "content"
+        );
+    }
+
+    #[test]
+    fn copy_all_data_under_the_directory_with_hash_verification_preserves_modification_time() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+
+        fs::create_dir(&source_dir).unwrap();
+        fs::create_dir(&dest_dir).unwrap();
+
+        let source_file = source_dir.join("file.txt");
+        File::create(&source_file)
+            .unwrap()
+            .write_all(b"content")
+            .unwrap();
+        let modified = FileTime::from_unix_time(1_700_000_000, 0);
+        filetime::set_file_mtime(&source_file, modified).unwrap();
+
+        // ===== Act =====
+        let result = FileSystem::copy_all_data_under_the_directory_with_hash_verification(
+            &source_dir,
+            &dest_dir,
+        );
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        let dest_metadata = fs::metadata(dest_dir.join("file.txt")).unwrap();
+        assert_eq!(
+            FileTime::from_last_modification_time(&dest_metadata),
+            modified
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn copy_all_data_under_the_directory_with_hash_verification_reports_path_of_unreadable_file() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // ===== Arrange =====
+        let source_dir = TempDir::new().unwrap();
+        let dest_dir = TempDir::new().unwrap();
+        let unreadable = source_dir.path().join("secret.txt");
+        fs::write(&unreadable, "secret").unwrap();
+        fs::set_permissions(&unreadable, Permissions::from_mode(0o000)).unwrap();
+        // root など権限を無視して読み込める環境では検証できない
+        if File::open(&unreadable).is_ok() {
+            return;
+        }
+
+        // ===== Act =====
+        let result = FileSystem::copy_all_data_under_the_directory_with_hash_verification(
+            source_dir.path(),
+            dest_dir.path(),
+        );
+
+        // ===== Assert =====
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains(&unreadable.display().to_string()));
+        assert!(message.contains("オープン"));
+    }
+
+    #[test]
+    fn copy_all_data_under_the_directory_with_hash_verification_returns_error_when_source_directory_does_not_exist(
+    ) {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let non_existent_source = temp_dir.path().join("non_existent");
         let dest_dir = temp_dir.path().join("dest");
 
         fs::create_dir(&dest_dir).unwrap();
@@ -183,6 +1812,56 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn calculate_directory_size_sums_file_sizes_excluding_excluded_entries() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().join("dir");
+        fs::create_dir_all(dir.join("subdir")).unwrap();
+        fs::write(dir.join("file1.txt"), "12345").unwrap();
+        fs::write(dir.join("subdir").join("file2.txt"), "123").unwrap();
+        fs::write(dir.join("skipped.tmp"), "1234567890").unwrap();
+        let options = CopyOptions {
+            exclude_patterns: GlobPatterns::new(vec!["*.tmp".to_string()]).unwrap(),
+            ..CopyOptions::default()
+        };
+
+        // ===== Act =====
+        let result = FileSystem::calculate_directory_size(&dir, &options);
+
+        // ===== Assert =====
+        assert_eq!(result.unwrap(), 8);
+    }
+
+    #[test]
+    fn count_entries_counts_files_and_nested_directories() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join("a").join("b").join("c")).unwrap();
+        fs::create_dir(temp_dir.path().join("empty")).unwrap();
+        for file in ["root.txt", "a/one.txt", "a/b/two.txt", "a/b/c/three.txt"] {
+            fs::write(temp_dir.path().join(file), file).unwrap();
+        }
+
+        // ===== Act =====
+        let result = FileSystem::count_entries(temp_dir.path(), &CopyOptions::default());
+
+        // ===== Assert =====
+        assert_eq!(result.unwrap(), (4, 4));
+    }
+
+    #[test]
+    fn available_space_returns_space_for_existing_directory() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+
+        // ===== Act =====
+        let result = FileSystem::available_space(temp_dir.path());
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+    }
+
     #[test]
     fn is_path_readonly_returns_true_for_readonly_file() {
         // ===== Arrange =====
@@ -195,26 +1874,51 @@ mod tests {
         fs::set_permissions(&test_file, perms).unwrap();
 
         // ===== Act =====
-        let result = FileSystem::is_path_readonly(&test_file);
+        let result = FileSystem::is_path_readonly(&test_file);
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert!(result.unwrap());
+    }
+
+    #[test]
+    fn is_path_readonly_returns_false_for_writable_file() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("writable.txt");
+        File::create(&test_file).unwrap();
+
+        // ===== Act =====
+        let result = FileSystem::is_path_readonly(&test_file);
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert!(!result.unwrap());
+    }
+
+    #[test]
+    fn is_directory_writable_leaves_no_probe_file() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+
+        // ===== Act =====
+        let result = FileSystem::is_directory_writable(temp_dir.path());
 
         // ===== Assert =====
-        assert!(result.is_ok());
-        assert!(result.unwrap());
+        assert!(result);
+        assert!(FileSystem::is_directory_empty(temp_dir.path()).unwrap());
     }
 
     #[test]
-    fn is_path_readonly_returns_false_for_writable_file() {
+    fn is_directory_writable_returns_false_for_missing_directory() {
         // ===== Arrange =====
         let temp_dir = TempDir::new().unwrap();
-        let test_file = temp_dir.path().join("writable.txt");
-        File::create(&test_file).unwrap();
 
         // ===== Act =====
-        let result = FileSystem::is_path_readonly(&test_file);
+        let result = FileSystem::is_directory_writable(&temp_dir.path().join("missing"));
 
         // ===== Assert =====
-        assert!(result.is_ok());
-        assert!(!result.unwrap());
+        assert!(!result);
     }
 
     #[test]
@@ -311,6 +2015,163 @@ mod tests {
         assert!(!result.unwrap());
     }
 
+    #[test]
+    fn find_directory_differences_reports_missing_and_modified_files() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+        fs::create_dir_all(source_dir.join("subdir")).unwrap();
+        fs::create_dir_all(dest_dir.join("subdir")).unwrap();
+        fs::write(source_dir.join("same.txt"), "same").unwrap();
+        fs::write(dest_dir.join("same.txt"), "same").unwrap();
+        fs::write(source_dir.join("subdir").join("changed.txt"), "before").unwrap();
+        fs::write(dest_dir.join("subdir").join("changed.txt"), "after!").unwrap();
+        fs::write(source_dir.join("only_source.txt"), "source").unwrap();
+        fs::write(dest_dir.join("only_dest.txt"), "dest").unwrap();
+
+        // ===== Act =====
+        let result =
+            FileSystem::find_directory_differences(&source_dir, &dest_dir, &CopyOptions::default());
+
+        // ===== Assert =====
+        assert_eq!(
+            result.unwrap(),
+            vec![
+                DirectoryDifference::MissingInSource(PathBuf::from("only_dest.txt")),
+                DirectoryDifference::MissingInDestination(PathBuf::from("only_source.txt")),
+                DirectoryDifference::ContentMismatch(Path::new("subdir").join("changed.txt")),
+            ]
+        );
+    }
+
+    #[test]
+    fn find_directory_differences_compares_names_by_case_sensitivity() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+        fs::create_dir_all(source_dir.join("Docs")).unwrap();
+        fs::create_dir_all(dest_dir.join("docs")).unwrap();
+        fs::write(source_dir.join("Docs").join("File.TXT"), "content").unwrap();
+        fs::write(dest_dir.join("docs").join("file.txt"), "content").unwrap();
+        let options = |case_sensitivity| CopyOptions {
+            case_sensitivity,
+            ..CopyOptions::default()
+        };
+
+        // ===== Act =====
+        let sensitive = FileSystem::find_directory_differences(
+            &source_dir,
+            &dest_dir,
+            &options(CaseSensitivity::Sensitive),
+        );
+        let insensitive = FileSystem::find_directory_differences(
+            &source_dir,
+            &dest_dir,
+            &options(CaseSensitivity::Insensitive),
+        );
+
+        // ===== Assert =====
+        assert!(sensitive
+            .unwrap()
+            .contains(&DirectoryDifference::MissingInDestination(
+                Path::new("Docs").join("File.TXT")
+            )));
+        assert!(insensitive.unwrap().is_empty());
+    }
+
+    #[test]
+    fn verify_directory_contents_match_with_options_compares_files_by_verification_mode() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let dir1 = temp_dir.path().join("dir1");
+        let dir2 = temp_dir.path().join("dir2");
+        fs::create_dir(&dir1).unwrap();
+        fs::create_dir(&dir2).unwrap();
+        // サイズは同じだが内容が異なる
+        fs::write(dir1.join("file.txt"), "aaaa").unwrap();
+        fs::write(dir2.join("file.txt"), "bbbb").unwrap();
+        let options = |verification_mode| CopyOptions {
+            verification_mode,
+            ..CopyOptions::default()
+        };
+
+        // ===== Act =====
+        let size_only = FileSystem::verify_directory_contents_match_with_options(
+            &dir1,
+            &dir2,
+            &options(VerificationMode::SizeOnly),
+        );
+        let hash = FileSystem::verify_directory_contents_match_with_options(
+            &dir1,
+            &dir2,
+            &options(VerificationMode::Hash),
+        );
+
+        // ===== Assert =====
+        assert!(size_only.unwrap());
+        assert!(!hash.unwrap());
+    }
+
+    #[test]
+    fn tree_hash_matches_for_identical_trees_and_changes_with_a_single_byte() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let dir_1 = temp_dir.path().join("dir_1");
+        let dir_2 = temp_dir.path().join("dir_2");
+        for dir in [&dir_1, &dir_2] {
+            create_three_level_tree(dir);
+        }
+
+        // ===== Act =====
+        let root_1 = FileSystem::tree_hash(&dir_1).unwrap();
+        let root_2 = FileSystem::tree_hash(&dir_2).unwrap();
+        fs::write(dir_2.join("a").join("b").join("level2.txt"), "3").unwrap();
+        let modified_root = FileSystem::tree_hash(&dir_2).unwrap();
+
+        // ===== Assert =====
+        assert_eq!(root_1, root_2);
+        assert_ne!(root_1, modified_root);
+    }
+
+    #[test]
+    fn verify_checksum_file_detects_modified_and_missing_files() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir(temp_dir.path().join("sub")).unwrap();
+        fs::write(temp_dir.path().join("root.txt"), "root").unwrap();
+        fs::write(temp_dir.path().join("sub").join("nested.txt"), "nested").unwrap();
+        fs::write(temp_dir.path().join("removed.txt"), "removed").unwrap();
+        let checksum_path =
+            FileSystem::write_checksum_file(temp_dir.path(), &CopyOptions::default()).unwrap();
+        let unchanged = FileSystem::verify_checksum_file(temp_dir.path()).unwrap();
+        fs::write(temp_dir.path().join("sub").join("nested.txt"), "modified").unwrap();
+        fs::remove_file(temp_dir.path().join("removed.txt")).unwrap();
+
+        // ===== Act =====
+        let result = FileSystem::verify_checksum_file(temp_dir.path());
+
+        // ===== Assert =====
+        assert!(unchanged.is_empty());
+        let content = fs::read_to_string(checksum_path).unwrap();
+        assert!(content.contains(&format!(
+            "{}  root.txt\n",
+            HashAlgorithm::Sha256
+                .calculate_hash_from_file_content(&temp_dir.path().join("root.txt"))
+                .unwrap()
+        )));
+        assert!(content.contains("  sub/nested.txt\n"));
+        assert!(!content.contains(CHECKSUM_FILE_NAME));
+        assert_eq!(
+            result.unwrap(),
+            vec![
+                DirectoryDifference::MissingInDestination(PathBuf::from("removed.txt")),
+                DirectoryDifference::ContentMismatch(Path::new("sub").join("nested.txt")),
+            ]
+        );
+    }
+
     #[test]
     fn calculate_hash_from_file_content_returns_consistent_hash_for_same_content() {
         // ===== Arrange =====
@@ -322,8 +2183,10 @@ mod tests {
             .unwrap();
 
         // ===== Act =====
-        let hash1 = FileSystem::calculate_hash_from_file_content(&test_file);
-        let hash2 = FileSystem::calculate_hash_from_file_content(&test_file);
+        let hash1 =
+            FileSystem::calculate_hash_from_file_content(&test_file, &CopyOptions::default());
+        let hash2 =
+            FileSystem::calculate_hash_from_file_content(&test_file, &CopyOptions::default());
 
         // ===== Assert =====
         assert!(hash1.is_ok());
@@ -348,8 +2211,8 @@ mod tests {
             .unwrap();
 
         // ===== Act =====
-        let hash1 = FileSystem::calculate_hash_from_file_content(&file1);
-        let hash2 = FileSystem::calculate_hash_from_file_content(&file2);
+        let hash1 = FileSystem::calculate_hash_from_file_content(&file1, &CopyOptions::default());
+        let hash2 = FileSystem::calculate_hash_from_file_content(&file2, &CopyOptions::default());
 
         // ===== Assert =====
         assert!(hash1.is_ok());
@@ -419,4 +2282,435 @@ mod tests {
         assert!(result.is_ok());
         assert!(FileSystem::is_directory_empty(&empty_dir).unwrap());
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn try_atomic_move_renames_entries_on_same_filesystem() {
+        use std::os::unix::fs::MetadataExt;
+
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+        fs::create_dir_all(source_dir.join("subdir")).unwrap();
+        fs::create_dir(&dest_dir).unwrap();
+        fs::write(source_dir.join("file1.txt"), "content1").unwrap();
+        fs::write(source_dir.join("subdir").join("file2.txt"), "content2").unwrap();
+        let inode = fs::metadata(source_dir.join("file1.txt")).unwrap().ino();
+
+        // ===== Act =====
+        let result = FileSystem::try_atomic_move(&source_dir, &dest_dir, &CopyOptions::default());
+
+        // ===== Assert =====
+        let summary = result.unwrap().unwrap();
+        assert_eq!(summary.files_copied, 2);
+        assert_eq!(summary.directories_created, 1);
+        assert!(FileSystem::is_directory_empty(&source_dir).unwrap());
+        // rename であればinodeは変わらない
+        assert_eq!(
+            fs::metadata(dest_dir.join("file1.txt")).unwrap().ino(),
+            inode
+        );
+        assert_eq!(
+            fs::read_to_string(dest_dir.join("subdir").join("file2.txt")).unwrap(),
+            "content2"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn try_atomic_move_with_progress_reports_every_moved_file() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+        fs::create_dir_all(source_dir.join("subdir")).unwrap();
+        fs::create_dir(&dest_dir).unwrap();
+        fs::write(source_dir.join("file1.txt"), "content1").unwrap();
+        fs::write(source_dir.join("subdir").join("file2.txt"), "data").unwrap();
+        let mut reported = Vec::new();
+
+        // ===== Act =====
+        let result = FileSystem::try_atomic_move_with_progress(
+            &source_dir,
+            &dest_dir,
+            &CopyOptions::default(),
+            |path, bytes| reported.push((path.to_path_buf(), bytes)),
+        );
+
+        // ===== Assert =====
+        let summary = result.unwrap().unwrap();
+        reported.sort();
+        assert_eq!(
+            reported,
+            vec![
+                (PathBuf::from("file1.txt"), 8),
+                (Path::new("subdir").join("file2.txt"), 4),
+            ]
+        );
+        assert_eq!(reported.len() as u64, summary.files_copied);
+        assert_eq!(
+            reported.iter().map(|(_, bytes)| bytes).sum::<u64>(),
+            summary.total_bytes
+        );
+    }
+
+    #[test]
+    fn try_atomic_move_skips_rename_when_entries_are_excluded() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+        fs::create_dir(&source_dir).unwrap();
+        fs::create_dir(&dest_dir).unwrap();
+        fs::write(source_dir.join("file1.txt"), "content1").unwrap();
+        let options = CopyOptions {
+            exclude_patterns: GlobPatterns::new(vec!["*.tmp".to_string()]).unwrap(),
+            ..CopyOptions::default()
+        };
+
+        // ===== Act =====
+        let result = FileSystem::try_atomic_move(&source_dir, &dest_dir, &options);
+
+        // ===== Assert =====
+        assert!(result.unwrap().is_none());
+        assert!(source_dir.join("file1.txt").exists());
+        assert!(FileSystem::is_directory_empty(&dest_dir).unwrap());
+    }
+
+    #[test]
+    fn try_atomic_move_does_not_fail_for_readonly_source() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+        fs::create_dir(&source_dir).unwrap();
+        fs::create_dir(&dest_dir).unwrap();
+        fs::write(source_dir.join("file1.txt"), "content1").unwrap();
+        let mut perms = fs::metadata(&source_dir).unwrap().permissions();
+        perms.set_readonly(true);
+        fs::set_permissions(&source_dir, perms.clone()).unwrap();
+
+        // ===== Act =====
+        let result = FileSystem::try_atomic_move(&source_dir, &dest_dir, &CopyOptions::default());
+
+        // ===== Assert =====
+        #[allow(clippy::permissions_set_readonly_false)]
+        perms.set_readonly(false);
+        fs::set_permissions(&source_dir, perms).unwrap();
+        // 権限を無視できる root ではリネームされ、それ以外ではコピーによる移動に切り替わる
+        match result.unwrap() {
+            Some(_) => assert!(dest_dir.join("file1.txt").exists()),
+            None => {
+                assert!(source_dir.join("file1.txt").exists());
+                assert!(FileSystem::is_directory_empty(&dest_dir).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn try_atomic_move_treats_denied_rename_as_not_applicable() {
+        // ===== Arrange =====
+        let cases = [
+            (ErrorKind::PermissionDenied, true),
+            (ErrorKind::CrossesDevices, true),
+            (ErrorKind::ReadOnlyFilesystem, true),
+            (ErrorKind::NotFound, false),
+        ];
+
+        for (kind, expected) in cases {
+            // ===== Act =====
+            let result = FileSystem::is_rename_not_applicable(&io::Error::from(kind));
+
+            // ===== Assert =====
+            assert_eq!(result, expected, "{:?}", kind);
+        }
+    }
+
+    #[test]
+    fn try_atomic_move_skips_rename_when_destination_is_not_empty() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+        fs::create_dir(&source_dir).unwrap();
+        fs::create_dir(&dest_dir).unwrap();
+        fs::write(source_dir.join("file1.txt"), "content1").unwrap();
+        fs::write(dest_dir.join("existing.txt"), "existing").unwrap();
+
+        // ===== Act =====
+        let result = FileSystem::try_atomic_move(&source_dir, &dest_dir, &CopyOptions::default());
+
+        // ===== Assert =====
+        assert!(result.unwrap().is_none());
+        assert!(source_dir.join("file1.txt").exists());
+    }
+
+    #[test]
+    fn copy_all_data_under_the_directory_with_hash_verification_and_progress_returns_summary() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+        fs::create_dir_all(source_dir.join("a").join("b")).unwrap();
+        fs::create_dir_all(source_dir.join("c")).unwrap();
+        fs::create_dir(&dest_dir).unwrap();
+        fs::write(source_dir.join("root.txt"), "12345").unwrap();
+        fs::write(source_dir.join("a").join("a.txt"), "123").unwrap();
+        fs::write(source_dir.join("a").join("b").join("b.txt"), "12").unwrap();
+
+        // ===== Act =====
+        let result =
+            FileSystem::copy_all_data_under_the_directory_with_hash_verification_and_progress(
+                &source_dir,
+                &dest_dir,
+                &CopyOptions::default(),
+                |_, _| {},
+            );
+
+        // ===== Assert =====
+        assert_eq!(
+            result.unwrap(),
+            TransferSummary {
+                files_copied: 3,
+                directories_created: 3,
+                total_bytes: 10,
+                files_skipped: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn copy_all_data_under_the_directory_with_hash_verification_copies_multi_megabyte_file() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let dest_dir = temp_dir.path().join("dest");
+        fs::create_dir(&source_dir).unwrap();
+        fs::create_dir(&dest_dir).unwrap();
+        // バッファサイズの境界をまたぐよう、半端なサイズの非一様なデータを用意する
+        let content: Vec<u8> = (0..(5 * 1024 * 1024 + 123))
+            .map(|i: u32| (i.wrapping_mul(2654435761) >> 24) as u8)
+            .collect();
+        fs::write(source_dir.join("large.bin"), &content).unwrap();
+
+        // ===== Act =====
+        let result =
+            FileSystem::copy_all_data_under_the_directory_with_hash_verification_and_progress(
+                &source_dir,
+                &dest_dir,
+                &CopyOptions::default(),
+                |_, _| {},
+            );
+
+        // ===== Assert =====
+        assert_eq!(result.unwrap().total_bytes, content.len() as u64);
+        assert_eq!(fs::read(dest_dir.join("large.bin")).unwrap(), content);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn copy_all_data_under_the_directory_with_hash_verification_respects_bandwidth_limit() {
+        // ===== Arrange =====
+        let source_dir = TempDir::new().unwrap();
+        let dest_dir = TempDir::new().unwrap();
+        for name in ["a.bin", "b.bin", "c.bin"] {
+            fs::write(source_dir.path().join(name), vec![0u8; 1000]).unwrap();
+        }
+        // 最初の 1 秒分（2000 バイト）は待たずに書き込めるため、残りの 1000 バイトで 0.5 秒かかる
+        let options = CopyOptions {
+            rate_limiter: Some(RateLimiter::new(BandwidthLimit::new(2000).unwrap())),
+            ..CopyOptions::default()
+        };
+
+        // ===== Act =====
+        let started = std::time::Instant::now();
+        let result =
+            FileSystem::copy_all_data_under_the_directory_with_hash_verification_and_progress(
+                source_dir.path(),
+                dest_dir.path(),
+                &options,
+                |_, _| {},
+            );
+        let elapsed = started.elapsed();
+
+        // ===== Assert =====
+        assert_eq!(result.unwrap().total_bytes, 3000);
+        assert!(elapsed >= Duration::from_millis(450), "{:?}", elapsed);
+        assert!(elapsed < Duration::from_secs(3), "{:?}", elapsed);
+    }
+
+    #[test]
+    fn copy_all_data_under_the_directory_with_hash_verification_verifies_bytes_as_read() {
+        // ===== Arrange =====
+        let source_dir = TempDir::new().unwrap();
+        let dest_dir = TempDir::new().unwrap();
+        let source_file = source_dir.path().join("growing.log");
+        fs::write(&source_file, vec![b'a'; 4000]).unwrap();
+        // 1000 バイトずつ読み込み、最初の 2000 バイトより後は書き込みを待たせてコピーに 1 秒ほどかける
+        let options = CopyOptions {
+            buffer_size: BufferSize::new(1000).unwrap(),
+            rate_limiter: Some(RateLimiter::new(BandwidthLimit::new(2000).unwrap())),
+            ..CopyOptions::default()
+        };
+        // コピーの途中で、既に読み込んだ先頭部分を同じ長さのまま書き換える
+        let writer = {
+            let source_file = source_file.clone();
+            std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(250));
+                let mut file = fs::OpenOptions::new()
+                    .write(true)
+                    .open(source_file)
+                    .unwrap();
+                file.write_all(&[b'b'; 1000]).unwrap();
+            })
+        };
+
+        // ===== Act =====
+        let result =
+            FileSystem::copy_all_data_under_the_directory_with_hash_verification_and_progress(
+                source_dir.path(),
+                dest_dir.path(),
+                &options,
+                |_, _| {},
+            );
+        writer.join().unwrap();
+
+        // ===== Assert =====
+        // 移動元を読み直して比較すると不一致になるが、書き込んだ内容そのものを検証するため成功する
+        assert!(result.is_ok());
+        assert_eq!(
+            fs::read(dest_dir.path().join("growing.log")).unwrap(),
+            vec![b'a'; 4000]
+        );
+        assert_ne!(fs::read(&source_file).unwrap(), vec![b'a'; 4000]);
+    }
+
+    #[test]
+    fn verify_written_file_reports_destination_corruption() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let written = temp_dir.path().join("written.txt");
+        fs::write(&written, "corrupted").unwrap();
+        let options = CopyOptions::default();
+        let written_hash = options.hash_algorithm.hasher().finalize();
+
+        // ===== Act =====
+        let result = FileSystem::verify_written_file(&written, &written_hash, &options);
+
+        // ===== Assert =====
+        assert!(matches!(result, Err(AppError::DestinationCorrupted(path)) if path == written));
+    }
+
+    fn create_symlink_fixture(temp_dir: &Path) -> (PathBuf, PathBuf) {
+        let outside = temp_dir.join("outside");
+        let source_dir = temp_dir.join("source");
+        let dest_dir = temp_dir.join("dest");
+        fs::create_dir_all(outside.join("linked_dir")).unwrap();
+        fs::create_dir(&source_dir).unwrap();
+        fs::create_dir(&dest_dir).unwrap();
+        fs::write(outside.join("target.txt"), "target").unwrap();
+        fs::write(outside.join("linked_dir").join("inner.txt"), "inner").unwrap();
+        std::os::unix::fs::symlink(outside.join("target.txt"), source_dir.join("file_link"))
+            .unwrap();
+        std::os::unix::fs::symlink(outside.join("linked_dir"), source_dir.join("dir_link"))
+            .unwrap();
+        (source_dir, dest_dir)
+    }
+
+    #[cfg(unix)]
+    fn symlink_options(symlink_policy: SymlinkPolicy) -> CopyOptions {
+        CopyOptions {
+            symlink_policy,
+            ..CopyOptions::default()
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn copy_all_data_under_the_directory_follows_symlinks_by_default() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let (source_dir, dest_dir) = create_symlink_fixture(temp_dir.path());
+
+        // ===== Act =====
+        let result =
+            FileSystem::copy_all_data_under_the_directory_with_hash_verification_and_progress(
+                &source_dir,
+                &dest_dir,
+                &symlink_options(SymlinkPolicy::default()),
+                |_, _| {},
+            );
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        let file_metadata = fs::symlink_metadata(dest_dir.join("file_link")).unwrap();
+        assert!(file_metadata.is_file());
+        assert_eq!(
+            fs::read_to_string(dest_dir.join("file_link")).unwrap(),
+            "target"
+        );
+        let dir_metadata = fs::symlink_metadata(dest_dir.join("dir_link")).unwrap();
+        assert!(dir_metadata.is_dir());
+        assert_eq!(
+            fs::read_to_string(dest_dir.join("dir_link").join("inner.txt")).unwrap(),
+            "inner"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn copy_all_data_under_the_directory_skips_symlinks_with_skip_policy() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let (source_dir, dest_dir) = create_symlink_fixture(temp_dir.path());
+        let options = symlink_options(SymlinkPolicy::Skip);
+
+        // ===== Act =====
+        let result =
+            FileSystem::copy_all_data_under_the_directory_with_hash_verification_and_progress(
+                &source_dir,
+                &dest_dir,
+                &options,
+                |_, _| {},
+            );
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert!(FileSystem::is_directory_empty(&dest_dir).unwrap());
+        assert!(FileSystem::verify_directory_contents_match_with_options(
+            &source_dir,
+            &dest_dir,
+            &options
+        )
+        .unwrap());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn copy_all_data_under_the_directory_recreates_symlinks_with_copy_as_link_policy() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let (source_dir, dest_dir) = create_symlink_fixture(temp_dir.path());
+
+        // ===== Act =====
+        let result =
+            FileSystem::copy_all_data_under_the_directory_with_hash_verification_and_progress(
+                &source_dir,
+                &dest_dir,
+                &symlink_options(SymlinkPolicy::CopyAsLink),
+                |_, _| {},
+            );
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        for name in ["file_link", "dir_link"] {
+            let metadata = fs::symlink_metadata(dest_dir.join(name)).unwrap();
+            assert!(metadata.file_type().is_symlink());
+            assert_eq!(
+                fs::read_link(dest_dir.join(name)).unwrap(),
+                fs::read_link(source_dir.join(name)).unwrap()
+            );
+        }
+    }
 }
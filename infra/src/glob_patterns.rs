@@ -0,0 +1,107 @@
+use std::path::Path;
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use shared::error::{AppError, AppResult};
+
+#[derive(Debug, Clone, Default)]
+pub struct GlobPatterns {
+    patterns: Vec<String>,
+    glob_set: GlobSet,
+}
+
+impl GlobPatterns {
+    pub fn new(patterns: Vec<String>) -> AppResult<Self> {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in &patterns {
+            let glob = Glob::new(pattern).map_err(|e| {
+                AppError::Io(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!("無効なglobパターンが指定されています: {}: {}", pattern, e),
+                ))
+            })?;
+            builder.add(glob);
+        }
+
+        let glob_set = builder
+            .build()
+            .map_err(|e| AppError::Io(std::io::Error::new(std::io::ErrorKind::InvalidInput, e)))?;
+
+        Ok(Self { patterns, glob_set })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    /// 相対パス全体、またはファイル名単体のいずれかがパターンに一致するかを判定する
+    pub fn matches(&self, rel_path: &Path) -> bool {
+        self.glob_set.is_match(rel_path)
+            || rel_path
+                .file_name()
+                .is_some_and(|file_name| self.glob_set.is_match(file_name))
+    }
+}
+
+impl PartialEq for GlobPatterns {
+    fn eq(&self, other: &Self) -> bool {
+        self.patterns == other.patterns
+    }
+}
+
+impl Eq for GlobPatterns {}
+
+impl std::ops::Deref for GlobPatterns {
+    type Target = [String];
+
+    fn deref(&self) -> &Self::Target {
+        &self.patterns
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glob_patterns_match_file_name_at_any_depth() {
+        // ===== Arrange =====
+        let patterns =
+            GlobPatterns::new(vec!["*.tmp".to_string(), "node_modules".to_string()]).unwrap();
+
+        // ===== Act & Assert =====
+        assert!(patterns.matches(Path::new("a.tmp")));
+        assert!(patterns.matches(Path::new("sub/dir/a.tmp")));
+        assert!(patterns.matches(Path::new("node_modules")));
+        assert!(patterns.matches(Path::new("app/node_modules")));
+        assert!(!patterns.matches(Path::new("app/src/main.rs")));
+    }
+
+    #[test]
+    fn glob_patterns_match_relative_path() {
+        // ===== Arrange =====
+        let patterns = GlobPatterns::new(vec!["logs/**".to_string()]).unwrap();
+
+        // ===== Act & Assert =====
+        assert!(patterns.matches(Path::new("logs/2024/app.log")));
+        assert!(!patterns.matches(Path::new("data/logs.txt")));
+    }
+
+    #[test]
+    fn glob_patterns_creation_fails_with_invalid_pattern() {
+        // ===== Act =====
+        let result = GlobPatterns::new(vec!["[invalid".to_string()]);
+
+        // ===== Assert =====
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn empty_glob_patterns_match_nothing() {
+        // ===== Arrange =====
+        let patterns = GlobPatterns::default();
+
+        // ===== Act & Assert =====
+        assert!(patterns.is_empty());
+        assert!(!patterns.matches(Path::new("anything.txt")));
+    }
+}
@@ -0,0 +1,184 @@
+use std::{fs::File, io::Read, path::Path};
+
+use sha2::{Digest, Sha256};
+use shared::error::{AppError, AppResult};
+
+use crate::buffer_size::BufferSize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HashAlgorithm {
+    #[default]
+    Sha256,
+    Blake3,
+}
+
+/// 逐次的にデータを受け取ってハッシュ値を計算する
+pub(crate) enum ContentHasher {
+    Sha256(Sha256),
+    Blake3(Box<blake3::Hasher>),
+}
+
+impl ContentHasher {
+    pub fn update(&mut self, chunk: &[u8]) {
+        match self {
+            ContentHasher::Sha256(hasher) => hasher.update(chunk),
+            ContentHasher::Blake3(hasher) => {
+                hasher.update(chunk);
+            }
+        }
+    }
+
+    pub fn finalize(self) -> String {
+        match self {
+            ContentHasher::Sha256(hasher) => format!("{:x}", hasher.finalize()),
+            ContentHasher::Blake3(hasher) => hasher.finalize().to_hex().to_string(),
+        }
+    }
+}
+
+impl HashAlgorithm {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HashAlgorithm::Sha256 => "sha256",
+            HashAlgorithm::Blake3 => "blake3",
+        }
+    }
+
+    pub(crate) fn hasher(&self) -> ContentHasher {
+        match self {
+            HashAlgorithm::Sha256 => ContentHasher::Sha256(Sha256::new()),
+            HashAlgorithm::Blake3 => ContentHasher::Blake3(Box::new(blake3::Hasher::new())),
+        }
+    }
+
+    pub fn calculate_hash_from_file_content(&self, path: &Path) -> AppResult<String> {
+        self.calculate_hash_from_file_content_with_buffer_size(path, BufferSize::default())
+    }
+
+    pub fn calculate_hash_from_file_content_with_buffer_size(
+        &self,
+        path: &Path,
+        buffer_size: BufferSize,
+    ) -> AppResult<String> {
+        let mut file = File::open(path)?;
+        let mut buffer = buffer_size.allocate();
+        let mut hasher = self.hasher();
+
+        loop {
+            let n = file.read(&mut buffer)?;
+            if n == 0 {
+                break;
+            }
+            hasher.update(&buffer[..n]);
+        }
+        Ok(hasher.finalize())
+    }
+}
+
+impl TryFrom<String> for HashAlgorithm {
+    type Error = AppError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.to_lowercase().as_str() {
+            "sha256" => Ok(HashAlgorithm::Sha256),
+            "blake3" => Ok(HashAlgorithm::Blake3),
+            _ => Err(AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("無効なハッシュアルゴリズムが指定されています: {}", value),
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    const ALGORITHMS: [HashAlgorithm; 2] = [HashAlgorithm::Sha256, HashAlgorithm::Blake3];
+
+    #[test]
+    fn calculate_hash_from_file_content_agrees_on_identical_content() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let file1 = temp_dir.path().join("file1.txt");
+        let file2 = temp_dir.path().join("file2.txt");
+        fs::write(&file1, "same content").unwrap();
+        fs::write(&file2, "same content").unwrap();
+
+        for algorithm in ALGORITHMS {
+            // ===== Act =====
+            let hash1 = algorithm.calculate_hash_from_file_content(&file1).unwrap();
+            let hash2 = algorithm.calculate_hash_from_file_content(&file2).unwrap();
+
+            // ===== Assert =====
+            assert_eq!(hash1, hash2);
+        }
+    }
+
+    #[test]
+    fn calculate_hash_from_file_content_detects_corrupted_copy() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let original = temp_dir.path().join("original.txt");
+        let corrupted = temp_dir.path().join("corrupted.txt");
+        fs::write(&original, "original content").unwrap();
+        fs::write(&corrupted, "original c0ntent").unwrap();
+
+        for algorithm in ALGORITHMS {
+            // ===== Act =====
+            let original_hash = algorithm
+                .calculate_hash_from_file_content(&original)
+                .unwrap();
+            let corrupted_hash = algorithm
+                .calculate_hash_from_file_content(&corrupted)
+                .unwrap();
+
+            // ===== Assert =====
+            assert_ne!(original_hash, corrupted_hash);
+        }
+    }
+
+    #[test]
+    fn calculate_hash_from_file_content_is_independent_of_buffer_size() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let file = temp_dir.path().join("file.bin");
+        // どのバッファサイズでも境界をまたぐよう、半端なサイズのデータを用意する
+        let content: Vec<u8> = (0..(256 * 1024 + 17)).map(|i: u32| i as u8).collect();
+        fs::write(&file, &content).unwrap();
+        let buffer_sizes = [1, 4095, 8192, 64 * 1024, 1024 * 1024];
+
+        for algorithm in ALGORITHMS {
+            // ===== Act =====
+            let hashes: Vec<String> = buffer_sizes
+                .iter()
+                .map(|&bytes| {
+                    algorithm
+                        .calculate_hash_from_file_content_with_buffer_size(
+                            &file,
+                            BufferSize::new(bytes).unwrap(),
+                        )
+                        .unwrap()
+                })
+                .collect();
+
+            // ===== Assert =====
+            assert!(hashes.iter().all(|hash| *hash == hashes[0]));
+        }
+    }
+
+    #[test]
+    fn hash_algorithm_creation_from_string() {
+        // ===== Act =====
+        let sha256 = HashAlgorithm::try_from("SHA256".to_string()).unwrap();
+        let blake3 = HashAlgorithm::try_from("blake3".to_string()).unwrap();
+        let invalid = HashAlgorithm::try_from("md5".to_string());
+
+        // ===== Assert =====
+        assert_eq!(sha256, HashAlgorithm::Sha256);
+        assert_eq!(blake3, HashAlgorithm::Blake3);
+        assert!(invalid.is_err());
+    }
+}
@@ -1 +1,18 @@
+pub mod bandwidth_limit;
+pub mod buffer_size;
+pub mod case_sensitivity;
+pub mod copy_options;
+pub mod deadline;
+pub mod directory_difference;
+pub mod extended_attributes;
+pub mod file_digest;
+pub mod file_operations;
 pub mod file_system;
+pub mod glob_patterns;
+pub mod hash_algorithm;
+pub mod retry_policy;
+pub mod storage_backend;
+pub mod symlink_policy;
+pub mod transfer_lock;
+pub mod transfer_summary;
+pub mod verification_mode;
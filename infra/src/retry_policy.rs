@@ -0,0 +1,135 @@
+use std::{io::ErrorKind, thread, time::Duration};
+
+use shared::error::{AppError, AppResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+}
+
+impl RetryPolicy {
+    pub fn new(max_attempts: u32, base_delay: Duration) -> AppResult<Self> {
+        if max_attempts == 0 {
+            return Err(AppError::Io(std::io::Error::new(
+                ErrorKind::InvalidInput,
+                "リトライの最大試行回数には1以上を指定してください",
+            )));
+        }
+        Ok(Self {
+            max_attempts,
+            base_delay,
+        })
+    }
+
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    pub fn base_delay(&self) -> Duration {
+        self.base_delay
+    }
+
+    /// 一時的なIOエラーの場合のみ、待機時間を倍にしながら最大試行回数まで再実行する
+    pub fn run<T>(&self, mut operation: impl FnMut() -> AppResult<T>) -> AppResult<T> {
+        let mut attempt = 1;
+        loop {
+            match operation() {
+                Err(e) if attempt < self.max_attempts && Self::is_transient(&e) => {
+                    thread::sleep(self.base_delay * 2u32.saturating_pow(attempt - 1));
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    fn is_transient(error: &AppError) -> bool {
+        matches!(
+            error,
+            AppError::Io(e) if matches!(
+                e.kind(),
+                ErrorKind::Interrupted | ErrorKind::TimedOut | ErrorKind::WouldBlock
+            )
+        )
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 1,
+            base_delay: Duration::ZERO,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failing_operation(
+        failures: u32,
+        kind: ErrorKind,
+        calls: &mut u32,
+    ) -> impl FnMut() -> AppResult<u32> + '_ {
+        move || {
+            *calls += 1;
+            if *calls <= failures {
+                Err(AppError::Io(std::io::Error::new(kind, "failure")))
+            } else {
+                Ok(*calls)
+            }
+        }
+    }
+
+    #[test]
+    fn retry_policy_retries_transient_errors_until_success() {
+        // ===== Arrange =====
+        let policy = RetryPolicy::new(3, Duration::ZERO).unwrap();
+        let mut calls = 0;
+
+        // ===== Act =====
+        let result = policy.run(failing_operation(2, ErrorKind::Interrupted, &mut calls));
+
+        // ===== Assert =====
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[test]
+    fn retry_policy_returns_last_error_when_attempts_are_exhausted() {
+        // ===== Arrange =====
+        let policy = RetryPolicy::new(2, Duration::ZERO).unwrap();
+        let mut calls = 0;
+
+        // ===== Act =====
+        let result = policy.run(failing_operation(5, ErrorKind::TimedOut, &mut calls));
+
+        // ===== Assert =====
+        assert!(matches!(result, Err(AppError::Io(e)) if e.kind() == ErrorKind::TimedOut));
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn retry_policy_does_not_retry_permanent_errors() {
+        // ===== Arrange =====
+        let policy = RetryPolicy::new(3, Duration::ZERO).unwrap();
+        let mut calls = 0;
+
+        // ===== Act =====
+        let result = policy.run(failing_operation(1, ErrorKind::NotFound, &mut calls));
+
+        // ===== Assert =====
+        assert!(result.is_err());
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn retry_policy_creation_fails_with_zero_attempts() {
+        // ===== Act =====
+        let result = RetryPolicy::new(0, Duration::ZERO);
+
+        // ===== Assert =====
+        assert!(result.is_err());
+    }
+}
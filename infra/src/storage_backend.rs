@@ -0,0 +1,248 @@
+use std::{
+    fmt, fs,
+    io::{self, ErrorKind},
+    ops::Deref,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use shared::error::{AppError, AppResult};
+
+use crate::{copy_options::CopyOptions, file_system::FileSystem};
+
+/// 移動先への書き込みと、移動先の内容の確認を行うストレージ
+///
+/// 移動元は常にローカルのファイルシステムから読み込み、移動先のディレクトリとファイルの作成、
+/// 空であるかの確認、コピー後の構成の検証と削除をこのトレイトを介して行う。
+/// 移動先ディレクトリ自体の作成、ロックファイル、マニフェスト、チェックサムファイル、ツリーハッシュと
+/// 一時ディレクトリからのリネームはローカルのファイルシステムで行うため、移動先はローカルから参照できる必要がある。
+pub trait StorageBackend: Send + Sync {
+    /// 親ディレクトリを含めてディレクトリを作成する
+    fn create_dir(&self, path: &Path) -> AppResult<()>;
+
+    /// ローカルの `from` を `to` にコピーし、コピーしたバイト数を返す
+    fn copy_file(&self, from: &Path, to: &Path, options: &CopyOptions) -> AppResult<u64>;
+
+    /// 対応していないストレージでは、呼び出し側が通常のコピーにフォールバックする
+    fn hard_link(&self, _original: &Path, _link: &Path) -> AppResult<()> {
+        Err(AppError::Io(io::Error::new(
+            ErrorKind::Unsupported,
+            "このストレージはハードリンクに対応していません",
+        )))
+    }
+
+    /// 配下のディレクトリとファイルの相対パスを名前順に返す
+    fn list(&self, dir: &Path, options: &CopyOptions) -> AppResult<Vec<PathBuf>>;
+
+    fn hash(&self, path: &Path, options: &CopyOptions) -> AppResult<String>;
+
+    /// ディレクトリ自体は残したまま、配下のエントリをすべて削除する
+    fn remove_all(&self, dir: &Path) -> AppResult<()>;
+
+    /// 存在しないディレクトリは空として扱う
+    fn is_empty(&self, dir: &Path) -> AppResult<bool>;
+
+    /// ファイルが存在する場合に `true` を返す（ディレクトリの場合は `false`）
+    fn is_file(&self, path: &Path) -> AppResult<bool>;
+
+    /// `true` の場合に限り、同一ファイルシステム上のリネームによる移動を試みる
+    fn is_local(&self) -> bool {
+        false
+    }
+}
+
+/// `std::fs` によるローカルのファイルシステム
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LocalStorageBackend;
+
+impl StorageBackend for LocalStorageBackend {
+    fn create_dir(&self, path: &Path) -> AppResult<()> {
+        fs::create_dir_all(path)?;
+        Ok(())
+    }
+
+    fn copy_file(&self, from: &Path, to: &Path, options: &CopyOptions) -> AppResult<u64> {
+        FileSystem::copy_file_entry(from, to, options)
+    }
+
+    fn hard_link(&self, original: &Path, link: &Path) -> AppResult<()> {
+        fs::hard_link(original, link)?;
+        Ok(())
+    }
+
+    fn list(&self, dir: &Path, options: &CopyOptions) -> AppResult<Vec<PathBuf>> {
+        FileSystem::list_entries(dir, options)
+    }
+
+    fn hash(&self, path: &Path, options: &CopyOptions) -> AppResult<String> {
+        FileSystem::calculate_hash_from_file_content(path, options)
+    }
+
+    fn remove_all(&self, dir: &Path) -> AppResult<()> {
+        FileSystem::clear_directory_contents(dir)
+    }
+
+    fn is_empty(&self, dir: &Path) -> AppResult<bool> {
+        match dir.exists() {
+            true => FileSystem::is_directory_empty(dir),
+            false => Ok(true),
+        }
+    }
+
+    fn is_file(&self, path: &Path) -> AppResult<bool> {
+        match fs::metadata(path) {
+            Ok(metadata) => Ok(metadata.is_file()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(AppError::Io(e)),
+        }
+    }
+
+    fn is_local(&self) -> bool {
+        true
+    }
+}
+
+/// `CopyOptions` で受け渡すための、共有可能なストレージ（既定はローカル）
+#[derive(Clone)]
+pub struct SharedStorageBackend(Arc<dyn StorageBackend>);
+
+impl SharedStorageBackend {
+    pub fn new(backend: impl StorageBackend + 'static) -> Self {
+        Self(Arc::new(backend))
+    }
+}
+
+impl Default for SharedStorageBackend {
+    fn default() -> Self {
+        Self::new(LocalStorageBackend)
+    }
+}
+
+impl fmt::Debug for SharedStorageBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = if self.0.is_local() { "local" } else { "custom" };
+        f.debug_tuple("SharedStorageBackend").field(&kind).finish()
+    }
+}
+
+impl Deref for SharedStorageBackend {
+    type Target = dyn StorageBackend;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tempfile::TempDir;
+
+    fn create_tree(root: &Path) {
+        fs::create_dir_all(root.join("a").join("b")).unwrap();
+        fs::write(root.join("root.txt"), "root").unwrap();
+        fs::write(root.join("a").join("b").join("nested.txt"), "nested").unwrap();
+    }
+
+    #[test]
+    fn local_storage_backend_matches_file_system() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        create_tree(temp_dir.path());
+        let backend = LocalStorageBackend;
+        let options = CopyOptions::default();
+        let file = temp_dir.path().join("root.txt");
+
+        // ===== Act =====
+        let entries = backend.list(temp_dir.path(), &options).unwrap();
+        let hash = backend.hash(&file, &options).unwrap();
+        let is_empty = backend.is_empty(temp_dir.path()).unwrap();
+
+        // ===== Assert =====
+        assert_eq!(
+            entries,
+            FileSystem::list_entries(temp_dir.path(), &options).unwrap()
+        );
+        assert_eq!(
+            hash,
+            options
+                .hash_algorithm
+                .calculate_hash_from_file_content(&file)
+                .unwrap()
+        );
+        assert!(!is_empty);
+        backend.remove_all(temp_dir.path()).unwrap();
+        assert!(backend.is_empty(temp_dir.path()).unwrap());
+    }
+
+    /// ローカルに書き込みつつ、呼び出し回数を数えるストレージ
+    #[derive(Default)]
+    struct CountingStorageBackend {
+        copied_files: AtomicUsize,
+        created_dirs: AtomicUsize,
+    }
+
+    impl StorageBackend for Arc<CountingStorageBackend> {
+        fn create_dir(&self, path: &Path) -> AppResult<()> {
+            self.created_dirs.fetch_add(1, Ordering::SeqCst);
+            LocalStorageBackend.create_dir(path)
+        }
+
+        fn copy_file(&self, from: &Path, to: &Path, options: &CopyOptions) -> AppResult<u64> {
+            self.copied_files.fetch_add(1, Ordering::SeqCst);
+            LocalStorageBackend.copy_file(from, to, options)
+        }
+
+        fn list(&self, dir: &Path, options: &CopyOptions) -> AppResult<Vec<PathBuf>> {
+            LocalStorageBackend.list(dir, options)
+        }
+
+        fn hash(&self, path: &Path, options: &CopyOptions) -> AppResult<String> {
+            LocalStorageBackend.hash(path, options)
+        }
+
+        fn remove_all(&self, dir: &Path) -> AppResult<()> {
+            LocalStorageBackend.remove_all(dir)
+        }
+
+        fn is_empty(&self, dir: &Path) -> AppResult<bool> {
+            LocalStorageBackend.is_empty(dir)
+        }
+
+        fn is_file(&self, path: &Path) -> AppResult<bool> {
+            LocalStorageBackend.is_file(path)
+        }
+    }
+
+    #[test]
+    fn copy_writes_destination_through_storage_backend() {
+        // ===== Arrange =====
+        let source_dir = TempDir::new().unwrap();
+        let dest_dir = TempDir::new().unwrap();
+        create_tree(source_dir.path());
+        let backend = Arc::new(CountingStorageBackend::default());
+        let options = CopyOptions {
+            storage_backend: SharedStorageBackend::new(backend.clone()),
+            ..CopyOptions::default()
+        };
+
+        // ===== Act =====
+        let result =
+            FileSystem::copy_all_data_under_the_directory_with_hash_verification_and_progress(
+                source_dir.path(),
+                dest_dir.path(),
+                &options,
+                |_, _| {},
+            );
+
+        // ===== Assert =====
+        assert!(result.is_ok());
+        assert_eq!(backend.copied_files.load(Ordering::SeqCst), 2);
+        assert_eq!(backend.created_dirs.load(Ordering::SeqCst), 2);
+        assert!(
+            FileSystem::verify_directory_contents_match(source_dir.path(), dest_dir.path())
+                .unwrap()
+        );
+    }
+}
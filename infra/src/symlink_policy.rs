@@ -0,0 +1,80 @@
+use shared::error::{AppError, AppResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SymlinkPolicy {
+    /// リンク先の内容をコピーする（ディレクトリへのリンクは中身を再帰的にコピーする）
+    #[default]
+    Follow,
+    /// シンボリックリンクをコピーせず、移動元に残す
+    Skip,
+    /// シンボリックリンク自体を移動先に作成する
+    CopyAsLink,
+}
+
+impl SymlinkPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SymlinkPolicy::Follow => "follow",
+            SymlinkPolicy::Skip => "skip",
+            SymlinkPolicy::CopyAsLink => "copy_as_link",
+        }
+    }
+}
+
+impl TryFrom<String> for SymlinkPolicy {
+    type Error = AppError;
+
+    fn try_from(value: String) -> AppResult<Self> {
+        match value.to_lowercase().as_str() {
+            "follow" => Ok(SymlinkPolicy::Follow),
+            "skip" => Ok(SymlinkPolicy::Skip),
+            "copy_as_link" => Ok(SymlinkPolicy::CopyAsLink),
+            _ => Err(AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "無効なシンボリックリンクの扱いが指定されています: {}",
+                    value
+                ),
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn symlink_policy_creation_from_string() {
+        // ===== Arrange =====
+        let values = ["follow", "SKIP", "copy_as_link"];
+
+        // ===== Act =====
+        let results: Vec<SymlinkPolicy> = values
+            .iter()
+            .map(|value| SymlinkPolicy::try_from(value.to_string()).unwrap())
+            .collect();
+
+        // ===== Assert =====
+        assert_eq!(
+            results,
+            vec![
+                SymlinkPolicy::Follow,
+                SymlinkPolicy::Skip,
+                SymlinkPolicy::CopyAsLink
+            ]
+        );
+    }
+
+    #[test]
+    fn symlink_policy_creation_from_invalid_string() {
+        // ===== Arrange =====
+        let value = "invalid";
+
+        // ===== Act =====
+        let result = SymlinkPolicy::try_from(value.to_string());
+
+        // ===== Assert =====
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,141 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
+use fs2::FileExt;
+use shared::error::{AppError, AppResult};
+
+pub const LOCK_FILE_NAME: &str = ".srow.lock";
+
+/// 同じ移動先に対する同時実行を防ぐための排他ロック。破棄されるとロックファイルを削除してロックを解放する
+#[derive(Debug)]
+pub struct TransferLock {
+    file: File,
+    path: PathBuf,
+}
+
+impl TransferLock {
+    /// `dir` にロックファイルを作成して排他ロックを取得し、実行中のプロセスIDと開始時刻を書き込む
+    pub fn acquire(dir: &Path, started_at: &str) -> AppResult<Self> {
+        let path = Self::lock_file_path(dir);
+        let mut file = loop {
+            // ロックを取得するまでは他の実行の内容を消さないよう、切り詰めずに開く
+            let file = OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .truncate(false)
+                .open(&path)?;
+            if let Err(e) = file.try_lock_exclusive() {
+                return Err(
+                    if e.kind() == fs2::lock_contended_error().kind()
+                        || e.kind() == ErrorKind::WouldBlock
+                    {
+                        AppError::TransferLocked(path)
+                    } else {
+                        AppError::Io(e)
+                    },
+                );
+            }
+            // 開いた後に前の実行がファイルを削除していた場合は、削除済みのファイルをロックしているため開き直す
+            if Self::is_current_lock_file(&file, &path)? {
+                break file;
+            }
+        };
+        file.set_len(0)?;
+        writeln!(file, "pid: {}", std::process::id())?;
+        writeln!(file, "started_at: {}", started_at)?;
+        file.flush()?;
+        Ok(Self { file, path })
+    }
+
+    pub fn lock_file_path(dir: &Path) -> PathBuf {
+        dir.join(LOCK_FILE_NAME)
+    }
+
+    #[cfg(unix)]
+    fn is_current_lock_file(file: &File, path: &Path) -> AppResult<bool> {
+        use std::os::unix::fs::MetadataExt;
+        let locked = file.metadata()?;
+        match fs::metadata(path) {
+            Ok(current) => Ok(current.dev() == locked.dev() && current.ino() == locked.ino()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(AppError::Io(e)),
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn is_current_lock_file(_file: &File, path: &Path) -> AppResult<bool> {
+        // ファイルを識別できない環境では、パスが残っているかのみを確認する
+        Ok(path.exists())
+    }
+}
+
+impl Drop for TransferLock {
+    fn drop(&mut self) {
+        // ロックを保持したまま削除し、同じファイルを開いて待っていた実行には開き直させる
+        let _ = fs::remove_file(&self.path);
+        let _ = FileExt::unlock(&self.file);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn transfer_lock_rejects_second_acquisition_while_held() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let lock = TransferLock::acquire(temp_dir.path(), "2024-01-01T00:00:00+09:00").unwrap();
+
+        // ===== Act =====
+        let result = TransferLock::acquire(temp_dir.path(), "2024-01-01T00:00:01+09:00");
+
+        // ===== Assert =====
+        let lock_path = TransferLock::lock_file_path(temp_dir.path());
+        assert!(matches!(result, Err(AppError::TransferLocked(path)) if path == lock_path));
+        let content = fs::read_to_string(&lock_path).unwrap();
+        assert!(content.contains(&format!("pid: {}", std::process::id())));
+        assert!(content.contains("started_at: 2024-01-01T00:00:00+09:00"));
+        drop(lock);
+        assert!(TransferLock::acquire(temp_dir.path(), "2024-01-01T00:00:02+09:00").is_ok());
+    }
+
+    #[test]
+    fn transfer_lock_removes_lock_file_when_released() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let lock = TransferLock::acquire(temp_dir.path(), "2024-01-01T00:00:00+09:00").unwrap();
+        assert!(TransferLock::lock_file_path(temp_dir.path()).exists());
+
+        // ===== Act =====
+        drop(lock);
+
+        // ===== Assert =====
+        assert!(fs::read_dir(temp_dir.path()).unwrap().next().is_none());
+    }
+
+    #[test]
+    fn transfer_lock_reopens_lock_file_removed_after_opening() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let lock_path = TransferLock::lock_file_path(temp_dir.path());
+        // 前の実行が削除する前に開いたファイルを想定する
+        let stale = File::create(&lock_path).unwrap();
+        fs::remove_file(&lock_path).unwrap();
+
+        // ===== Act =====
+        let is_current = TransferLock::is_current_lock_file(&stale, &lock_path).unwrap();
+        let lock = TransferLock::acquire(temp_dir.path(), "2024-01-01T00:00:00+09:00");
+
+        // ===== Assert =====
+        assert!(!is_current);
+        assert!(lock.is_ok());
+        assert!(lock_path.exists());
+    }
+}
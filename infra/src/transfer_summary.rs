@@ -0,0 +1,23 @@
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TransferSummary {
+    pub files_copied: u64,
+    pub directories_created: u64,
+    pub total_bytes: u64,
+    // 移動先に同じ内容のファイルが既にあったため、コピーを省略したファイル数
+    pub files_skipped: u64,
+}
+
+impl TransferSummary {
+    pub fn add_file(&mut self, bytes: u64) {
+        self.files_copied += 1;
+        self.total_bytes += bytes;
+    }
+
+    pub fn add_skipped_file(&mut self) {
+        self.files_skipped += 1;
+    }
+
+    pub fn add_directory(&mut self) {
+        self.directories_created += 1;
+    }
+}
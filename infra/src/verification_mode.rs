@@ -0,0 +1,119 @@
+use std::{fs, path::Path};
+
+use filetime::FileTime;
+use shared::error::{AppError, AppResult};
+
+use crate::{buffer_size::BufferSize, hash_algorithm::HashAlgorithm};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VerificationMode {
+    /// ファイルの内容のハッシュ値を比較する
+    #[default]
+    Hash,
+    /// ファイルサイズと更新日時を比較する
+    SizeAndMtime,
+    /// ファイルサイズのみを比較する
+    SizeOnly,
+}
+
+impl VerificationMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VerificationMode::Hash => "hash",
+            VerificationMode::SizeAndMtime => "size_and_mtime",
+            VerificationMode::SizeOnly => "size_only",
+        }
+    }
+
+    pub fn files_match(
+        &self,
+        path_1: &Path,
+        path_2: &Path,
+        algorithm: HashAlgorithm,
+        buffer_size: BufferSize,
+    ) -> AppResult<bool> {
+        match self {
+            VerificationMode::Hash => Ok(algorithm
+                .calculate_hash_from_file_content_with_buffer_size(path_1, buffer_size)?
+                == algorithm
+                    .calculate_hash_from_file_content_with_buffer_size(path_2, buffer_size)?),
+            VerificationMode::SizeAndMtime => {
+                let metadata_1 = fs::metadata(path_1)?;
+                let metadata_2 = fs::metadata(path_2)?;
+                Ok(metadata_1.len() == metadata_2.len()
+                    && FileTime::from_last_modification_time(&metadata_1)
+                        == FileTime::from_last_modification_time(&metadata_2))
+            }
+            VerificationMode::SizeOnly => {
+                Ok(fs::metadata(path_1)?.len() == fs::metadata(path_2)?.len())
+            }
+        }
+    }
+}
+
+impl TryFrom<String> for VerificationMode {
+    type Error = AppError;
+
+    fn try_from(value: String) -> AppResult<Self> {
+        match value.to_lowercase().as_str() {
+            "hash" => Ok(VerificationMode::Hash),
+            "size_and_mtime" => Ok(VerificationMode::SizeAndMtime),
+            "size_only" => Ok(VerificationMode::SizeOnly),
+            _ => Err(AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("無効な検証方法が指定されています: {}", value),
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn verification_mode_creation_from_string() {
+        // ===== Arrange =====
+        let values = ["hash", "SIZE_AND_MTIME", "size_only"];
+
+        // ===== Act =====
+        let results: Vec<VerificationMode> = values
+            .iter()
+            .map(|value| VerificationMode::try_from(value.to_string()).unwrap())
+            .collect();
+
+        // ===== Assert =====
+        assert_eq!(
+            results,
+            vec![
+                VerificationMode::Hash,
+                VerificationMode::SizeAndMtime,
+                VerificationMode::SizeOnly
+            ]
+        );
+    }
+
+    #[test]
+    fn size_and_mtime_mode_detects_different_modification_time() {
+        // ===== Arrange =====
+        let temp_dir = TempDir::new().unwrap();
+        let file_1 = temp_dir.path().join("file1.txt");
+        let file_2 = temp_dir.path().join("file2.txt");
+        fs::write(&file_1, "content").unwrap();
+        fs::write(&file_2, "content").unwrap();
+        filetime::set_file_mtime(&file_1, FileTime::from_unix_time(1_000_000, 0)).unwrap();
+        filetime::set_file_mtime(&file_2, FileTime::from_unix_time(2_000_000, 0)).unwrap();
+
+        // ===== Act =====
+        let result = VerificationMode::SizeAndMtime.files_match(
+            &file_1,
+            &file_2,
+            HashAlgorithm::default(),
+            BufferSize::default(),
+        );
+
+        // ===== Assert =====
+        assert!(!result.unwrap());
+    }
+}
@@ -1,42 +1,225 @@
 use std::env::VarError;
 use std::io;
-use std::path::StripPrefixError;
+use std::path::{PathBuf, StripPrefixError};
+use std::time::Duration;
 
-#[derive(Debug)]
+use thiserror::Error;
+
+#[derive(Debug, Error)]
 pub enum AppError {
-    Io(io::Error),
-    Env(VarError),
-    Path(StripPrefixError),
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+    #[error("Environment variable error: {0}")]
+    Env(#[from] VarError),
+    #[error("Path error: {0}")]
+    Path(#[from] StripPrefixError),
+    #[error("今日は指定された曜日ではありません。終了します。: 指定 {expected} / 今日 {actual}")]
+    WrongWeekday { expected: String, actual: String },
+    #[error(
+        "移動先ディレクトリにデータが既に存在するため、処理を終了します: {}",
+        .0.display()
+    )]
+    DestinationNotEmpty(PathBuf),
+    #[error(
+        "整合性エラー：コピー内容が一致しません。: {} -> {}",
+        source_path.display(),
+        destination_path.display()
+    )]
+    IntegrityMismatch {
+        source_path: PathBuf,
+        destination_path: PathBuf,
+    },
+    #[error(
+        "移動先に書き込んだ内容を読み直すと一致しません（移動先の破損の可能性があります）。: {}",
+        .0.display()
+    )]
+    DestinationCorrupted(PathBuf),
+    #[error("移動先の空き容量が不足しています。: 必要 {required} bytes / 空き {available} bytes")]
+    InsufficientSpace { required: u64, available: u64 },
+    #[error(
+        "移動元と移動先のディレクトリが入れ子になっているため、処理を終了します: {} / {}",
+        source_path.display(),
+        destination_path.display()
+    )]
+    NestedDirectories {
+        source_path: PathBuf,
+        destination_path: PathBuf,
+    },
+    #[error("パスに無効な文字が含まれています: {}", .0.display())]
+    InvalidPath(PathBuf),
+    #[error("移動元ディレクトリが空のため、処理を終了します: {}", .0.display())]
+    SourceEmpty(PathBuf),
+    #[error(
+        "別の srow が同じ移動先で実行中のため、処理を終了します（ロックファイル: {}）",
+        .0.display()
+    )]
+    TransferLocked(PathBuf),
+    #[error("転送が制限時間（{}秒）を超えたため中断しました", .0.as_secs_f64())]
+    Timeout(Duration),
+    #[error(
+        "設定ファイルの解析に失敗しました（{line}行{column}列{}）: {message}",
+        key.as_ref().map(|key| format!("、キー `{}`", key)).unwrap_or_default()
+    )]
+    Config {
+        line: usize,
+        column: usize,
+        key: Option<String>,
+        message: String,
+    },
+    #[error("設定ファイルがスキーマに適合しません: {}", .0.join("; "))]
+    ConfigSchema(Vec<String>),
+    #[error("{0}件の差分が見つかりました")]
+    DifferencesFound(usize),
+    #[error("{total}件中{failed}件のジョブが失敗しました")]
+    JobsFailed { total: usize, failed: usize },
 }
 
-impl From<io::Error> for AppError {
-    fn from(err: io::Error) -> Self {
-        AppError::Io(err)
+impl AppError {
+    /// スクリプトから判別できるよう、メッセージとは独立した固定のエラーコードを返す
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::Io(_) => "io",
+            AppError::Env(_) => "env",
+            AppError::Path(_) => "path",
+            AppError::WrongWeekday { .. } => "wrong_weekday",
+            AppError::DestinationNotEmpty(_) => "destination_not_empty",
+            AppError::IntegrityMismatch { .. } => "integrity_mismatch",
+            AppError::DestinationCorrupted(_) => "destination_corrupted",
+            AppError::InsufficientSpace { .. } => "insufficient_space",
+            AppError::NestedDirectories { .. } => "nested_directories",
+            AppError::InvalidPath(_) => "invalid_path",
+            AppError::SourceEmpty(_) => "source_empty",
+            AppError::TransferLocked(_) => "transfer_locked",
+            AppError::Timeout(_) => "timeout",
+            AppError::Config { .. } => "config",
+            AppError::ConfigSchema(_) => "config_schema",
+            AppError::DifferencesFound(_) => "differences_found",
+            AppError::JobsFailed { .. } => "jobs_failed",
+        }
     }
-}
 
-impl From<VarError> for AppError {
-    fn from(err: VarError) -> Self {
-        AppError::Env(err)
+    /// 失敗の種類ごとに割り当てたプロセスの終了コード（README に一覧を記載）
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            AppError::Io(_)
+            | AppError::Env(_)
+            | AppError::Path(_)
+            | AppError::InvalidPath(_)
+            | AppError::Config { .. }
+            | AppError::ConfigSchema(_) => 1,
+            AppError::WrongWeekday { .. } => 2,
+            AppError::DestinationNotEmpty(_) => 3,
+            AppError::IntegrityMismatch { .. } | AppError::DestinationCorrupted(_) => 4,
+            AppError::InsufficientSpace { .. } => 5,
+            AppError::NestedDirectories { .. } => 6,
+            AppError::SourceEmpty(_) => 7,
+            AppError::TransferLocked(_) => 8,
+            AppError::Timeout(_) => 9,
+            AppError::DifferencesFound(_) => 10,
+            AppError::JobsFailed { .. } => 11,
+        }
     }
 }
 
-impl From<StripPrefixError> for AppError {
-    fn from(err: StripPrefixError) -> Self {
-        AppError::Path(err)
+pub type AppResult<T> = Result<T, AppError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn io_error_is_exposed_as_source() {
+        // ===== Arrange =====
+        let io_error = io::Error::new(io::ErrorKind::NotFound, "missing");
+
+        // ===== Act =====
+        let error = AppError::from(io_error);
+
+        // ===== Assert =====
+        let source = error.source().unwrap();
+        let source = source.downcast_ref::<io::Error>().unwrap();
+        assert_eq!(source.kind(), io::ErrorKind::NotFound);
+        assert_eq!(error.to_string(), "IO error: missing");
     }
-}
 
-impl std::fmt::Display for AppError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            AppError::Io(e) => write!(f, "IO error: {}", e),
-            AppError::Env(e) => write!(f, "Environment variable error: {}", e),
-            AppError::Path(e) => write!(f, "Path error: {}", e),
-        }
+    #[test]
+    fn env_error_is_exposed_as_source() {
+        // ===== Act =====
+        let error = AppError::from(VarError::NotPresent);
+
+        // ===== Assert =====
+        let source = error.source().unwrap();
+        assert_eq!(
+            source.downcast_ref::<VarError>(),
+            Some(&VarError::NotPresent)
+        );
     }
-}
 
-impl std::error::Error for AppError {}
+    #[test]
+    fn domain_errors_have_no_source() {
+        // ===== Arrange =====
+        let error = AppError::DestinationNotEmpty(PathBuf::from("/dest"));
 
-pub type AppResult<T> = Result<T, AppError>;
+        // ===== Act =====
+        let source = error.source();
+
+        // ===== Assert =====
+        assert!(source.is_none());
+    }
+
+    #[test]
+    fn code_is_stable_snake_case_identifier() {
+        // ===== Arrange =====
+        let error = AppError::InsufficientSpace {
+            required: 10,
+            available: 5,
+        };
+
+        // ===== Act =====
+        let code = error.code();
+
+        // ===== Assert =====
+        assert_eq!(code, "insufficient_space");
+    }
+
+    #[test]
+    fn exit_code_is_mapped_per_failure_class() {
+        // ===== Arrange =====
+        let cases = [
+            (AppError::from(io::Error::other("io")), 1),
+            (
+                AppError::WrongWeekday {
+                    expected: "Mon".to_string(),
+                    actual: "Tue".to_string(),
+                },
+                2,
+            ),
+            (AppError::DestinationNotEmpty(PathBuf::from("/dest")), 3),
+            (
+                AppError::IntegrityMismatch {
+                    source_path: PathBuf::from("/src/a"),
+                    destination_path: PathBuf::from("/dest/a"),
+                },
+                4,
+            ),
+            (AppError::TransferLocked(PathBuf::from("/.srow.lock")), 8),
+            (AppError::DifferencesFound(2), 10),
+            (
+                AppError::JobsFailed {
+                    total: 3,
+                    failed: 2,
+                },
+                11,
+            ),
+        ];
+
+        for (error, expected) in cases {
+            // ===== Act =====
+            let exit_code = error.exit_code();
+
+            // ===== Assert =====
+            assert_eq!(exit_code, expected, "{}", error.code());
+        }
+    }
+}
@@ -0,0 +1,491 @@
+use std::{path::Path, str::FromStr};
+
+use crate::error::AppError;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Lang {
+    #[default]
+    Ja,
+    En,
+}
+
+impl Lang {
+    /// `LANG` が英語ロケール（`en_US.UTF-8` など）の場合のみ英語にし、それ以外は日本語とする
+    pub fn from_env() -> Self {
+        match std::env::var("LANG") {
+            Ok(lang) if lang.to_lowercase().starts_with("en") => Lang::En,
+            _ => Lang::Ja,
+        }
+    }
+}
+
+impl FromStr for Lang {
+    type Err = AppError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "ja" => Ok(Lang::Ja),
+            "en" => Ok(Lang::En),
+            _ => Err(AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "無効な言語が指定されています（ja または en）",
+            ))),
+        }
+    }
+}
+
+pub enum Message<'a> {
+    TransferSucceeded,
+    TransferSummary {
+        files_copied: u64,
+        directories_created: u64,
+        total_bytes: u64,
+    },
+    PreflightPassed {
+        source: &'a Path,
+        destination: &'a Path,
+    },
+    CapacityChecked {
+        required: u64,
+        available: u64,
+    },
+    ClearingDestination(&'a Path),
+    SourceEmpty(&'a Path),
+    ConfirmSourceRemoval {
+        source: &'a Path,
+        count: usize,
+    },
+    SourceKept(&'a Path),
+    TransferStarted {
+        source: &'a Path,
+        destination: &'a Path,
+    },
+    FileCopied {
+        path: &'a Path,
+        bytes: u64,
+    },
+    FileMoved {
+        path: &'a Path,
+        bytes: u64,
+    },
+    SampleConfigWritten(&'a Path),
+    ManifestWritten(&'a Path),
+    TreeHash {
+        source: &'a str,
+        destination: &'a str,
+    },
+    NextRun(&'a str),
+    FilesProgress {
+        done: u64,
+        total: u64,
+    },
+    RollingBack(&'a AppError),
+    RemovingStaging(&'a AppError),
+    MovedByRename {
+        source: &'a Path,
+        destination: &'a Path,
+    },
+    SourceCleared(&'a Path),
+    VerificationResult(bool),
+    DryRunRemoval(&'a Path),
+    DryRunCopy(&'a Path),
+    DirectoriesMatch,
+    ConfigRequired,
+    IncrementalWithStdinConfig,
+    InteractiveWithStdinConfig,
+    JobSkipped {
+        job: usize,
+        reason: &'a AppError,
+    },
+    JobFailed {
+        job: usize,
+        reason: &'a AppError,
+    },
+    Error(&'a AppError),
+}
+
+impl Message<'_> {
+    pub fn render(&self, lang: Lang) -> String {
+        match lang {
+            Lang::Ja => self.render_ja(),
+            Lang::En => self.render_en(),
+        }
+    }
+
+    fn render_ja(&self) -> String {
+        match self {
+            Message::TransferSucceeded => "ファイルを正常に移動しました。".to_string(),
+            Message::TransferSummary {
+                files_copied,
+                directories_created,
+                total_bytes,
+            } => format!(
+                "移動結果: ファイル {} 件 / ディレクトリ {} 件 / 合計 {} bytes",
+                files_copied, directories_created, total_bytes
+            ),
+            Message::PreflightPassed {
+                source,
+                destination,
+            } => format!(
+                "事前チェックを通過しました: {} -> {}",
+                source.display(),
+                destination.display()
+            ),
+            Message::CapacityChecked {
+                required,
+                available,
+            } => format!(
+                "容量を確認しました: 必要 {} bytes / 空き {} bytes",
+                required, available
+            ),
+            Message::ClearingDestination(path) => {
+                format!("移動先の既存データを削除します: {}", path.display())
+            }
+            Message::SourceEmpty(path) => {
+                format!("移動元ディレクトリが空です: {}", path.display())
+            }
+            Message::ConfirmSourceRemoval { source, count } => format!(
+                "移動元 {} から上記の {} 件を削除します。よろしいですか？ [y/N] ",
+                source.display(),
+                count
+            ),
+            Message::SourceKept(path) => format!(
+                "移動元の削除を取りやめました（移動先へのコピーは完了しています）: {}",
+                path.display()
+            ),
+            Message::TransferStarted {
+                source,
+                destination,
+            } => format!(
+                "移動を開始します: {} -> {}",
+                source.display(),
+                destination.display()
+            ),
+            Message::FileCopied { path, bytes } => {
+                format!("コピーしました: {} ({} bytes)", path.display(), bytes)
+            }
+            Message::FileMoved { path, bytes } => {
+                format!("移動しました: {} ({} bytes)", path.display(), bytes)
+            }
+            Message::SampleConfigWritten(path) => {
+                format!("設定ファイルのサンプルを作成しました: {}", path.display())
+            }
+            Message::ManifestWritten(path) => {
+                format!("マニフェストを作成しました: {}", path.display())
+            }
+            Message::RollingBack(reason) => {
+                format!("転送に失敗したため移動先をロールバックします: {}", reason)
+            }
+            Message::RemovingStaging(reason) => {
+                format!("転送に失敗したため一時ディレクトリを削除します: {}", reason)
+            }
+            Message::MovedByRename {
+                source,
+                destination,
+            } => format!(
+                "同一ファイルシステム上のためリネームで移動しました: {} -> {}",
+                source.display(),
+                destination.display()
+            ),
+            Message::SourceCleared(path) => format!("移動元をクリアしました: {}", path.display()),
+            Message::VerificationResult(matched) => {
+                format!("検証結果: {}", if *matched { "一致" } else { "不一致" })
+            }
+            Message::DryRunRemoval(path) => format!("削除予定: {}", path.display()),
+            Message::DryRunCopy(path) => format!("コピー予定: {}", path.display()),
+            Message::TreeHash {
+                source,
+                destination,
+            } => format!("ツリーハッシュ: 移動元 {} / 移動先 {}", source, destination),
+            Message::NextRun(date) => format!("次回の実行日: {}", date),
+            Message::FilesProgress { done, total } => format!("{}/{} ファイル", done, total),
+            Message::DirectoriesMatch => "ディレクトリの内容は一致しています。".to_string(),
+            Message::ConfigRequired => "設定ファイル（--file または srow.json）またはコマンドライン引数（source_directory, destination_directory, weekday）が必要です".to_string(),
+            Message::IncrementalWithStdinConfig => {
+                "--incremental は標準入力から設定を読み込む場合には使用できません".to_string()
+            }
+            Message::InteractiveWithStdinConfig => {
+                "--interactive は標準入力から設定を読み込む場合には使用できません".to_string()
+            }
+            Message::JobSkipped { job, reason } => {
+                format!("ジョブ{}をスキップしました: {}", job, reason)
+            }
+            Message::JobFailed { job, reason } => {
+                format!("ジョブ{}が失敗しました: {}", job, reason)
+            }
+            Message::Error(error) => error.to_string(),
+        }
+    }
+
+    fn render_en(&self) -> String {
+        match self {
+            Message::TransferSucceeded => "Files were moved successfully.".to_string(),
+            Message::TransferSummary {
+                files_copied,
+                directories_created,
+                total_bytes,
+            } => format!(
+                "Result: {} files / {} directories / {} bytes in total",
+                files_copied, directories_created, total_bytes
+            ),
+            Message::PreflightPassed {
+                source,
+                destination,
+            } => format!(
+                "Pre-flight checks passed: {} -> {}",
+                source.display(),
+                destination.display()
+            ),
+            Message::CapacityChecked {
+                required,
+                available,
+            } => format!(
+                "Checked capacity: {} bytes required / {} bytes available",
+                required, available
+            ),
+            Message::ClearingDestination(path) => {
+                format!("Deleting existing data in the destination: {}", path.display())
+            }
+            Message::SourceEmpty(path) => {
+                format!("The source directory is empty: {}", path.display())
+            }
+            Message::ConfirmSourceRemoval { source, count } => format!(
+                "Delete the {} entries listed above from the source {}? [y/N] ",
+                count,
+                source.display()
+            ),
+            Message::SourceKept(path) => format!(
+                "Kept the source; the destination already holds the verified copy: {}",
+                path.display()
+            ),
+            Message::TransferStarted {
+                source,
+                destination,
+            } => format!(
+                "Starting transfer: {} -> {}",
+                source.display(),
+                destination.display()
+            ),
+            Message::FileCopied { path, bytes } => {
+                format!("Copied: {} ({} bytes)", path.display(), bytes)
+            }
+            Message::FileMoved { path, bytes } => {
+                format!("Moved: {} ({} bytes)", path.display(), bytes)
+            }
+            Message::SampleConfigWritten(path) => {
+                format!("Wrote a sample config file: {}", path.display())
+            }
+            Message::ManifestWritten(path) => {
+                format!("Wrote a manifest: {}", path.display())
+            }
+            Message::RollingBack(reason) => format!(
+                "Rolling back the destination because the transfer failed: {}",
+                Message::Error(reason).render_en()
+            ),
+            Message::RemovingStaging(reason) => format!(
+                "Removing the staging directory because the transfer failed: {}",
+                Message::Error(reason).render_en()
+            ),
+            Message::MovedByRename {
+                source,
+                destination,
+            } => format!(
+                "Moved by rename because both are on the same file system: {} -> {}",
+                source.display(),
+                destination.display()
+            ),
+            Message::SourceCleared(path) => format!("Cleared the source: {}", path.display()),
+            Message::VerificationResult(matched) => format!(
+                "Verification result: {}",
+                if *matched { "match" } else { "mismatch" }
+            ),
+            Message::DryRunRemoval(path) => format!("Would remove: {}", path.display()),
+            Message::DryRunCopy(path) => format!("Would copy: {}", path.display()),
+            Message::TreeHash {
+                source,
+                destination,
+            } => format!("Tree hash: source {} / destination {}", source, destination),
+            Message::NextRun(date) => format!("Next run: {}", date),
+            Message::FilesProgress { done, total } => format!("{}/{} files", done, total),
+            Message::DirectoriesMatch => "The directory contents match.".to_string(),
+            Message::ConfigRequired => "A config file (--file or srow.json) or the command line arguments (source_directory, destination_directory, weekday) are required".to_string(),
+            Message::IncrementalWithStdinConfig => {
+                "--incremental cannot be used when the config is read from stdin".to_string()
+            }
+            Message::InteractiveWithStdinConfig => {
+                "--interactive cannot be used when the config is read from stdin, since the answer is read from stdin too".to_string()
+            }
+            Message::JobSkipped { job, reason } => {
+                format!("Skipped job {}: {}", job, Message::Error(reason).render_en())
+            }
+            Message::JobFailed { job, reason } => {
+                format!("Job {} failed: {}", job, Message::Error(reason).render_en())
+            }
+            Message::Error(error) => Self::render_error_en(error),
+        }
+    }
+
+    // IO エラーなどは元のメッセージをそのまま使う
+    fn render_error_en(error: &AppError) -> String {
+        match error {
+            AppError::WrongWeekday { expected, actual } => format!(
+                "Today is not the specified weekday. Exiting.: expected {} / today {}",
+                expected, actual
+            ),
+            AppError::DestinationNotEmpty(path) => format!(
+                "The destination directory already contains data; aborting: {}",
+                path.display()
+            ),
+            AppError::IntegrityMismatch {
+                source_path,
+                destination_path,
+            } => format!(
+                "Integrity error: the copied content does not match: {} -> {}",
+                source_path.display(),
+                destination_path.display()
+            ),
+            AppError::DestinationCorrupted(path) => format!(
+                "Reading back the written file does not match what was written (the destination may be corrupted): {}",
+                path.display()
+            ),
+            AppError::InsufficientSpace {
+                required,
+                available,
+            } => format!(
+                "Not enough free space at the destination: {} bytes required / {} bytes available",
+                required, available
+            ),
+            AppError::NestedDirectories {
+                source_path,
+                destination_path,
+            } => format!(
+                "The source and destination directories are nested; aborting: {} / {}",
+                source_path.display(),
+                destination_path.display()
+            ),
+            AppError::InvalidPath(path) => {
+                format!("The path contains invalid characters: {}", path.display())
+            }
+            AppError::SourceEmpty(path) => format!(
+                "The source directory is empty; aborting: {}",
+                path.display()
+            ),
+            AppError::TransferLocked(path) => format!(
+                "Another srow run is using the same destination; aborting (lock file: {})",
+                path.display()
+            ),
+            AppError::Timeout(timeout) => format!(
+                "The transfer exceeded the time limit ({} s) and was aborted",
+                timeout.as_secs_f64()
+            ),
+            AppError::Config {
+                line,
+                column,
+                key,
+                message,
+            } => format!(
+                "Failed to parse the config file (line {}, column {}{}): {}",
+                line,
+                column,
+                key.as_ref()
+                    .map(|key| format!(", key `{}`", key))
+                    .unwrap_or_default(),
+                message
+            ),
+            AppError::ConfigSchema(violations) => format!(
+                "The config file does not match the schema: {}",
+                violations.join("; ")
+            ),
+            AppError::DifferencesFound(count) => format!("Found {} difference(s)", count),
+            AppError::JobsFailed { total, failed } => {
+                format!("{} of {} jobs failed", failed, total)
+            }
+            _ => error.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wrong_weekday_error() -> AppError {
+        AppError::WrongWeekday {
+            expected: "Thu".to_string(),
+            actual: "Mon".to_string(),
+        }
+    }
+
+    #[test]
+    fn transfer_succeeded_is_rendered_in_each_language() {
+        // ===== Arrange =====
+        let message = Message::TransferSucceeded;
+
+        // ===== Act =====
+        let ja = message.render(Lang::Ja);
+        let en = message.render(Lang::En);
+
+        // ===== Assert =====
+        assert_eq!(ja, "ファイルを正常に移動しました。");
+        assert_eq!(en, "Files were moved successfully.");
+    }
+
+    #[test]
+    fn wrong_weekday_error_is_rendered_in_each_language() {
+        // ===== Arrange =====
+        let error = wrong_weekday_error();
+        let message = Message::Error(&error);
+
+        // ===== Act =====
+        let ja = message.render(Lang::Ja);
+        let en = message.render(Lang::En);
+
+        // ===== Assert =====
+        assert_eq!(
+            ja,
+            "今日は指定された曜日ではありません。終了します。: 指定 Thu / 今日 Mon"
+        );
+        assert_eq!(
+            en,
+            "Today is not the specified weekday. Exiting.: expected Thu / today Mon"
+        );
+    }
+
+    #[test]
+    fn verification_result_is_rendered_in_each_language() {
+        // ===== Act =====
+        let ja = Message::VerificationResult(false).render(Lang::Ja);
+        let en = Message::VerificationResult(true).render(Lang::En);
+
+        // ===== Assert =====
+        assert_eq!(ja, "検証結果: 不一致");
+        assert_eq!(en, "Verification result: match");
+    }
+
+    #[test]
+    fn file_moved_is_rendered_in_each_language() {
+        // ===== Arrange =====
+        let message = Message::FileMoved {
+            path: Path::new("a.txt"),
+            bytes: 2,
+        };
+
+        // ===== Act =====
+        let ja = message.render(Lang::Ja);
+        let en = message.render(Lang::En);
+
+        // ===== Assert =====
+        assert_eq!(ja, "移動しました: a.txt (2 bytes)");
+        assert_eq!(en, "Moved: a.txt (2 bytes)");
+    }
+
+    #[test]
+    fn lang_is_parsed_case_insensitively() {
+        // ===== Act =====
+        let result: Vec<Lang> = ["JA", "en"].iter().map(|s| s.parse().unwrap()).collect();
+
+        // ===== Assert =====
+        assert_eq!(result, vec![Lang::Ja, Lang::En]);
+        assert!("fr".parse::<Lang>().is_err());
+    }
+}
@@ -1,20 +1,45 @@
-use std::path::PathBuf;
+use std::{
+    cell::Cell,
+    io::{IsTerminal, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
 
-use clap::Parser;
+use clap::{ArgAction, CommandFactory, Parser, Subcommand, ValueEnum};
+use clap_complete::Shell;
+use directories::ProjectDirs;
 use domain::{
+    clock::{Clock, SystemClock},
     config_builder::{
-        arg_config_builder::ArgConfigBuilder, json_config_builder::JsonConfigBuilder, ConfigBuilder,
+        arg_config_builder::ArgConfigBuilder, multi_job_config_builder::MultiJobConfigBuilder,
+        sample_config::write_sample_config, ConfigBuilder,
+    },
+    directory_data_transfer_service::{
+        DirectoryDataTransferService, TransferPlan, TransferSummary,
     },
-    directory_data_transfer_service::DirectoryDataTransferService,
+    directory_verification_service::{CaseSensitivity, DirectoryVerificationService},
+    incremental_run::{parse_timestamp, IncrementalRun},
+    manifest::verify_manifest,
+    source_removal_prompt::confirm_source_removal,
+};
+use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
+use serde_json::json;
+use shared::{
+    error::{AppError, AppResult},
+    message::{Lang, Message},
 };
-use shared::error::{AppError, AppResult};
 
 #[derive(Parser)]
 #[command(name = "sRow motion")]
 #[command(bin_name = "srow")]
 #[command(version = "0.1")]
 #[command(about="Move all date under the specific directory to other directory", long_about = None)]
+#[command(args_conflicts_with_subcommands = true)]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Config file to load; pass `-` to read a JSON config from stdin
     #[arg(short, long, value_name = "JSON_FILE")]
     file: Option<PathBuf>,
 
@@ -40,33 +65,754 @@ struct Cli {
         short,
         long,
         value_name = "WEEKDAY",
+        value_delimiter = ',',
+        requires = "source_directory",
+        conflicts_with = "file"
+    )]
+    weekday: Option<Vec<String>>,
+
+    #[arg(
+        short,
+        long,
+        value_name = "TIMEZONE",
+        requires = "source_directory",
+        conflicts_with = "file"
+    )]
+    timezone: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "HASH_ALGORITHM",
+        requires = "source_directory",
+        conflicts_with = "file"
+    )]
+    hash_algorithm: Option<String>,
+
+    #[arg(
+        short,
+        long = "exclude",
+        value_name = "GLOB",
+        requires = "source_directory",
+        conflicts_with = "file"
+    )]
+    exclude_patterns: Vec<String>,
+
+    #[arg(
+        long = "include",
+        value_name = "GLOB",
+        requires = "source_directory",
+        conflicts_with = "file"
+    )]
+    include_patterns: Vec<String>,
+
+    #[arg(
+        long,
+        value_name = "SYMLINK_POLICY",
+        requires = "source_directory",
+        conflicts_with = "file"
+    )]
+    symlink_policy: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "MAX_ATTEMPTS",
+        requires = "source_directory",
+        conflicts_with = "file"
+    )]
+    retry_attempts: Option<u32>,
+
+    #[arg(
+        long,
+        value_name = "MILLISECONDS",
+        requires = "retry_attempts",
+        conflicts_with = "file"
+    )]
+    retry_delay_ms: Option<u64>,
+
+    #[arg(
+        long,
+        value_name = "OCTAL_MODE",
+        requires = "source_directory",
+        conflicts_with = "file"
+    )]
+    directory_mode: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "VERIFICATION_MODE",
+        requires = "source_directory",
+        conflicts_with = "file"
+    )]
+    verification_mode: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "BYTES",
+        requires = "source_directory",
+        conflicts_with = "file"
+    )]
+    buffer_size: Option<usize>,
+
+    /// Limit the average write speed to the destination in bytes per second
+    #[arg(
+        long,
+        value_name = "BYTES_PER_SEC",
+        requires = "source_directory",
+        conflicts_with = "file"
+    )]
+    bandwidth_limit: Option<u64>,
+
+    #[arg(
+        long,
+        value_name = "DAYS",
+        allow_hyphen_values = true,
+        requires = "source_directory",
+        conflicts_with = "file"
+    )]
+    date_offset_days: Option<i64>,
+
+    #[arg(long, requires = "date_offset_days", conflicts_with = "file")]
+    date_offset_applies_to_weekday: bool,
+
+    #[arg(long, requires = "source_directory", conflicts_with = "file")]
+    snap_date_to_weekday: bool,
+
+    #[arg(
+        long,
+        value_name = "EMPTY_SOURCE_POLICY",
+        requires = "source_directory",
+        conflicts_with = "file"
+    )]
+    empty_source_policy: Option<String>,
+
+    /// Copy and verify without deleting the source directory contents
+    #[arg(long, requires = "source_directory", conflicts_with = "file")]
+    keep_source: bool,
+
+    /// Only move entries up to this depth below the source (0 = immediate children)
+    #[arg(
+        long,
+        value_name = "DEPTH",
+        requires = "source_directory",
+        conflicts_with = "file"
+    )]
+    max_depth: Option<usize>,
+
+    /// Skip files that already exist in the destination with the same content
+    #[arg(long, requires = "source_directory", conflicts_with = "file")]
+    skip_existing_matching: bool,
+
+    /// Reject a destination without a date placeholder, since it can only be used once
+    #[arg(long, requires = "source_directory", conflicts_with = "file")]
+    require_date_placeholder: bool,
+
+    /// Also copy extended attributes (xattrs, including POSIX ACLs on Linux) on Unix
+    #[arg(long, requires = "source_directory", conflicts_with = "file")]
+    preserve_xattrs: bool,
+
+    /// Copy files concurrently on several threads
+    #[arg(long, requires = "source_directory", conflicts_with = "file")]
+    parallel: bool,
+
+    #[arg(
+        long,
+        value_name = "CASE_SENSITIVITY",
         requires = "source_directory",
         conflicts_with = "file"
     )]
-    weekday: Option<String>,
+    case_sensitivity: Option<String>,
+
+    /// Write a SHA256SUMS file into the destination after the transfer
+    #[arg(long, requires = "source_directory", conflicts_with = "file")]
+    write_checksums: bool,
+
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+
+    #[arg(long)]
+    force: bool,
+
+    /// Run even if today is not one of the configured weekdays
+    #[arg(long)]
+    ignore_weekday: bool,
+
+    /// Resolve relative paths in the config file against the current directory instead of the config file's directory
+    #[arg(long, conflicts_with = "source_directory")]
+    no_follow_config_dir: bool,
+
+    /// Abort each transfer that runs longer than this many seconds and roll back its destination
+    #[arg(long, value_name = "SECONDS")]
+    timeout_secs: Option<u64>,
+
+    /// Log a single hash of the whole source and destination trees and check that they match
+    #[arg(long)]
+    tree_hash: bool,
+
+    /// List the source entries and ask for y/N on stdin before deleting them
+    #[arg(long, conflicts_with = "dry_run")]
+    interactive: bool,
+
+    /// Copy into a temporary sibling directory and rename it to the destination only after verification
+    #[arg(long, conflicts_with = "skip_existing_matching")]
+    staging: bool,
+
+    /// List the entries that would be removed (with --force) and copied without changing anything
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Print the effective configuration (defaults applied, destination date rendered) as JSON and exit
+    #[arg(long)]
+    print_config: bool,
+
+    /// Only move files modified at or after this RFC 3339 timestamp
+    #[arg(long, value_name = "TIMESTAMP", conflicts_with = "incremental")]
+    since: Option<String>,
+
+    /// Only move files modified since the last successful run recorded next to the config file
+    #[arg(long, conflicts_with = "source_directory")]
+    incremental: bool,
+
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    output: OutputFormat,
+
+    /// Print nothing to stdout on success; errors are still written to stderr
+    #[arg(short, long, global = true)]
+    quiet: bool,
+
+    /// Language of the messages (ja or en); defaults to the LANG environment variable
+    #[arg(long, global = true)]
+    lang: Option<Lang>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    Text,
+    Json,
+}
+
+/// ジョブごとの実行結果。`--dry-run` の場合は計画のみを返す
+enum JobOutcome {
+    Transferred(TransferSummary),
+    Planned(TransferPlan),
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Write a sample config file
+    Init {
+        #[arg(value_name = "PATH", default_value = "config.json")]
+        path: PathBuf,
+
+        #[arg(long)]
+        force: bool,
+    },
+    /// Compare the contents of two directories without moving anything
+    Verify {
+        #[arg(value_name = "SOURCE_DIRECTORY", required_unless_present = "manifest")]
+        source: Option<PathBuf>,
+
+        #[arg(
+            value_name = "DESTINATION_DIRECTORY",
+            required_unless_present = "manifest"
+        )]
+        destination: Option<PathBuf>,
+
+        /// Compare file and directory names without regard to case (for macOS or Windows volumes)
+        #[arg(long, conflicts_with = "manifest")]
+        case_insensitive: bool,
+
+        /// Check a destination against the srow-manifest.json written when it was moved
+        #[arg(long, value_name = "DESTINATION_DIRECTORY", conflicts_with_all = ["source", "destination"])]
+        manifest: Option<PathBuf>,
+    },
+    /// Print a shell completion script to stdout
+    Completions {
+        #[arg(value_enum)]
+        shell: Shell,
+    },
+}
+
+fn init_logger(verbose: u8) {
+    let level = match verbose {
+        0 => log::LevelFilter::Warn,
+        1 => log::LevelFilter::Info,
+        2 => log::LevelFilter::Debug,
+        _ => log::LevelFilter::Trace,
+    };
+    // RUST_LOG が設定されていればそちらを優先する
+    env_logger::Builder::new()
+        .filter_level(level)
+        .parse_default_env()
+        .init();
 }
 
-fn main() -> AppResult<()> {
+const DEFAULT_CONFIG_FILE_NAME: &str = "srow.json";
+const STDIN_CONFIG_FILE: &str = "-";
+
+// カレントディレクトリ、ユーザーの設定ディレクトリの順に設定ファイルを探す
+fn discover_config_file(current_dir: &Path, config_dir: Option<&Path>) -> Option<PathBuf> {
+    std::iter::once(current_dir)
+        .chain(config_dir)
+        .map(|dir| dir.join(DEFAULT_CONFIG_FILE_NAME))
+        .find(|path| path.is_file())
+}
+
+fn write_completions(shell: Shell, out: &mut impl Write) {
+    let mut command = Cli::command();
+    let bin_name = command.get_bin_name().unwrap_or("srow").to_string();
+    clap_complete::generate(shell, &mut command, bin_name, out);
+}
+
+fn error_json(error: &AppError, lang: Lang) -> serde_json::Value {
+    json!({
+        "code": error.code(),
+        "message": Message::Error(error).render(lang),
+    })
+}
+
+// 設定の読み込みなど、ジョブに入る前の失敗も JSON で報告する
+fn report_failure(output: OutputFormat, lang: Lang, error: AppError) -> AppError {
+    if output == OutputFormat::Json {
+        println!(
+            "{}",
+            json!({ "status": "failed", "error": error_json(&error, lang) })
+        );
+    }
+    error
+}
+
+fn verify_directories(
+    source: PathBuf,
+    destination: PathBuf,
+    case_sensitivity: CaseSensitivity,
+    lang: Lang,
+    quiet: bool,
+) -> AppResult<()> {
+    let differences = DirectoryVerificationService::new(source, destination)?
+        .with_case_sensitivity(case_sensitivity)
+        .verify()?;
+    if differences.is_empty() {
+        if !quiet {
+            println!("{}", Message::DirectoriesMatch.render(lang));
+        }
+        return Ok(());
+    }
+
+    for difference in &differences {
+        println!("{}", difference);
+    }
+    Err(AppError::DifferencesFound(differences.len()))
+}
+
+fn verify_against_manifest(destination: &Path, lang: Lang, quiet: bool) -> AppResult<()> {
+    let drifts = verify_manifest(destination)?;
+    if drifts.is_empty() {
+        if !quiet {
+            println!("{}", Message::DirectoriesMatch.render(lang));
+        }
+        return Ok(());
+    }
+
+    for drift in &drifts {
+        println!("{}", drift);
+    }
+    Err(AppError::DifferencesFound(drifts.len()))
+}
+
+/// 移動するバイト数をバーで、ファイル数をメッセージで示しながら転送する
+fn transfer_with_progress_bar(
+    service: DirectoryDataTransferService,
+    lang: Lang,
+) -> AppResult<TransferSummary> {
+    let totals = service.totals()?;
+    let bar = ProgressBar::with_draw_target(Some(totals.bytes), ProgressDrawTarget::stdout());
+    if let Ok(style) =
+        ProgressStyle::with_template("{wide_bar} {bytes}/{total_bytes} {msg} ETA {eta}")
+    {
+        bar.set_style(style);
+    }
+    let files_progress = move |done| {
+        Message::FilesProgress {
+            done,
+            total: totals.files,
+        }
+        .render(lang)
+    };
+    bar.set_message(files_progress(0));
+
+    let observer = bar.clone();
+    let files_done = Cell::new(0);
+    let result = service
+        .with_progress(move |_, bytes| {
+            files_done.set(files_done.get() + 1);
+            observer.set_message(files_progress(files_done.get()));
+            observer.inc(bytes);
+        })
+        .transfer();
+    // 結果の表示と混ざらないよう、バーは消してから戻る
+    bar.finish_and_clear();
+    result
+}
+
+fn main() -> ExitCode {
     let cli = Cli::parse();
+    init_logger(cli.verbose);
+    let lang = cli.lang.unwrap_or_else(Lang::from_env);
+
+    match run(cli, lang) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", Message::Error(&e).render(lang));
+            ExitCode::from(e.exit_code())
+        }
+    }
+}
 
-    let config = if let Some(file) = cli.file.as_deref() {
-        // 設定ファイルから設定を読み込み
-        JsonConfigBuilder::new(file.to_str().unwrap())?.build()?
+fn run(cli: Cli, lang: Lang) -> AppResult<()> {
+    match cli.command {
+        Some(Command::Init { path, force }) => {
+            write_sample_config(&path, force)?;
+            if !cli.quiet {
+                println!("{}", Message::SampleConfigWritten(&path).render(lang));
+            }
+            return Ok(());
+        }
+        Some(Command::Verify {
+            manifest: Some(destination),
+            ..
+        }) => return verify_against_manifest(&destination, lang, cli.quiet),
+        Some(Command::Verify {
+            source: Some(source),
+            destination: Some(destination),
+            case_insensitive,
+            ..
+        }) => {
+            let case_sensitivity = match case_insensitive {
+                true => CaseSensitivity::Insensitive,
+                false => CaseSensitivity::Sensitive,
+            };
+            return verify_directories(source, destination, case_sensitivity, lang, cli.quiet);
+        }
+        // clap の required_unless_present により到達しない
+        Some(Command::Verify { .. }) => unreachable!(),
+        Some(Command::Completions { shell }) => {
+            write_completions(shell, &mut std::io::stdout());
+            return Ok(());
+        }
+        None => {}
+    }
+
+    // 明示的な --file を優先し、引数も指定されていない場合のみ既定の場所を探す
+    let config_file = match (&cli.file, &cli.source_directory) {
+        (Some(file), _) => Some(file.clone()),
+        (None, None) => {
+            let project_dirs = ProjectDirs::from("", "", "srow");
+            discover_config_file(
+                &std::env::current_dir()?,
+                project_dirs.as_ref().map(|dirs| dirs.config_dir()),
+            )
+        }
+        (None, Some(_)) => None,
+    };
+
+    let configs = if config_file.as_deref() == Some(Path::new(STDIN_CONFIG_FILE)) {
+        // コンテナなどから渡される設定を標準入力から JSON として読み込み
+        if cli.incremental {
+            let error = AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                Message::IncrementalWithStdinConfig.render(lang),
+            ));
+            return Err(report_failure(cli.output, lang, error));
+        }
+        // 確認の回答も標準入力から読むため、設定と同時には受け取れない
+        if cli.interactive {
+            let error = AppError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                Message::InteractiveWithStdinConfig.render(lang),
+            ));
+            return Err(report_failure(cli.output, lang, error));
+        }
+        MultiJobConfigBuilder::build_from_reader(std::io::stdin().lock())
+            .map_err(|e| report_failure(cli.output, lang, e))?
+    } else if let Some(file) = config_file.as_deref() {
+        // 設定ファイルから設定を読み込み（拡張子で形式を判定）
+        file.to_str()
+            .ok_or_else(|| AppError::InvalidPath(file.to_path_buf()))
+            .and_then(MultiJobConfigBuilder::new)
+            .and_then(|builder| {
+                builder
+                    .with_follow_config_dir(!cli.no_follow_config_dir)
+                    .build()
+            })
+            .map_err(|e| report_failure(cli.output, lang, e))?
     } else if let (Some(source), Some(destination), Some(weekday)) =
         (cli.source_directory, cli.destination_directory, cli.weekday)
     {
         // コマンドライン引数から設定を構築
-        let source_path = source.to_str().unwrap().to_string();
-        let destination_path = destination.to_str().unwrap().to_string();
-        ArgConfigBuilder::new(source_path, destination_path, weekday)?.build()?
+        let config = ArgConfigBuilder::new(source, destination, weekday)
+            .and_then(|builder| {
+                builder
+                    .with_timezone(cli.timezone)
+                    .with_hash_algorithm(cli.hash_algorithm)
+                    .with_exclude_patterns(cli.exclude_patterns)
+                    .with_include_patterns(cli.include_patterns)
+                    .with_symlink_policy(cli.symlink_policy)
+                    .with_retry(cli.retry_attempts, cli.retry_delay_ms)
+                    .with_directory_mode(cli.directory_mode)
+                    .with_verification_mode(cli.verification_mode)
+                    .with_buffer_size(cli.buffer_size)
+                    .with_bandwidth_limit(cli.bandwidth_limit)
+                    .with_max_depth(cli.max_depth)
+                    .with_skip_existing_matching(cli.skip_existing_matching)
+                    .with_require_date_placeholder(cli.require_date_placeholder)
+                    .with_preserve_xattrs(cli.preserve_xattrs)
+                    .with_parallel(cli.parallel)
+                    .with_case_sensitivity(cli.case_sensitivity)
+                    .with_date_offset(cli.date_offset_days, cli.date_offset_applies_to_weekday)
+                    .with_snap_date_to_weekday(cli.snap_date_to_weekday)
+                    .with_delete_source(!cli.keep_source)
+                    .with_empty_source_policy(cli.empty_source_policy)
+                    .with_write_checksums(cli.write_checksums)
+                    .build()
+            })
+            .map_err(|e| report_failure(cli.output, lang, e))?;
+        vec![config]
     } else {
-        return Err(AppError::Io(std::io::Error::new(
+        let error = AppError::Io(std::io::Error::new(
             std::io::ErrorKind::InvalidInput,
-            "設定ファイルまたはコマンドライン引数（source_directory, destination_directory, weekday）が必要です"
-        )));
+            Message::ConfigRequired.render(lang),
+        ));
+        return Err(report_failure(cli.output, lang, error));
     };
 
-    DirectoryDataTransferService::new(config)
-        .validate()?
-        .transfer()
+    if cli.print_config {
+        let now = SystemClock.now();
+        let mut values: Vec<_> = configs
+            .iter()
+            .map(|config| config.effective_values(now))
+            .collect();
+        let values = match values.len() {
+            1 => values.remove(0),
+            _ => serde_json::Value::from(values),
+        };
+        println!("{:#}", values);
+        return Ok(());
+    }
+
+    // 前回の実行日時は設定ファイルの隣の状態ファイルに記録する
+    let incremental_run = config_file
+        .as_deref()
+        .filter(|_| cli.incremental)
+        .map(IncrementalRun::start);
+    let modified_since = match (&cli.since, &incremental_run) {
+        (Some(since), _) => Some(parse_timestamp(since)),
+        (None, Some(incremental_run)) => incremental_run.last_run().transpose(),
+        (None, None) => None,
+    }
+    .transpose()
+    .map_err(|e| report_failure(cli.output, lang, e))?;
+
+    let job_count = configs.len();
+    // パイプやリダイレクト先に制御文字を書き込まないよう、端末にのみ表示する
+    let show_progress = cli.output == OutputFormat::Text
+        && !cli.quiet
+        && !cli.dry_run
+        && std::io::stdout().is_terminal();
+    let mut errors = Vec::new();
+    for (index, config) in configs.into_iter().enumerate() {
+        let source = config.source_directory_path.display().to_string();
+        let destination = config.dest_directory_path.display().to_string();
+        let next_run = config.next_run(&SystemClock.now());
+        let mut service = DirectoryDataTransferService::new(config);
+        if cli.interactive {
+            service = service.with_source_removal_confirmation(move |source, entries| {
+                // 標準出力は結果の出力に使うため、確認は標準エラー出力に表示する
+                confirm_source_removal(
+                    &mut std::io::stdin().lock(),
+                    &mut std::io::stderr(),
+                    source,
+                    entries,
+                    lang,
+                )
+            });
+        }
+        let result = service
+            .with_force(cli.force)
+            .with_ignore_weekday(cli.ignore_weekday)
+            .with_lang(lang)
+            .with_modified_since(modified_since)
+            .with_timeout(cli.timeout_secs.map(std::time::Duration::from_secs))
+            .with_tree_hash(cli.tree_hash)
+            .with_staging(cli.staging)
+            .validate()
+            .and_then(|service| {
+                if cli.dry_run {
+                    service.plan().map(JobOutcome::Planned)
+                } else if show_progress {
+                    transfer_with_progress_bar(service, lang).map(JobOutcome::Transferred)
+                } else {
+                    service.transfer().map(JobOutcome::Transferred)
+                }
+            });
+
+        if cli.output == OutputFormat::Json {
+            let report = match &result {
+                Ok(JobOutcome::Planned(plan)) => json!({
+                    "status": "dry_run",
+                    "source": source,
+                    "destination": destination,
+                    "removals": plan.removals,
+                    "copies": plan.copies,
+                }),
+                Ok(JobOutcome::Transferred(summary)) => json!({
+                    "status": "success",
+                    "source": source,
+                    "destination": destination,
+                    "files_copied": summary.files_copied,
+                    "directories_created": summary.directories_created,
+                    "bytes": summary.total_bytes,
+                    "files_skipped": summary.files_skipped,
+                }),
+                Err(e) => json!({
+                    "status": if matches!(e, AppError::WrongWeekday { .. }) && job_count > 1 {
+                        "skipped"
+                    } else {
+                        "failed"
+                    },
+                    "source": source,
+                    "destination": destination,
+                    "error": error_json(e, lang),
+                }),
+            };
+            println!("{}", report);
+        }
+
+        if matches!(result, Err(AppError::WrongWeekday { .. })) && cli.output == OutputFormat::Text
+        {
+            let date = next_run.format("%Y-%m-%d (%a)").to_string();
+            eprintln!("{}", Message::NextRun(&date).render(lang));
+        }
+
+        match result {
+            Ok(JobOutcome::Planned(plan)) => {
+                if cli.output == OutputFormat::Text && !cli.quiet {
+                    for path in &plan.removals {
+                        println!("{}", Message::DryRunRemoval(path).render(lang));
+                    }
+                    for path in &plan.copies {
+                        println!("{}", Message::DryRunCopy(path).render(lang));
+                    }
+                }
+            }
+            Ok(JobOutcome::Transferred(summary)) => {
+                if cli.output == OutputFormat::Text && !cli.quiet {
+                    println!("{}", Message::TransferSucceeded.render(lang));
+                    println!(
+                        "{}",
+                        Message::TransferSummary {
+                            files_copied: summary.files_copied,
+                            directories_created: summary.directories_created,
+                            total_bytes: summary.total_bytes,
+                        }
+                        .render(lang)
+                    );
+                }
+            }
+            // 曜日違いは失敗ではなくスキップとして扱う
+            Err(e @ AppError::WrongWeekday { .. }) if job_count > 1 => {
+                if cli.output == OutputFormat::Text && !cli.quiet {
+                    let message = Message::JobSkipped {
+                        job: index + 1,
+                        reason: &e,
+                    };
+                    println!("{}", message.render(lang));
+                }
+            }
+            Err(e) if job_count == 1 => return Err(e),
+            Err(e) => {
+                if cli.output == OutputFormat::Text {
+                    let message = Message::JobFailed {
+                        job: index + 1,
+                        reason: &e,
+                    };
+                    eprintln!("{}", message.render(lang));
+                }
+                errors.push(e);
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(AppError::JobsFailed {
+            total: job_count,
+            failed: errors.len(),
+        });
+    }
+
+    // 試行のみの場合は何も移動していないため、前回の実行時刻を更新しない
+    if let Some(incremental_run) = incremental_run.filter(|_| !cli.dry_run) {
+        incremental_run.finish()?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn write_completions_generates_script_for_each_shell() {
+        // ===== Arrange =====
+        let shells = [Shell::Bash, Shell::Zsh, Shell::Fish];
+
+        for shell in shells {
+            // ===== Act =====
+            let mut out = Vec::new();
+            write_completions(shell, &mut out);
+
+            // ===== Assert =====
+            let script = String::from_utf8(out).unwrap();
+            assert!(!script.is_empty());
+            assert!(script.contains("srow"));
+        }
+    }
+
+    #[test]
+    fn discover_config_file_prefers_current_directory() {
+        // ===== Arrange =====
+        let current_dir = TempDir::new().unwrap();
+        let config_dir = TempDir::new().unwrap();
+        fs::write(current_dir.path().join("srow.json"), "{}").unwrap();
+        fs::write(config_dir.path().join("srow.json"), "{}").unwrap();
+
+        // ===== Act =====
+        let result = discover_config_file(current_dir.path(), Some(config_dir.path()));
+
+        // ===== Assert =====
+        assert_eq!(result, Some(current_dir.path().join("srow.json")));
+    }
+
+    #[test]
+    fn discover_config_file_falls_back_to_config_directory() {
+        // ===== Arrange =====
+        let current_dir = TempDir::new().unwrap();
+        let config_dir = TempDir::new().unwrap();
+        fs::write(config_dir.path().join("srow.json"), "{}").unwrap();
+
+        // ===== Act =====
+        let result = discover_config_file(current_dir.path(), Some(config_dir.path()));
+        let not_found = discover_config_file(current_dir.path(), None);
+
+        // ===== Assert =====
+        assert_eq!(result, Some(config_dir.path().join("srow.json")));
+        assert_eq!(not_found, None);
+    }
+
+    #[test]
+    fn cli_definition_is_valid() {
+        // ===== Act & Assert =====
+        Cli::command().debug_assert();
+    }
 }
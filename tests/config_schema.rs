@@ -0,0 +1,33 @@
+use std::{fs, process::Command};
+use tempfile::TempDir;
+
+#[test]
+fn config_file_with_invalid_weekday_fails_schema_validation() {
+    // ===== Arrange =====
+    let source_dir = TempDir::new().unwrap();
+    let dest_root = TempDir::new().unwrap();
+    let config_path = dest_root.path().join("srow.json");
+    let config = serde_json::json!({
+        "jobs": [{
+            "source_directory_path": source_dir.path(),
+            "destination_directory_path": dest_root.path().join("{yyyy}-{mm}-{dd}"),
+            "weekday": "Funday",
+        }]
+    });
+    fs::write(&config_path, config.to_string()).unwrap();
+
+    // ===== Act =====
+    let output = Command::new(env!("CARGO_BIN_EXE_srow"))
+        .arg("--file")
+        .arg(&config_path)
+        .args(["--output", "json"])
+        .output()
+        .unwrap();
+
+    // ===== Assert =====
+    assert_eq!(output.status.code(), Some(1));
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["error"]["code"], "config_schema");
+    let message = report["error"]["message"].as_str().unwrap();
+    assert!(message.contains("/jobs/0/weekday"), "{}", message);
+}
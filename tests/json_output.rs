@@ -0,0 +1,117 @@
+use std::{fs, path::Path, process::Command};
+use tempfile::TempDir;
+
+const ALL_WEEKDAYS: &str = "Mon,Tue,Wed,Thu,Fri,Sat,Sun";
+
+fn make_readonly(dir: &Path) {
+    let mut perms = fs::metadata(dir).unwrap().permissions();
+    perms.set_readonly(true);
+    fs::set_permissions(dir, perms).unwrap();
+}
+
+fn run_srow(source: &Path, destination: &Path) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_srow"))
+        .arg("--source-directory")
+        .arg(source)
+        .arg("--destination-directory")
+        .arg(destination)
+        .args(["--weekday", ALL_WEEKDAYS, "--output", "json"])
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn json_output_reports_successful_transfer() {
+    // ===== Arrange =====
+    let source_dir = TempDir::new().unwrap();
+    fs::create_dir(source_dir.path().join("subdir")).unwrap();
+    fs::write(source_dir.path().join("file1.txt"), "12345").unwrap();
+    fs::write(source_dir.path().join("subdir").join("file2.txt"), "123").unwrap();
+    make_readonly(source_dir.path());
+    let dest_dir = TempDir::new().unwrap();
+
+    // ===== Act =====
+    let output = run_srow(source_dir.path(), dest_dir.path());
+
+    // ===== Assert =====
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["status"], "success");
+    assert_eq!(report["files_copied"], 2);
+    assert_eq!(report["directories_created"], 1);
+    assert_eq!(report["bytes"], 8);
+    assert_eq!(report["source"], source_dir.path().display().to_string());
+    assert_eq!(report["destination"], dest_dir.path().display().to_string());
+}
+
+#[test]
+fn json_output_reports_error_code_on_failure() {
+    // ===== Arrange =====
+    let source_dir = TempDir::new().unwrap();
+    make_readonly(source_dir.path());
+    let dest_dir = TempDir::new().unwrap();
+    fs::write(dest_dir.path().join("existing.txt"), "existing").unwrap();
+
+    // ===== Act =====
+    let output = run_srow(source_dir.path(), dest_dir.path());
+
+    // ===== Assert =====
+    assert!(!output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["status"], "failed");
+    assert_eq!(report["error"]["code"], "destination_not_empty");
+}
+
+#[test]
+fn json_output_reports_incremental_with_stdin_config_in_selected_language() {
+    // ===== Act =====
+    let output = Command::new(env!("CARGO_BIN_EXE_srow"))
+        .args([
+            "--file",
+            "-",
+            "--incremental",
+            "--output",
+            "json",
+            "--lang",
+            "en",
+        ])
+        .output()
+        .unwrap();
+
+    // ===== Assert =====
+    assert!(!output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["status"], "failed");
+    assert_eq!(
+        report["error"]["message"],
+        "IO error: --incremental cannot be used when the config is read from stdin"
+    );
+}
+
+#[test]
+fn json_output_rejects_interactive_with_stdin_config() {
+    // ===== Act =====
+    let output = Command::new(env!("CARGO_BIN_EXE_srow"))
+        .args([
+            "--file",
+            "-",
+            "--interactive",
+            "--output",
+            "json",
+            "--lang",
+            "en",
+        ])
+        .output()
+        .unwrap();
+
+    // ===== Assert =====
+    assert_eq!(output.status.code(), Some(1));
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["status"], "failed");
+    let message = report["error"]["message"].as_str().unwrap();
+    assert!(
+        message.contains("--interactive cannot be used"),
+        "{}",
+        message
+    );
+}
@@ -0,0 +1,39 @@
+use std::{fs, path::Path, process::Command};
+use tempfile::TempDir;
+
+#[test]
+fn failed_job_among_several_exits_with_jobs_failed_code() {
+    // ===== Arrange =====
+    let source_dir = TempDir::new().unwrap();
+    let dest_dir = TempDir::new().unwrap();
+    let occupied_dest_dir = TempDir::new().unwrap();
+    fs::write(source_dir.path().join("file.txt"), "content").unwrap();
+    fs::write(occupied_dest_dir.path().join("existing.txt"), "existing").unwrap();
+    let job = |destination: &Path| {
+        serde_json::json!({
+            "source_directory_path": source_dir.path(),
+            "destination_directory_path": destination,
+            "weekday": ["weekdays", "weekends"],
+        })
+    };
+    let config_path = dest_dir.path().join("srow.json");
+    let config = serde_json::json!({
+        "jobs": [job(occupied_dest_dir.path()), job(&dest_dir.path().join("moved"))]
+    });
+    fs::create_dir(dest_dir.path().join("moved")).unwrap();
+    fs::write(&config_path, config.to_string()).unwrap();
+
+    // ===== Act =====
+    let output = Command::new(env!("CARGO_BIN_EXE_srow"))
+        .arg("--file")
+        .arg(&config_path)
+        .args(["--lang", "en"])
+        .output()
+        .unwrap();
+
+    // ===== Assert =====
+    assert_eq!(output.status.code(), Some(11));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("1 of 2 jobs failed"), "{}", stderr);
+    assert!(dest_dir.path().join("moved").join("file.txt").exists());
+}
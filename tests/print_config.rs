@@ -0,0 +1,34 @@
+use std::{fs, process::Command};
+use tempfile::TempDir;
+
+#[test]
+fn print_config_prints_rendered_destination_without_transferring() {
+    // ===== Arrange =====
+    let source_dir = TempDir::new().unwrap();
+    let dest_root = TempDir::new().unwrap();
+    fs::write(source_dir.path().join("file.txt"), "content").unwrap();
+    let destination = dest_root.path().join("{yyyy}-{mm}-{dd}");
+
+    // ===== Act =====
+    let output = Command::new(env!("CARGO_BIN_EXE_srow"))
+        .arg("--source-directory")
+        .arg(source_dir.path())
+        .arg("--destination-directory")
+        .arg(&destination)
+        .args(["--weekday", "Sat,Sun", "--print-config"])
+        .output()
+        .unwrap();
+
+    // ===== Assert =====
+    assert!(output.status.success());
+    let config: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let rendered = config["destination_directory_path"].as_str().unwrap();
+    let date = rendered.rsplit(['/', '\\']).next().unwrap();
+    assert!(!rendered.contains('{'), "{}", rendered);
+    assert_eq!(date.len(), "yyyy-mm-dd".len(), "{}", rendered);
+    assert!(date.chars().all(|c| c.is_ascii_digit() || c == '-'));
+    assert_eq!(config["weekday"], serde_json::json!(["Sat", "Sun"]));
+    // 移動は行わない
+    assert!(source_dir.path().join("file.txt").exists());
+    assert_eq!(fs::read_dir(dest_root.path()).unwrap().count(), 0);
+}
@@ -0,0 +1,31 @@
+use std::{fs, process::Command};
+use tempfile::TempDir;
+
+#[test]
+fn progress_bar_is_not_drawn_when_stdout_is_not_a_terminal() {
+    // ===== Arrange =====
+    let source_dir = TempDir::new().unwrap();
+    let dest_dir = TempDir::new().unwrap();
+    for i in 0..3 {
+        fs::write(source_dir.path().join(format!("file{}.txt", i)), "content").unwrap();
+    }
+
+    // ===== Act =====
+    // --keep-source でリネームではなくファイル単位のコピーを行わせ、進捗が発生するようにする
+    let output = Command::new(env!("CARGO_BIN_EXE_srow"))
+        .arg("--source-directory")
+        .arg(source_dir.path())
+        .arg("--destination-directory")
+        .arg(dest_dir.path())
+        .args(["--weekday", "Mon,Tue,Wed,Thu,Fri,Sat,Sun", "--keep-source"])
+        .output()
+        .unwrap();
+
+    // ===== Assert =====
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(!stdout.is_empty());
+    assert!(!stdout.contains('\x1b'), "{:?}", stdout);
+    assert!(!stdout.contains('\r'), "{:?}", stdout);
+    assert!(!String::from_utf8_lossy(&output.stderr).contains('\x1b'));
+}
@@ -0,0 +1,47 @@
+use std::{fs, process::Command};
+use tempfile::TempDir;
+
+#[test]
+fn quiet_flag_prints_nothing_to_stdout_on_success() {
+    // ===== Arrange =====
+    let source_dir = TempDir::new().unwrap();
+    let dest_dir = TempDir::new().unwrap();
+    fs::write(source_dir.path().join("file.txt"), "content").unwrap();
+
+    // ===== Act =====
+    let output = Command::new(env!("CARGO_BIN_EXE_srow"))
+        .arg("--source-directory")
+        .arg(source_dir.path())
+        .arg("--destination-directory")
+        .arg(dest_dir.path())
+        .args(["--weekday", "Mon,Tue,Wed,Thu,Fri,Sat,Sun", "--quiet"])
+        .output()
+        .unwrap();
+
+    // ===== Assert =====
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(dest_dir.path().join("file.txt").exists());
+}
+
+#[test]
+fn quiet_flag_still_reports_errors_to_stderr() {
+    // ===== Arrange =====
+    let dest_dir = TempDir::new().unwrap();
+    let missing_source = dest_dir.path().join("missing");
+
+    // ===== Act =====
+    let output = Command::new(env!("CARGO_BIN_EXE_srow"))
+        .arg("--source-directory")
+        .arg(&missing_source)
+        .arg("--destination-directory")
+        .arg(dest_dir.path().join("dest"))
+        .args(["--weekday", "Mon", "-q"])
+        .output()
+        .unwrap();
+
+    // ===== Assert =====
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(!output.stderr.is_empty());
+}
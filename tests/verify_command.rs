@@ -0,0 +1,85 @@
+use std::{fs, path::Path, process::Command};
+use tempfile::TempDir;
+
+fn create_tree(root: &Path, nested_content: &str) {
+    fs::create_dir_all(root.join("a").join("b")).unwrap();
+    fs::write(root.join("root.txt"), "root").unwrap();
+    fs::write(root.join("a").join("b").join("nested.txt"), nested_content).unwrap();
+}
+
+fn run_verify(source: &Path, destination: &Path) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_srow"))
+        .arg("verify")
+        .arg(source)
+        .arg(destination)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn verify_command_succeeds_for_identical_trees() {
+    // ===== Arrange =====
+    let source_dir = TempDir::new().unwrap();
+    let dest_dir = TempDir::new().unwrap();
+    create_tree(source_dir.path(), "nested");
+    create_tree(dest_dir.path(), "nested");
+
+    // ===== Act =====
+    let output = run_verify(source_dir.path(), dest_dir.path());
+
+    // ===== Assert =====
+    assert!(output.status.success());
+}
+
+#[test]
+fn verify_command_reports_differing_nested_file_and_fails() {
+    // ===== Arrange =====
+    let source_dir = TempDir::new().unwrap();
+    let dest_dir = TempDir::new().unwrap();
+    create_tree(source_dir.path(), "nested");
+    create_tree(dest_dir.path(), "modified");
+
+    // ===== Act =====
+    let output = run_verify(source_dir.path(), dest_dir.path());
+
+    // ===== Assert =====
+    assert_eq!(output.status.code(), Some(10));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let nested_path = Path::new("a").join("b").join("nested.txt");
+    assert!(stdout.contains(&format!("内容が一致しません: {}", nested_path.display())));
+    assert!(!stdout.contains("root.txt"));
+}
+
+#[test]
+fn verify_manifest_detects_file_tampered_after_move() {
+    // ===== Arrange =====
+    let source_dir = TempDir::new().unwrap();
+    let dest_dir = TempDir::new().unwrap();
+    create_tree(source_dir.path(), "nested");
+    let moved = Command::new(env!("CARGO_BIN_EXE_srow"))
+        .arg("--source-directory")
+        .arg(source_dir.path())
+        .arg("--destination-directory")
+        .arg(dest_dir.path())
+        .args(["--weekday", "Mon,Tue,Wed,Thu,Fri,Sat,Sun"])
+        .output()
+        .unwrap();
+    assert!(moved.status.success());
+    let run_verify_manifest = || {
+        Command::new(env!("CARGO_BIN_EXE_srow"))
+            .args(["verify", "--manifest"])
+            .arg(dest_dir.path())
+            .output()
+            .unwrap()
+    };
+    assert!(run_verify_manifest().status.success());
+    fs::write(dest_dir.path().join("root.txt"), "tampered").unwrap();
+
+    // ===== Act =====
+    let output = run_verify_manifest();
+
+    // ===== Assert =====
+    assert_eq!(output.status.code(), Some(10));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("内容が変更されています: root.txt"));
+}